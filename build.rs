@@ -0,0 +1,144 @@
+//! Generates the built-in wordlists from the files in `resources/wordlists/`. Each `NAME.txt` there
+//! becomes the constant `charsets::NAME` (in upper case), and each `languages/NAME.txt` a constant
+//! in the `languages` module. `LICENSE-*` files, the notices of lists taken from other projects,
+//! are skipped.
+//!
+//! A wordlist has one word per line. The build fails if a list has fewer than two words, or if a
+//! word is repeated, contains whitespace, or isn't either lowercase ASCII or in Unicode NFC form.
+//!
+//! The words are packed for `charsets::Wordlist`: `NAME.txt` in `OUT_DIR` holds the words
+//! concatenated, and `NAME.offsets` the little-endian `u32` offset at which each word starts,
+//! followed by the length of the text.
+
+extern crate unicode_normalization;
+
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use unicode_normalization::is_nfc;
+
+const WORDLIST_DIR: &str = "resources/wordlists";
+
+fn main() {
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR isn't set"));
+    let wordlist_dir = Path::new(WORDLIST_DIR);
+
+    println!("cargo:rerun-if-changed={}", WORDLIST_DIR);
+    println!("cargo:rerun-if-changed=build.rs");
+
+    if let Err(message) = generate(wordlist_dir, &out_dir.join("charsets"))
+        .and_then(|_| generate(&wordlist_dir.join("languages"), &out_dir.join("languages")))
+    {
+        eprintln!("error: {}", message);
+        process::exit(1);
+    }
+}
+
+/// Pack the wordlists in `dir` into `out_dir`, and write the constants for them to `out_dir.rs`.
+fn generate(dir: &Path, out_dir: &Path) -> Result<(), String> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).map_err(|e| format!("Couldn't read {}: {}", dir.display(), e))? {
+        let path = entry.map_err(|e| format!("Couldn't read {}: {}", dir.display(), e))?.path();
+        let is_notice = path.file_name().and_then(|name| name.to_str()).is_some_and(|name| name.starts_with("LICENSE-"));
+        if path.is_file() && !is_notice {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    fs::create_dir_all(out_dir).unwrap();
+    let mut constants = String::new();
+    for path in paths {
+        let name = constant_name(&path)?;
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| format!("Couldn't read {}: {}", path.display(), e))?;
+        let words = parse_wordlist(&contents).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        let (text, offsets) = pack(&words).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let text_path = out_dir.join(format!("{}.txt", name));
+        let offsets_path = out_dir.join(format!("{}.offsets", name));
+        fs::write(&text_path, text).unwrap();
+        fs::write(&offsets_path, offsets).unwrap();
+
+        writeln!(constants, "/// The {} words of `{}`.", words.len(), path.display()).unwrap();
+        writeln!(
+            constants,
+            "pub const {}: &Wordlist = &Wordlist::from_packed(include_str!({:?}), include_bytes!({:?}));",
+            name, text_path, offsets_path
+        ).unwrap();
+    }
+
+    fs::write(out_dir.with_extension("rs"), constants).unwrap();
+    Ok(())
+}
+
+/// The name of the constant for the wordlist at `path`: its file name without `.txt`, in upper
+/// case.
+fn constant_name(path: &Path) -> Result<String, String> {
+    let stem = match (path.file_stem().and_then(|stem| stem.to_str()), path.extension()) {
+        (Some(stem), Some(extension)) if extension == "txt" => stem,
+        _ => return Err(format!("{} isn't a wordlist; wordlists must be named NAME.txt", path.display())),
+    };
+    let valid = stem.starts_with(|c: char| c.is_ascii_lowercase())
+        && stem.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !valid {
+        return Err(format!(
+            "{}: wordlist names must be lowercase letters, digits and underscores",
+            path.display()
+        ));
+    }
+    Ok(stem.to_uppercase())
+}
+
+/// Split a wordlist file into its words, checking each one.
+fn parse_wordlist(contents: &str) -> Result<Vec<&str>, String> {
+    let contents = contents.strip_suffix('\n').unwrap_or(contents);
+    let mut lines: HashMap<&str, usize> = HashMap::new();
+    let mut words = Vec::new();
+
+    for (i, word) in contents.split('\n').enumerate() {
+        let line = i + 1;
+        if word.is_empty() {
+            return Err(format!("line {} is empty", line));
+        }
+        if word.chars().any(char::is_whitespace) {
+            return Err(format!("line {}: {:?} contains whitespace", line, word));
+        }
+        if word.is_ascii() && word.bytes().any(|b| b.is_ascii_uppercase()) {
+            return Err(format!("line {}: {:?} isn't lowercase", line, word));
+        }
+        if !is_nfc(word) {
+            return Err(format!("line {}: {:?} isn't in Unicode NFC form", line, word));
+        }
+        if let Some(first) = lines.insert(word, line) {
+            return Err(format!("line {}: {:?} is a duplicate of line {}", line, word, first));
+        }
+        words.push(word);
+    }
+
+    if words.len() < 2 {
+        return Err("a wordlist must have at least two words".to_owned());
+    }
+    Ok(words)
+}
+
+/// The concatenated words, and the offsets table.
+fn pack(words: &[&str]) -> Result<(String, Vec<u8>), String> {
+    let text = words.concat();
+    if text.len() > u32::MAX as usize {
+        return Err("the wordlist is too large to pack".to_owned());
+    }
+
+    let mut offsets = Vec::with_capacity(4 * (words.len() + 1));
+    let mut offset = 0;
+    for word in words {
+        offsets.extend_from_slice(&(offset as u32).to_le_bytes());
+        offset += word.len();
+    }
+    offsets.extend_from_slice(&(offset as u32).to_le_bytes());
+    Ok((text, offsets))
+}
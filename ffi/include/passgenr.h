@@ -0,0 +1,161 @@
+#ifndef PASSGENR_H
+#define PASSGENR_H
+
+/* Generated by cbindgen from src/lib.rs. Do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define PASSGENR_CHARSET_UPPERCASE_HEX 0
+
+#define PASSGENR_CHARSET_LOWERCASE_HEX 1
+
+#define PASSGENR_CHARSET_ALPHANUMERIC 2
+
+#define PASSGENR_CHARSET_ASCII 3
+
+#define PASSGENR_CHARSET_DECIMAL_DIGIT 4
+
+#define PASSGENR_CHARSET_LOWERCASE_ALPHABETIC 5
+
+#define PASSGENR_CHARSET_BASE32 6
+
+#define PASSGENR_CHARSET_CROCKFORD_BASE32 7
+
+#define PASSGENR_CHARSET_BASE58 8
+
+#define PASSGENR_CHARSET_BASE64URL 9
+
+#define PASSGENR_CHARSET_WORDS 10
+
+#define PASSGENR_CHARSET_BIP39_ENGLISH 11
+
+#define PASSGENR_CHARSET_EMOJI 12
+
+#define PASSGENR_CHARSET_GREEK_LOWERCASE 13
+
+#define PASSGENR_CHARSET_HIRAGANA 14
+
+// The result of every function that can fail.
+typedef enum PassgenrStatus {
+  PASSGENR_STATUS_OK = 0,
+  // A required pointer argument was NULL.
+  PASSGENR_STATUS_NULL_POINTER,
+  // The charset ID isn't one of the `PASSGENR_CHARSET_*` constants.
+  PASSGENR_STATUS_UNKNOWN_CHARSET,
+  // A string argument isn't valid UTF-8.
+  PASSGENR_STATUS_INVALID_UTF8,
+  // A custom charset has fewer than two elements.
+  PASSGENR_STATUS_CHARSET_TOO_SMALL,
+  // A custom charset has the same element more than once.
+  PASSGENR_STATUS_DUPLICATE_ELEMENTS,
+  // Different sequences of elements can join to the same password with the separator, e.g.
+  // because it occurs in an element, or is empty and the elements have different lengths.
+  PASSGENR_STATUS_AMBIGUOUS,
+  // The output buffer is too small. See `passgenr_max_length`.
+  PASSGENR_STATUS_BUFFER_TOO_SMALL,
+  // The operating system's random number generator failed.
+  PASSGENR_STATUS_RNG,
+  // A bug in passgenr. Please report it.
+  PASSGENR_STATUS_PANIC,
+} PassgenrStatus;
+
+// A charset created with `passgenr_charset_new`, which must be freed with
+// `passgenr_charset_free`.
+typedef struct PassgenrCharset PassgenrCharset;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Generate a password of `count` elements of a built-in charset, separated by `separator`, into
+// `out_buf` as a NUL-terminated string.
+//
+// On failure, `out_buf` holds an empty string (if `out_len` is nonzero).
+//
+// # Safety
+//
+// `separator` must be a NUL-terminated string, and `out_buf` must point to `out_len` writable
+// bytes.
+enum PassgenrStatus passgenr_generate(uint32_t charset_id,
+                                      size_t count,
+                                      const char *separator,
+                                      char *out_buf,
+                                      size_t out_len);
+
+// Like `passgenr_generate`, but with a custom charset.
+//
+// # Safety
+//
+// `charset` must have been returned by `passgenr_charset_new` and not yet freed. The other
+// arguments are as for `passgenr_generate`.
+enum PassgenrStatus passgenr_generate_custom(const struct PassgenrCharset *charset,
+                                             size_t count,
+                                             const char *separator,
+                                             char *out_buf,
+                                             size_t out_len);
+
+// Generate a password like `passgenr_generate`, but into a newly-allocated string, which is
+// written to `*out` and must be freed with `passgenr_string_free`.
+//
+// # Safety
+//
+// `separator` must be a NUL-terminated string, and `out` must point to a writable `char *`.
+enum PassgenrStatus passgenr_generate_string(uint32_t charset_id,
+                                             size_t count,
+                                             const char *separator,
+                                             char **out);
+
+// Zero and free a string returned by `passgenr_generate_string`. Does nothing if `s` is NULL.
+//
+// # Safety
+//
+// `s` must have been returned by `passgenr_generate_string` and not yet freed.
+void passgenr_string_free(char *s);
+
+// Write the buffer size, including the terminating NUL, that is always big enough for
+// `passgenr_generate` with the same arguments to `*out_len`.
+//
+// # Safety
+//
+// `separator` must be a NUL-terminated string, and `out_len` must point to a writable `size_t`.
+enum PassgenrStatus passgenr_max_length(uint32_t charset_id,
+                                        size_t count,
+                                        const char *separator,
+                                        size_t *out_len);
+
+// Create a custom charset from an array of `count` strings, and write it to `*out`. It must be
+// freed with `passgenr_charset_free`.
+//
+// The strings are copied, so they may be freed as soon as this returns.
+//
+// # Safety
+//
+// `elements` must point to `count` NUL-terminated strings, and `out` must point to a writable
+// `PassgenrCharset *`.
+enum PassgenrStatus passgenr_charset_new(const char *const *elements,
+                                         size_t count,
+                                         struct PassgenrCharset **out);
+
+// Free a charset created with `passgenr_charset_new`. Does nothing if `charset` is NULL.
+//
+// # Safety
+//
+// `charset` must have been returned by `passgenr_charset_new` and not yet freed.
+void passgenr_charset_free(struct PassgenrCharset *charset);
+
+// A static, NUL-terminated description of `status`, or of an unknown status if it isn't one of
+// the `PassgenrStatus` values.
+//
+// `status` is an `int` rather than a `PassgenrStatus` because C allows any `int` in an enum
+// variable, while a Rust enum outside its declared values is undefined behavior.
+const char *passgenr_status_message(int status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* PASSGENR_H */
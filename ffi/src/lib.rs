@@ -0,0 +1,476 @@
+//! C bindings for passgenr, built as `libpassgenr_ffi` (both shared and static). The header,
+//! `include/passgenr.h`, is generated from this file with
+//!
+//! ```sh
+//! cbindgen --config cbindgen.toml --output include/passgenr.h
+//! ```
+//!
+//! Functions never panic across the FFI boundary. They return a `PassgenrStatus` and write their
+//! results through pointer arguments. Strings are NUL-terminated UTF-8.
+
+extern crate passgenr;
+extern crate rand_core;
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::panic::{self, UnwindSafe};
+use std::ptr;
+use std::slice;
+
+use passgenr::charsets::{self, Charset};
+use passgenr::fixed_buffer::{self, FixedBufferError};
+use rand_core::OsRng;
+
+/// The result of every function that can fail.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PassgenrStatus {
+    Ok = 0,
+    /// A required pointer argument was NULL.
+    NullPointer,
+    /// The charset ID isn't one of the `PASSGENR_CHARSET_*` constants.
+    UnknownCharset,
+    /// A string argument isn't valid UTF-8.
+    InvalidUtf8,
+    /// A custom charset has fewer than two elements.
+    CharsetTooSmall,
+    /// A custom charset has the same element more than once.
+    DuplicateElements,
+    /// Different sequences of elements can join to the same password with the separator, e.g.
+    /// because it occurs in an element, or is empty and the elements have different lengths.
+    Ambiguous,
+    /// The output buffer is too small. See `passgenr_max_length`.
+    BufferTooSmall,
+    /// The operating system's random number generator failed.
+    Rng,
+    /// A bug in passgenr. Please report it.
+    Panic,
+}
+
+impl PassgenrStatus {
+    /// Every status, in order of value.
+    const ALL: [PassgenrStatus; 10] = [
+        PassgenrStatus::Ok,
+        PassgenrStatus::NullPointer,
+        PassgenrStatus::UnknownCharset,
+        PassgenrStatus::InvalidUtf8,
+        PassgenrStatus::CharsetTooSmall,
+        PassgenrStatus::DuplicateElements,
+        PassgenrStatus::Ambiguous,
+        PassgenrStatus::BufferTooSmall,
+        PassgenrStatus::Rng,
+        PassgenrStatus::Panic,
+    ];
+
+    /// The status with the value `status`, if there is one.
+    fn from_raw(status: c_int) -> Option<PassgenrStatus> {
+        PassgenrStatus::ALL.iter().cloned().find(|&known| known as c_int == status)
+    }
+}
+
+// The built-in charsets, in the order of `charsets::NAMED`.
+pub const PASSGENR_CHARSET_UPPERCASE_HEX: u32 = 0;
+pub const PASSGENR_CHARSET_LOWERCASE_HEX: u32 = 1;
+pub const PASSGENR_CHARSET_ALPHANUMERIC: u32 = 2;
+pub const PASSGENR_CHARSET_ASCII: u32 = 3;
+pub const PASSGENR_CHARSET_DECIMAL_DIGIT: u32 = 4;
+pub const PASSGENR_CHARSET_LOWERCASE_ALPHABETIC: u32 = 5;
+pub const PASSGENR_CHARSET_BASE32: u32 = 6;
+pub const PASSGENR_CHARSET_CROCKFORD_BASE32: u32 = 7;
+pub const PASSGENR_CHARSET_BASE58: u32 = 8;
+pub const PASSGENR_CHARSET_BASE64URL: u32 = 9;
+pub const PASSGENR_CHARSET_WORDS: u32 = 10;
+pub const PASSGENR_CHARSET_BIP39_ENGLISH: u32 = 11;
+pub const PASSGENR_CHARSET_EMOJI: u32 = 12;
+pub const PASSGENR_CHARSET_GREEK_LOWERCASE: u32 = 13;
+pub const PASSGENR_CHARSET_HIRAGANA: u32 = 14;
+
+/// A charset created with `passgenr_charset_new`, which must be freed with
+/// `passgenr_charset_free`.
+pub struct PassgenrCharset {
+    elements: Vec<String>,
+}
+
+impl PassgenrCharset {
+    fn elements(&self) -> Vec<&str> {
+        self.elements.iter().map(String::as_str).collect()
+    }
+}
+
+/// Generate a password of `count` elements of a built-in charset, separated by `separator`, into
+/// `out_buf` as a NUL-terminated string.
+///
+/// On failure, `out_buf` holds an empty string (if `out_len` is nonzero).
+///
+/// # Safety
+///
+/// `separator` must be a NUL-terminated string, and `out_buf` must point to `out_len` writable
+/// bytes.
+#[no_mangle]
+pub unsafe extern "C" fn passgenr_generate(
+    charset_id: u32,
+    count: usize,
+    separator: *const c_char,
+    out_buf: *mut c_char,
+    out_len: usize
+) -> PassgenrStatus {
+    guard(|| {
+        let buffer = out_buffer(out_buf, out_len)?;
+        generate_into(builtin(charset_id)?, count, c_str(separator)?, buffer)
+    })
+}
+
+/// Like `passgenr_generate`, but with a custom charset.
+///
+/// # Safety
+///
+/// `charset` must have been returned by `passgenr_charset_new` and not yet freed. The other
+/// arguments are as for `passgenr_generate`.
+#[no_mangle]
+pub unsafe extern "C" fn passgenr_generate_custom(
+    charset: *const PassgenrCharset,
+    count: usize,
+    separator: *const c_char,
+    out_buf: *mut c_char,
+    out_len: usize
+) -> PassgenrStatus {
+    guard(|| {
+        let buffer = out_buffer(out_buf, out_len)?;
+        let charset = charset.as_ref().ok_or(PassgenrStatus::NullPointer)?;
+        generate_into(&charset.elements(), count, c_str(separator)?, buffer)
+    })
+}
+
+/// Generate a password like `passgenr_generate`, but into a newly-allocated string, which is
+/// written to `*out` and must be freed with `passgenr_string_free`.
+///
+/// # Safety
+///
+/// `separator` must be a NUL-terminated string, and `out` must point to a writable `char *`.
+#[no_mangle]
+pub unsafe extern "C" fn passgenr_generate_string(
+    charset_id: u32,
+    count: usize,
+    separator: *const c_char,
+    out: *mut *mut c_char
+) -> PassgenrStatus {
+    guard(|| {
+        let out = out.as_mut().ok_or(PassgenrStatus::NullPointer)?;
+        *out = ptr::null_mut();
+        let (charset, separator) = (builtin(charset_id)?, c_str(separator)?);
+        check_unambiguous(charset, separator)?;
+        let password = passgenr::random_password(charset, count, separator)
+            .map_err(|_| PassgenrStatus::Rng)?;
+        // Neither the built-in elements nor a separator read from a C string can contain NUL.
+        *out = CString::new(password).map_err(|_| PassgenrStatus::Panic)?.into_raw();
+        Ok(())
+    })
+}
+
+/// Zero and free a string returned by `passgenr_generate_string`. Does nothing if `s` is NULL.
+///
+/// # Safety
+///
+/// `s` must have been returned by `passgenr_generate_string` and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn passgenr_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        let mut bytes = CString::from_raw(s).into_bytes_with_nul();
+        for byte in bytes.iter_mut() {
+            ptr::write_volatile(byte, 0);
+        }
+    }
+}
+
+/// Write the buffer size, including the terminating NUL, that is always big enough for
+/// `passgenr_generate` with the same arguments to `*out_len`.
+///
+/// # Safety
+///
+/// `separator` must be a NUL-terminated string, and `out_len` must point to a writable `size_t`.
+#[no_mangle]
+pub unsafe extern "C" fn passgenr_max_length(
+    charset_id: u32,
+    count: usize,
+    separator: *const c_char,
+    out_len: *mut usize
+) -> PassgenrStatus {
+    guard(|| {
+        let out_len = out_len.as_mut().ok_or(PassgenrStatus::NullPointer)?;
+        *out_len = fixed_buffer::max_password_length(builtin(charset_id)?, count, c_str(separator)?) + 1;
+        Ok(())
+    })
+}
+
+/// Create a custom charset from an array of `count` strings, and write it to `*out`. It must be
+/// freed with `passgenr_charset_free`.
+///
+/// The strings are copied, so they may be freed as soon as this returns.
+///
+/// # Safety
+///
+/// `elements` must point to `count` NUL-terminated strings, and `out` must point to a writable
+/// `PassgenrCharset *`.
+#[no_mangle]
+pub unsafe extern "C" fn passgenr_charset_new(
+    elements: *const *const c_char,
+    count: usize,
+    out: *mut *mut PassgenrCharset
+) -> PassgenrStatus {
+    guard(|| {
+        let out = out.as_mut().ok_or(PassgenrStatus::NullPointer)?;
+        *out = ptr::null_mut();
+        if elements.is_null() {
+            return Err(PassgenrStatus::NullPointer);
+        }
+
+        let mut copied = Vec::with_capacity(count);
+        for &element in slice::from_raw_parts(elements, count) {
+            copied.push(c_str(element)?.to_owned());
+        }
+        if copied.len() < 2 {
+            return Err(PassgenrStatus::CharsetTooSmall);
+        }
+        let mut sorted = copied.clone();
+        sorted.sort();
+        sorted.dedup();
+        if sorted.len() != copied.len() {
+            return Err(PassgenrStatus::DuplicateElements);
+        }
+
+        *out = Box::into_raw(Box::new(PassgenrCharset { elements: copied }));
+        Ok(())
+    })
+}
+
+/// Free a charset created with `passgenr_charset_new`. Does nothing if `charset` is NULL.
+///
+/// # Safety
+///
+/// `charset` must have been returned by `passgenr_charset_new` and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn passgenr_charset_free(charset: *mut PassgenrCharset) {
+    if !charset.is_null() {
+        drop(Box::from_raw(charset));
+    }
+}
+
+/// A static, NUL-terminated description of `status`, or of an unknown status if it isn't one of
+/// the `PassgenrStatus` values.
+///
+/// `status` is an `int` rather than a `PassgenrStatus` because C allows any `int` in an enum
+/// variable, while a Rust enum outside its declared values is undefined behavior.
+#[no_mangle]
+pub extern "C" fn passgenr_status_message(status: c_int) -> *const c_char {
+    let message: &'static [u8] = match PassgenrStatus::from_raw(status) {
+        Some(PassgenrStatus::Ok) => b"success\0",
+        Some(PassgenrStatus::NullPointer) => b"a required pointer was NULL\0",
+        Some(PassgenrStatus::UnknownCharset) => b"unknown charset ID\0",
+        Some(PassgenrStatus::InvalidUtf8) => b"a string is not valid UTF-8\0",
+        Some(PassgenrStatus::CharsetTooSmall) => b"the charset has fewer than two elements\0",
+        Some(PassgenrStatus::DuplicateElements) => b"the charset contains duplicate elements\0",
+        Some(PassgenrStatus::Ambiguous) => b"the charset's elements can't be joined with the separator unambiguously\0",
+        Some(PassgenrStatus::BufferTooSmall) => b"the output buffer is too small\0",
+        Some(PassgenrStatus::Rng) => b"the random number generator failed\0",
+        Some(PassgenrStatus::Panic) => b"internal error\0",
+        None => b"unknown status\0",
+    };
+    message.as_ptr() as *const c_char
+}
+
+/// Run `f`, turning a panic into `PassgenrStatus::Panic`, since unwinding into C is undefined
+/// behavior.
+fn guard<F: FnOnce() -> Result<(), PassgenrStatus> + UnwindSafe>(f: F) -> PassgenrStatus {
+    match panic::catch_unwind(f) {
+        Ok(Ok(())) => PassgenrStatus::Ok,
+        Ok(Err(status)) => status,
+        Err(_) => PassgenrStatus::Panic,
+    }
+}
+
+fn builtin(charset_id: u32) -> Result<&'static dyn Charset<'static>, PassgenrStatus> {
+    charsets::NAMED.get(charset_id as usize)
+        .map(|&(_, charset)| charset)
+        .ok_or(PassgenrStatus::UnknownCharset)
+}
+
+unsafe fn c_str<'a>(s: *const c_char) -> Result<&'a str, PassgenrStatus> {
+    if s.is_null() {
+        return Err(PassgenrStatus::NullPointer);
+    }
+    CStr::from_ptr(s).to_str().map_err(|_| PassgenrStatus::InvalidUtf8)
+}
+
+unsafe fn out_buffer<'a>(out_buf: *mut c_char, out_len: usize) -> Result<&'a mut [u8], PassgenrStatus> {
+    if out_buf.is_null() {
+        return Err(PassgenrStatus::NullPointer);
+    }
+    let buffer = slice::from_raw_parts_mut(out_buf as *mut u8, out_len);
+    if let Some(first) = buffer.first_mut() {
+        *first = 0;
+    }
+    Ok(buffer)
+}
+
+/// `passgenr::random_password` and `fixed_buffer::random_password` panic instead.
+fn check_unambiguous(charset: &dyn Charset, separator: &str) -> Result<(), PassgenrStatus> {
+    if passgenr::is_unambiguous(charset, separator, None) {
+        Ok(())
+    } else {
+        Err(PassgenrStatus::Ambiguous)
+    }
+}
+
+fn generate_into(charset: &dyn Charset, count: usize, separator: &str, buffer: &mut [u8]) -> Result<(), PassgenrStatus> {
+    if buffer.is_empty() {
+        return Err(PassgenrStatus::BufferTooSmall);
+    }
+    check_unambiguous(charset, separator)?;
+    let end = buffer.len() - 1;
+    let length = match fixed_buffer::random_password(&mut OsRng, charset, count, separator, &mut buffer[..end]) {
+        Ok(password) => password.len(),
+        Err(FixedBufferError::BufferTooSmall) => return Err(PassgenrStatus::BufferTooSmall),
+        Err(FixedBufferError::Rng(_)) => return Err(PassgenrStatus::Rng),
+    };
+    buffer[length] = 0;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn charset_ids_match_names() {
+        let ids = [
+            (PASSGENR_CHARSET_UPPERCASE_HEX, "uppercase_hex"),
+            (PASSGENR_CHARSET_LOWERCASE_HEX, "lowercase_hex"),
+            (PASSGENR_CHARSET_ALPHANUMERIC, "alphanumeric"),
+            (PASSGENR_CHARSET_ASCII, "ascii"),
+            (PASSGENR_CHARSET_DECIMAL_DIGIT, "decimal_digit"),
+            (PASSGENR_CHARSET_LOWERCASE_ALPHABETIC, "lowercase_alphabetic"),
+            (PASSGENR_CHARSET_BASE32, "base32"),
+            (PASSGENR_CHARSET_CROCKFORD_BASE32, "crockford_base32"),
+            (PASSGENR_CHARSET_BASE58, "base58"),
+            (PASSGENR_CHARSET_BASE64URL, "base64url"),
+            (PASSGENR_CHARSET_WORDS, "words"),
+            (PASSGENR_CHARSET_BIP39_ENGLISH, "bip39_english"),
+            (PASSGENR_CHARSET_EMOJI, "emoji"),
+            (PASSGENR_CHARSET_GREEK_LOWERCASE, "greek_lowercase"),
+            (PASSGENR_CHARSET_HIRAGANA, "hiragana"),
+        ];
+        assert_eq!(ids.len(), charsets::NAMED.len());
+        for &(id, name) in ids.iter() {
+            assert_eq!(charsets::NAMED[id as usize].0, name);
+        }
+    }
+
+    #[test]
+    fn generate_into_buffer() {
+        let mut buffer = [0x55 as c_char; 21];
+        let status = unsafe {
+            passgenr_generate(PASSGENR_CHARSET_ASCII, 20, b"\0".as_ptr() as *const c_char, buffer.as_mut_ptr(), buffer.len())
+        };
+        assert_eq!(status, PassgenrStatus::Ok);
+        let password = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+        assert_eq!(password.to_bytes().len(), 20);
+
+        let status = unsafe {
+            passgenr_generate(PASSGENR_CHARSET_ASCII, 21, b"\0".as_ptr() as *const c_char, buffer.as_mut_ptr(), buffer.len())
+        };
+        assert_eq!(status, PassgenrStatus::BufferTooSmall);
+        assert_eq!(buffer[0], 0);
+    }
+
+    #[test]
+    fn errors_instead_of_panics() {
+        let mut buffer = [0 as c_char; 8];
+        let sep = b"\0".as_ptr() as *const c_char;
+        unsafe {
+            assert_eq!(passgenr_generate(99, 1, sep, buffer.as_mut_ptr(), 8), PassgenrStatus::UnknownCharset);
+            assert_eq!(passgenr_generate(0, 1, ptr::null(), buffer.as_mut_ptr(), 8), PassgenrStatus::NullPointer);
+            assert_eq!(passgenr_generate(0, 1, sep, ptr::null_mut(), 8), PassgenrStatus::NullPointer);
+            assert_eq!(passgenr_generate(0, 0, sep, buffer.as_mut_ptr(), 0), PassgenrStatus::BufferTooSmall);
+            let invalid = b"\xff\0".as_ptr() as *const c_char;
+            assert_eq!(passgenr_generate(0, 2, invalid, buffer.as_mut_ptr(), 8), PassgenrStatus::InvalidUtf8);
+        }
+    }
+
+    #[test]
+    fn custom_charsets() {
+        let elements = [b"ab\0".as_ptr() as *const c_char, b"cd\0".as_ptr() as *const c_char];
+        let mut charset = ptr::null_mut();
+        let mut buffer = [0 as c_char; 16];
+        unsafe {
+            assert_eq!(passgenr_charset_new(elements.as_ptr(), 2, &mut charset), PassgenrStatus::Ok);
+            let sep = b"-\0".as_ptr() as *const c_char;
+            assert_eq!(passgenr_generate_custom(charset, 3, sep, buffer.as_mut_ptr(), 16), PassgenrStatus::Ok);
+            let password = CStr::from_ptr(buffer.as_ptr()).to_str().unwrap();
+            assert_eq!(password.len(), 8);
+            assert!(password.split('-').all(|elt| elt == "ab" || elt == "cd"));
+            passgenr_charset_free(charset);
+
+            assert_eq!(passgenr_charset_new(elements.as_ptr(), 1, &mut charset), PassgenrStatus::CharsetTooSmall);
+            assert!(charset.is_null());
+            let duplicates = [elements[0], elements[0]];
+            assert_eq!(passgenr_charset_new(duplicates.as_ptr(), 2, &mut charset), PassgenrStatus::DuplicateElements);
+            assert_eq!(passgenr_generate_custom(ptr::null(), 1, sep, buffer.as_mut_ptr(), 16), PassgenrStatus::NullPointer);
+            passgenr_charset_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn ambiguous_separators() {
+        let mut buffer = [0 as c_char; 128];
+        let mut password = ptr::null_mut();
+        let empty = b"\0".as_ptr() as *const c_char;
+        unsafe {
+            assert_eq!(passgenr_generate(PASSGENR_CHARSET_WORDS, 6, empty, buffer.as_mut_ptr(), 128), PassgenrStatus::Ambiguous);
+            assert_eq!(buffer[0], 0);
+            assert_eq!(passgenr_generate_string(PASSGENR_CHARSET_WORDS, 6, empty, &mut password), PassgenrStatus::Ambiguous);
+            assert!(password.is_null());
+
+            let elements = [b"a\0".as_ptr() as *const c_char, b"a.b\0".as_ptr() as *const c_char, b"b\0".as_ptr() as *const c_char];
+            let mut charset = ptr::null_mut();
+            assert_eq!(passgenr_charset_new(elements.as_ptr(), 3, &mut charset), PassgenrStatus::Ok);
+            let dot = b".\0".as_ptr() as *const c_char;
+            assert_eq!(passgenr_generate_custom(charset, 4, dot, buffer.as_mut_ptr(), 128), PassgenrStatus::Ambiguous);
+            passgenr_charset_free(charset);
+        }
+    }
+
+    #[test]
+    fn allocated_strings() {
+        let mut password = ptr::null_mut();
+        unsafe {
+            let sep = b" \0".as_ptr() as *const c_char;
+            assert_eq!(passgenr_generate_string(PASSGENR_CHARSET_WORDS, 6, sep, &mut password), PassgenrStatus::Ok);
+            assert_eq!(CStr::from_ptr(password).to_str().unwrap().split(' ').count(), 6);
+            passgenr_string_free(password);
+            passgenr_string_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn max_lengths() {
+        let mut length = 0;
+        unsafe {
+            let sep = b"--\0".as_ptr() as *const c_char;
+            assert_eq!(passgenr_max_length(PASSGENR_CHARSET_DECIMAL_DIGIT, 4, sep, &mut length), PassgenrStatus::Ok);
+        }
+        assert_eq!(length, 4 + 3 * 2 + 1);
+    }
+
+    #[test]
+    fn status_messages() {
+        let message = |status| unsafe { CStr::from_ptr(passgenr_status_message(status)) }.to_str().unwrap();
+        assert_eq!(message(PassgenrStatus::BufferTooSmall as c_int), "the output buffer is too small");
+        for (value, &status) in PassgenrStatus::ALL.iter().enumerate() {
+            assert_eq!(status as c_int, value as c_int);
+            assert_ne!(message(status as c_int), "unknown status");
+        }
+        assert_eq!(message(PassgenrStatus::ALL.len() as c_int), "unknown status");
+        assert_eq!(message(-1), "unknown status");
+        assert_eq!(message(c_int::MAX), "unknown status");
+    }
+}
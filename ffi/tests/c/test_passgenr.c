@@ -0,0 +1,91 @@
+/* Exercises the C API through the generated header. Built and run by tests/c_api.rs. */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "passgenr.h"
+
+static int failures = 0;
+
+#define CHECK(cond) do { \
+    if (!(cond)) { \
+        fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #cond); \
+        failures++; \
+    } \
+} while (0)
+
+#define CHECK_STATUS(call, expected) do { \
+    PassgenrStatus status_ = (call); \
+    if (status_ != (expected)) { \
+        fprintf(stderr, "%s:%d: %s returned %d (%s)\n", __FILE__, __LINE__, #call, \
+                (int)status_, passgenr_status_message(status_)); \
+        failures++; \
+    } \
+} while (0)
+
+static void test_generate(void) {
+    char buf[65];
+    CHECK_STATUS(passgenr_generate(PASSGENR_CHARSET_ALPHANUMERIC, 64, "", buf, sizeof buf), PASSGENR_STATUS_OK);
+    CHECK(strlen(buf) == 64);
+
+    size_t needed = 0;
+    CHECK_STATUS(passgenr_max_length(PASSGENR_CHARSET_DECIMAL_DIGIT, 8, "-", &needed), PASSGENR_STATUS_OK);
+    CHECK(needed == 16);
+    CHECK_STATUS(passgenr_generate(PASSGENR_CHARSET_DECIMAL_DIGIT, 8, "-", buf, needed), PASSGENR_STATUS_OK);
+    CHECK(strlen(buf) == 15);
+    CHECK(buf[1] == '-' && buf[13] == '-');
+}
+
+static void test_errors(void) {
+    char buf[8];
+    CHECK_STATUS(passgenr_generate(PASSGENR_CHARSET_ASCII, 8, "", buf, sizeof buf), PASSGENR_STATUS_BUFFER_TOO_SMALL);
+    CHECK(buf[0] == '\0');
+    CHECK_STATUS(passgenr_generate(1000, 4, "", buf, sizeof buf), PASSGENR_STATUS_UNKNOWN_CHARSET);
+    CHECK_STATUS(passgenr_generate(PASSGENR_CHARSET_ASCII, 4, NULL, buf, sizeof buf), PASSGENR_STATUS_NULL_POINTER);
+    CHECK_STATUS(passgenr_generate(PASSGENR_CHARSET_ASCII, 4, "\xff", buf, sizeof buf), PASSGENR_STATUS_INVALID_UTF8);
+    CHECK(strcmp(passgenr_status_message(PASSGENR_STATUS_OK), "success") == 0);
+    CHECK(strcmp(passgenr_status_message(1000), "unknown status") == 0);
+    CHECK(strcmp(passgenr_status_message(-1), "unknown status") == 0);
+}
+
+static void test_custom_charset(void) {
+    const char *elements[] = { "red", "green", "blue" };
+    PassgenrCharset *charset = NULL;
+    CHECK_STATUS(passgenr_charset_new(elements, 3, &charset), PASSGENR_STATUS_OK);
+    CHECK(charset != NULL);
+
+    char buf[64];
+    CHECK_STATUS(passgenr_generate_custom(charset, 4, " ", buf, sizeof buf), PASSGENR_STATUS_OK);
+    int words = 1;
+    for (char *c = buf; *c; c++) {
+        words += *c == ' ';
+    }
+    CHECK(words == 4);
+    passgenr_charset_free(charset);
+
+    const char *duplicates[] = { "red", "red" };
+    CHECK_STATUS(passgenr_charset_new(duplicates, 2, &charset), PASSGENR_STATUS_DUPLICATE_ELEMENTS);
+    CHECK(charset == NULL);
+    CHECK_STATUS(passgenr_charset_new(elements, 1, &charset), PASSGENR_STATUS_CHARSET_TOO_SMALL);
+    passgenr_charset_free(NULL);
+}
+
+static void test_allocated_string(void) {
+    char *password = NULL;
+    CHECK_STATUS(passgenr_generate_string(PASSGENR_CHARSET_BIP39_ENGLISH, 12, " ", &password), PASSGENR_STATUS_OK);
+    CHECK(password != NULL && strlen(password) >= 12 * 3 + 11);
+    passgenr_string_free(password);
+    passgenr_string_free(NULL);
+}
+
+int main(void) {
+    test_generate();
+    test_errors();
+    test_custom_charset();
+    test_allocated_string();
+    if (failures == 0) {
+        printf("All C API checks passed.\n");
+    }
+    return failures == 0 ? EXIT_SUCCESS : EXIT_FAILURE;
+}
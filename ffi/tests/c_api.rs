@@ -0,0 +1,42 @@
+//! Compiles `tests/c/test_passgenr.c` against the generated header and the shared library, and
+//! runs it. The C compiler is `$CC`, or `cc` if that isn't set.
+
+#![cfg(unix)]
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The directory holding `libpassgenr_ffi`, which is the parent of this test's `deps` directory.
+fn library_dir() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    exe.parent().and_then(|deps| deps.parent()).unwrap().to_path_buf()
+}
+
+#[test]
+fn c_test_program() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let library_dir = library_dir();
+    let program = library_dir.join("test_passgenr_c");
+
+    let compiled = Command::new(env::var("CC").unwrap_or_else(|_| "cc".to_owned()))
+        .arg("-std=c99")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I").arg(manifest_dir.join("include"))
+        .arg(manifest_dir.join("tests/c/test_passgenr.c"))
+        .arg("-o").arg(&program)
+        .arg("-L").arg(&library_dir)
+        .arg(format!("-Wl,-rpath,{}", library_dir.display()))
+        .arg("-lpassgenr_ffi")
+        .status()
+        .expect("Couldn't run the C compiler");
+    assert!(compiled.success(), "The C test program didn't compile");
+
+    let output = Command::new(&program).output().unwrap();
+    assert!(
+        output.status.success(),
+        "The C test program failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
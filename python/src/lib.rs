@@ -0,0 +1,147 @@
+//! Python bindings for passgenr. Build and test them with maturin:
+//!
+//! ```sh
+//! cd python
+//! maturin develop --extras test
+//! pytest
+//! ```
+//!
+//! Charsets can be given by name (see `charsets::NAMED`) or as a list of elements. Invalid
+//! arguments raise `ValueError` instead of panicking, password rules that can't be parsed or
+//! satisfied raise `PasswordPolicyError` (a `ValueError`), and RNG failures raise `OSError`.
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use passgenr::charsets;
+use passgenr::rules::PasswordPolicy;
+
+create_exception!(passgenr, PasswordPolicyError, PyValueError, "Password rules that can't be parsed or satisfied.");
+
+/// The length `PasswordPolicy.random_password` aims for when none is given, as in the CLI.
+const DEFAULT_POLICY_LENGTH: usize = 64;
+
+/// A charset argument: the name of a built-in charset, or a list of elements.
+#[derive(FromPyObject)]
+enum Charset {
+    Name(String),
+    Elements(Vec<String>),
+}
+
+impl Charset {
+    /// The elements, checked for the conditions under which the library would panic.
+    fn elements(&self) -> PyResult<Vec<&str>> {
+        let elements: Vec<&str> = match *self {
+            Charset::Name(ref name) => charsets::by_name(name)
+                .ok_or_else(|| PyValueError::new_err(format!("unknown charset \"{}\"", name)))?
+                .to_vec(),
+            Charset::Elements(ref elements) => elements.iter().map(String::as_str).collect(),
+        };
+
+        if elements.len() < 2 {
+            return Err(PyValueError::new_err("the charset must have at least two elements"));
+        }
+        let mut sorted = elements.clone();
+        sorted.sort();
+        sorted.dedup();
+        if sorted.len() != elements.len() {
+            return Err(PyValueError::new_err("the charset contains duplicate elements"));
+        }
+        Ok(elements)
+    }
+}
+
+/// random_password(charset, count, separator="")
+/// --
+///
+/// Randomly generate a password of `count` elements of `charset`, separated by `separator`.
+/// Charsets such as "words" need a separator that doesn't occur in their elements, because
+/// otherwise different sequences of words can give the same password.
+#[pyfunction]
+#[pyo3(signature = (charset, count, separator = ""))]
+fn random_password(charset: Charset, count: usize, separator: &str) -> PyResult<String> {
+    let elements = charset.elements()?;
+    if !passgenr::is_unambiguous(&elements, separator, None) {
+        return Err(PyValueError::new_err("the charset's elements can't be joined with this separator unambiguously"));
+    }
+    Ok(passgenr::random_password(&elements, count, separator)?)
+}
+
+/// The entropy, in bits, of a password of `count` elements of `charset`.
+#[pyfunction]
+fn entropy_bits(charset: Charset, count: usize) -> PyResult<f64> {
+    Ok(passgenr::entropy_bits(&charset.elements()?, count))
+}
+
+/// A password policy in the `passwordrules` syntax, e.g.
+/// `"minlength: 12; required: upper; required: digit; allowed: lower;"`.
+#[pyclass(name = "PasswordPolicy", module = "passgenr", frozen)]
+struct PyPasswordPolicy {
+    policy: PasswordPolicy,
+    rules: String,
+}
+
+#[pymethods]
+impl PyPasswordPolicy {
+    #[new]
+    fn new(rules: &str) -> PyResult<PyPasswordPolicy> {
+        match PasswordPolicy::parse(rules) {
+            Ok(policy) => Ok(PyPasswordPolicy { policy, rules: rules.to_owned() }),
+            Err(err) => Err(PasswordPolicyError::new_err(err.to_string())),
+        }
+    }
+
+    /// The length closest to `length` that the policy allows.
+    fn clamp_length(&self, length: usize) -> usize {
+        self.policy.clamp_length(length)
+    }
+
+    /// Whether `password` satisfies all of the rules.
+    fn is_compliant(&self, password: &str) -> bool {
+        let chars: Vec<String> = password.chars().map(String::from).collect();
+        let chars: Vec<&str> = chars.iter().map(String::as_str).collect();
+        self.policy.is_compliant(&chars)
+    }
+
+    /// The entropy, in bits, of a compliant password of `length` characters, or `-inf` if there
+    /// are none.
+    fn entropy_bits(&self, length: usize) -> f64 {
+        self.policy.entropy_bits(length)
+    }
+
+    /// random_password(length=None)
+    /// --
+    ///
+    /// Randomly generate a compliant password of `length` characters, or of the allowed length
+    /// closest to 64 if `length` is not given.
+    #[pyo3(signature = (length = None))]
+    fn random_password(&self, length: Option<usize>) -> PyResult<String> {
+        let length = length.unwrap_or_else(|| self.policy.clamp_length(DEFAULT_POLICY_LENGTH));
+        if self.policy.entropy_bits(length) == f64::NEG_INFINITY {
+            return Err(PasswordPolicyError::new_err(format!("no password of length {} satisfies the rules", length)));
+        }
+        Ok(self.policy.random_password(length)?)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PasswordPolicy({:?})", self.rules)
+    }
+}
+
+#[pymodule]
+#[pyo3(name = "passgenr")]
+fn passgenr_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(random_password, m)?)?;
+    m.add_function(wrap_pyfunction!(entropy_bits, m)?)?;
+    m.add_class::<PyPasswordPolicy>()?;
+    m.add("PasswordPolicyError", m.py().get_type::<PasswordPolicyError>())?;
+
+    let named = PyDict::new(m.py());
+    for &(name, charset) in charsets::NAMED {
+        named.set_item(name, charset.to_vec())?;
+    }
+    m.add("CHARSETS", named)?;
+    Ok(())
+}
@@ -0,0 +1,134 @@
+"""Tests for the passgenr extension module. Run `maturin develop --extras test` first."""
+
+import math
+
+import pytest
+
+import passgenr
+
+
+def test_named_charset():
+    password = passgenr.random_password("alphanumeric", 32)
+    assert len(password) == 32
+    assert all(c in passgenr.CHARSETS["alphanumeric"] for c in password)
+
+
+def test_emoji():
+    code = passgenr.random_password("emoji", 8)
+    # every emoji is one code point, but three or four bytes of UTF-8
+    assert len(code) == 8
+    assert 24 <= len(code.encode("utf-8")) <= 32
+
+
+def test_charset_names_are_case_insensitive():
+    assert len(passgenr.random_password("BASE58", 22)) == 22
+
+
+def test_separator():
+    password = passgenr.random_password("words", 6, " ")
+    words = password.split(" ")
+    assert len(words) == 6
+    assert all(word in passgenr.CHARSETS["words"] for word in words)
+
+
+def test_separator_keyword():
+    assert passgenr.random_password("decimal_digit", 4, separator="-").count("-") == 3
+
+
+def test_custom_charset():
+    password = passgenr.random_password(["red", "green", "blue"], 5, ",")
+    assert all(word in ("red", "green", "blue") for word in password.split(","))
+
+
+def test_zero_count():
+    assert passgenr.random_password("ascii", 0) == ""
+
+
+def test_charsets():
+    assert set(passgenr.CHARSETS) == {
+        "uppercase_hex", "lowercase_hex", "alphanumeric", "ascii", "decimal_digit",
+        "lowercase_alphabetic", "base32", "crockford_base32", "base58", "base64url", "words",
+        "bip39_english", "emoji", "greek_lowercase", "hiragana",
+    }
+    assert len(passgenr.CHARSETS["bip39_english"]) == 2048
+    assert passgenr.CHARSETS["decimal_digit"] == list("0123456789")
+    assert len(passgenr.CHARSETS["emoji"]) == 256
+
+
+def test_entropy_bits():
+    assert passgenr.entropy_bits("uppercase_hex", 64) == 256.0
+    assert passgenr.entropy_bits("bip39_english", 12) == 132.0
+    assert passgenr.entropy_bits(["a", "b", "c", "d"], 3) == 6.0
+    assert passgenr.entropy_bits("decimal_digit", 0) == 0.0
+
+
+@pytest.mark.parametrize("charset", ["klingon", "", ["a"], [], ["a", "a"]])
+def test_invalid_charsets(charset):
+    with pytest.raises(ValueError):
+        passgenr.random_password(charset, 8)
+    with pytest.raises(ValueError):
+        passgenr.entropy_bits(charset, 8)
+
+
+def test_ambiguous_separators():
+    with pytest.raises(ValueError, match="separator"):
+        passgenr.random_password("words", 6)
+    assert passgenr.random_password(["0", "10", "11"], 4) != ""
+    with pytest.raises(ValueError, match="separator"):
+        passgenr.random_password(["a", "a.b", "b"], 4, ".")
+
+
+def test_invalid_arguments():
+    with pytest.raises(TypeError):
+        passgenr.random_password(42, 8)
+    with pytest.raises(OverflowError):
+        passgenr.random_password("ascii", -1)
+
+
+def test_policy():
+    policy = passgenr.PasswordPolicy(
+        "minlength: 12; maxlength: 16; required: upper; required: digit; allowed: lower, [-_];"
+    )
+    assert policy.clamp_length(64) == 16
+    assert policy.clamp_length(4) == 12
+
+    password = policy.random_password()
+    assert len(password) == 16
+    assert any(c.isupper() for c in password)
+    assert any(c.isdigit() for c in password)
+    assert policy.is_compliant(password)
+
+    assert len(policy.random_password(12)) == 12
+    assert not policy.is_compliant("abcdefghijkl")
+    assert not policy.is_compliant("Abc1")
+
+
+def test_policy_entropy():
+    policy = passgenr.PasswordPolicy("required: digit;")
+    assert policy.entropy_bits(10) == pytest.approx(10 * math.log2(10))
+    assert passgenr.PasswordPolicy("maxlength: 4;").entropy_bits(5) == -math.inf
+
+
+def test_policy_repr():
+    assert repr(passgenr.PasswordPolicy("required: digit;")) == "PasswordPolicy(\"required: digit;\")"
+
+
+@pytest.mark.parametrize("rules", [
+    "bogus: 1;",
+    "required: vowels;",
+    "minlength: twelve;",
+    "minlength: 10; maxlength: 8;",
+])
+def test_invalid_rules(rules):
+    with pytest.raises(passgenr.PasswordPolicyError):
+        passgenr.PasswordPolicy(rules)
+
+
+def test_unsatisfiable_length():
+    policy = passgenr.PasswordPolicy("maxlength: 8;")
+    with pytest.raises(passgenr.PasswordPolicyError):
+        policy.random_password(9)
+
+
+def test_policy_error_is_value_error():
+    assert issubclass(passgenr.PasswordPolicyError, ValueError)
@@ -0,0 +1,251 @@
+//! Quality metrics for wordlists, to check a list before using it as a charset.
+//!
+//!     use passgenr::analysis::{analyze, OFFENSIVE_WORDS};
+//!
+//!     let analysis = analyze(&["cat", "cap", "cattle", "dog"], OFFENSIVE_WORDS);
+//!     assert_eq!(analysis.prefix_pairs, vec![("cat", "cattle")]);
+//!     assert_eq!(analysis.edit_distance_one_pairs, vec![("cap", "cat")]);
+//!     assert_eq!(analysis.average_length, 3.75);
+//!
+//! Prefixes matter when words are joined without a separator: "cat" + "tle..." and "cattle" +
+//! "..." can spell the same thing, so fewer distinct passphrases exist than the entropy suggests.
+//! Words at edit distance 1 are easily mistaken for each other when a passphrase is read aloud or
+//! mistyped.
+//!
+//! Prefixes alone don't make a list unusable without a separator. `is_uniquely_decodable` decides
+//! whether every concatenation of words can be split back into words in only one way, in which
+//! case joining them loses no entropy:
+//!
+//!     use passgenr::analysis::is_uniquely_decodable;
+//!
+//!     assert!(is_uniquely_decodable(&["0", "01", "11"]));
+//!     // "ab" + "ab" == "a" + "bab"
+//!     assert!(!is_uniquely_decodable(&["a", "ab", "bab"]));
+
+use std::collections::{HashMap, HashSet};
+
+/// A short list of English profanity, slurs and sexual terms, matched against whole words
+/// ignoring case. It isn't exhaustive; pass a longer list to `analyze` where that matters.
+pub const OFFENSIVE_WORDS: &[&str] = &[
+    "anal", "anus", "arse", "ass", "asshole", "bastard", "bitch", "bollocks", "boner", "boob",
+    "boobs", "clit", "cock", "crap", "cum", "cunt", "dick", "dildo", "dyke", "fag", "faggot",
+    "fuck", "fucker", "fucking", "horny", "jizz", "kike", "nazi", "nigga", "nigger", "orgasm",
+    "penis", "piss", "porn", "prick", "pussy", "rape", "rapist", "retard", "scrotum", "semen",
+    "shit", "slut", "spic", "tits", "tranny", "twat", "vagina", "wank", "wetback", "whore",
+];
+
+/// The results of `analyze`. Pairs are in sorted order.
+#[derive(Clone, PartialEq, Debug)]
+pub struct WordlistAnalysis<'a> {
+    pub word_count: usize,
+    /// Words that appear more than once, which would make some passwords more likely.
+    pub duplicates: Vec<&'a str>,
+    /// The shortest and longest word, in characters.
+    pub min_length: usize,
+    pub max_length: usize,
+    pub average_length: f64,
+    /// Pairs of a word and a longer word that starts with it.
+    pub prefix_pairs: Vec<(&'a str, &'a str)>,
+    /// Pairs of words that differ by one inserted, deleted or substituted character.
+    pub edit_distance_one_pairs: Vec<(&'a str, &'a str)>,
+    /// Words that are in the blocklist.
+    pub offensive_words: Vec<&'a str>,
+    /// Whether the words can be joined without a separator, see `is_uniquely_decodable`.
+    pub uniquely_decodable: bool,
+}
+
+/// Compute the metrics for `words`, reporting the words in `blocklist` as offensive.
+pub fn analyze<'a>(words: &[&'a str], blocklist: &[&str]) -> WordlistAnalysis<'a> {
+    let mut sorted = words.to_vec();
+    sorted.sort();
+
+    let mut duplicates: Vec<&str> = sorted.windows(2).filter(|pair| pair[0] == pair[1]).map(|pair| pair[0]).collect();
+    duplicates.dedup();
+    sorted.dedup();
+
+    let lengths: Vec<usize> = words.iter().map(|word| word.chars().count()).collect();
+    let average_length = if words.is_empty() {
+        0.0
+    } else {
+        lengths.iter().sum::<usize>() as f64 / words.len() as f64
+    };
+
+    let blocklist: HashSet<String> = blocklist.iter().map(|word| word.to_lowercase()).collect();
+    let offensive_words = sorted.iter().cloned().filter(|word| blocklist.contains(&word.to_lowercase())).collect();
+
+    WordlistAnalysis {
+        word_count: words.len(),
+        duplicates,
+        min_length: lengths.iter().cloned().min().unwrap_or(0),
+        max_length: lengths.iter().cloned().max().unwrap_or(0),
+        average_length,
+        prefix_pairs: prefix_pairs(&sorted),
+        edit_distance_one_pairs: edit_distance_one_pairs(&sorted),
+        offensive_words,
+        uniquely_decodable: is_uniquely_decodable(words),
+    }
+}
+
+/// Whether no string can be split into a sequence of `words` in two different ways, using the
+/// Sardinas–Patterson algorithm. Passwords made by joining elements of such a charset with an
+/// empty separator are as strong as `entropy_bits` says. Lists with duplicates or an empty word
+/// are never uniquely decodable, while lists where all words have the same length always are.
+///
+/// The test considers sequences of any length, so a list can fail it even though no two
+/// sequences of one particular length collide.
+pub fn is_uniquely_decodable(words: &[&str]) -> bool {
+    let mut sorted = words.to_vec();
+    sorted.sort();
+    if sorted.first() == Some(&"") || sorted.windows(2).any(|pair| pair[0] == pair[1]) {
+        return false;
+    }
+    let codewords: HashSet<&str> = sorted.iter().cloned().collect();
+
+    // A dangling suffix is what is left over when one sequence of words is a prefix of another.
+    // The list is ambiguous exactly when some dangling suffix is itself a word.
+    let mut seen = HashSet::new();
+    let mut pending = Vec::new();
+    for (word, longer) in prefix_pairs(&sorted) {
+        let suffix = &longer[word.len()..];
+        if seen.insert(suffix) {
+            pending.push(suffix);
+        }
+    }
+
+    while let Some(suffix) = pending.pop() {
+        if codewords.contains(suffix) {
+            return false;
+        }
+
+        let start = sorted.partition_point(|&word| word < suffix);
+        let extensions = sorted[start..].iter().take_while(|word| word.starts_with(suffix)).map(|word| &word[suffix.len()..]);
+        let remainders = suffix.char_indices().skip(1)
+            .filter(|&(i, _)| codewords.contains(&suffix[..i]))
+            .map(|(i, _)| &suffix[i..]);
+        for next in extensions.chain(remainders) {
+            if seen.insert(next) {
+                pending.push(next);
+            }
+        }
+    }
+    true
+}
+
+/// In sorted order, the words starting with `word` come right after it.
+fn prefix_pairs<'a>(sorted: &[&'a str]) -> Vec<(&'a str, &'a str)> {
+    let mut pairs = Vec::new();
+    for (i, &word) in sorted.iter().enumerate() {
+        for &longer in sorted[i + 1..].iter().take_while(|longer| longer.starts_with(word)) {
+            pairs.push((word, longer));
+        }
+    }
+    pairs
+}
+
+/// Two words differ by one substitution at position `i` if deleting their `i`th characters makes
+/// them equal, and by one insertion if deleting a character from the longer one gives the
+/// shorter. Indexing the deletions avoids comparing every pair of words.
+fn edit_distance_one_pairs<'a>(sorted: &[&'a str]) -> Vec<(&'a str, &'a str)> {
+    let words: HashSet<&str> = sorted.iter().cloned().collect();
+    let mut substitutions: HashMap<(usize, String), Vec<&str>> = HashMap::new();
+    let mut pairs = HashSet::new();
+
+    for &word in sorted {
+        let chars: Vec<char> = word.chars().collect();
+        for i in 0..chars.len() {
+            let deleted: String = chars[..i].iter().chain(&chars[i + 1..]).collect();
+            if let Some(&shorter) = words.get(deleted.as_str()) {
+                pairs.insert(if shorter < word { (shorter, word) } else { (word, shorter) });
+            }
+            substitutions.entry((i, deleted)).or_default().push(word);
+        }
+    }
+
+    for similar in substitutions.values() {
+        for (i, &a) in similar.iter().enumerate() {
+            for &b in &similar[i + 1..] {
+                pairs.insert((a, b));
+            }
+        }
+    }
+
+    let mut pairs: Vec<(&str, &str)> = pairs.into_iter().collect();
+    pairs.sort();
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::charsets::{self, Charset};
+
+    #[test]
+    fn metrics() {
+        let words = ["dog", "cat", "cart", "cattle", "ca", "dog", "Anus", "cut", "éa"];
+        let analysis = analyze(&words, OFFENSIVE_WORDS);
+        assert_eq!(analysis.word_count, 9);
+        assert_eq!(analysis.duplicates, vec!["dog"]);
+        assert_eq!((analysis.min_length, analysis.max_length), (2, 6));
+        assert_eq!(analysis.average_length, 30.0 / 9.0);
+        assert_eq!(analysis.prefix_pairs, vec![("ca", "cart"), ("ca", "cat"), ("ca", "cattle"), ("cat", "cattle")]);
+        assert_eq!(
+            analysis.edit_distance_one_pairs,
+            vec![("ca", "cat"), ("ca", "éa"), ("cart", "cat"), ("cat", "cut")]
+        );
+        assert_eq!(analysis.offensive_words, vec!["Anus"]);
+        // Only because of the duplicate: no dangling suffix ("rt", "t", "tle", "ttle") is a word.
+        assert!(!analysis.uniquely_decodable);
+        assert!(is_uniquely_decodable(&words[1..]));
+    }
+
+    #[test]
+    fn unique_decodability() {
+        // Prefix-free.
+        assert!(is_uniquely_decodable(&["0", "10", "110", "111"]));
+        assert!(is_uniquely_decodable(charsets::ASCII));
+        assert!(is_uniquely_decodable(&[]));
+        // Not prefix-free, but suffix-free.
+        assert!(is_uniquely_decodable(&["a", "ab", "abb"]));
+
+        // "a" + "b" == "ab"
+        assert!(!is_uniquely_decodable(&["a", "b", "ab"]));
+        // "ab" + "ba" == "a" + "bb" + "a"
+        assert!(!is_uniquely_decodable(&["a", "ab", "bb", "ba"]));
+        assert!(!is_uniquely_decodable(&["a", "a"]));
+        assert!(!is_uniquely_decodable(&["", "a"]));
+    }
+
+    #[test]
+    fn empty_wordlist() {
+        let analysis = analyze(&[], OFFENSIVE_WORDS);
+        assert_eq!(analysis.word_count, 0);
+        assert_eq!(analysis.average_length, 0.0);
+        assert!(analysis.prefix_pairs.is_empty());
+    }
+
+    /// The wordlist predates this analysis. Removing words would change the passphrases that
+    /// existing seeds and known answers produce, so the findings are recorded here instead, and
+    /// any change to them has to be deliberate.
+    #[test]
+    fn builtin_wordlist() {
+        let words: Vec<&str> = include_str!("../resources/wordlists/words.txt").lines().filter(|line| !line.is_empty()).collect();
+        assert_eq!(words, charsets::WORDS.to_vec());
+
+        let analysis = analyze(&words, OFFENSIVE_WORDS);
+        assert_eq!(analysis.word_count, 7236);
+        assert!(analysis.duplicates.is_empty());
+        assert_eq!((analysis.min_length, analysis.max_length), (2, 15));
+        assert!((analysis.average_length - 6.07).abs() < 0.01);
+        assert_eq!(analysis.prefix_pairs.len(), 4260);
+        assert_eq!(analysis.edit_distance_one_pairs.len(), 10805);
+        // e.g. "act" + "ion" == "action"
+        assert!(!analysis.uniquely_decodable);
+        assert_eq!(
+            analysis.offensive_words,
+            vec![
+                "anus", "ass", "bastard", "bitch", "cock", "faggot", "nazi", "penis", "prick", "rape",
+                "rapist", "retard", "semen", "slut", "vagina", "whore",
+            ]
+        );
+    }
+}
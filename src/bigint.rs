@@ -0,0 +1,221 @@
+//! Just enough arbitrary-precision arithmetic to count compliant passwords exactly, for the `rules`
+//! module. Counts easily exceed `u128`: there are 94^64, about 2^419, ASCII passwords of 64
+//! characters.
+
+use std::cmp::Ordering;
+
+use super::rand_core::{CryptoRng, Error, RngCore};
+
+/// A nonnegative integer, as little-endian 32-bit limbs with no trailing zero limbs.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> BigUint {
+        BigUint { limbs: Vec::new() }
+    }
+
+    pub fn from_u32(n: u32) -> BigUint {
+        let mut result = BigUint { limbs: vec![n] };
+        result.normalize();
+        result
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    /// The number of bits needed to write the number.
+    pub fn bits(&self) -> usize {
+        match self.limbs.last() {
+            Some(&top) => 32 * self.limbs.len() - top.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    pub fn add(&self, other: &BigUint) -> BigUint {
+        let (long, short) = if self.limbs.len() >= other.limbs.len() { (self, other) } else { (other, self) };
+        let mut limbs = Vec::with_capacity(long.limbs.len() + 1);
+        let mut carry = 0u64;
+        for (i, &limb) in long.limbs.iter().enumerate() {
+            let sum = u64::from(limb) + u64::from(short.limbs.get(i).cloned().unwrap_or(0)) + carry;
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        if carry != 0 {
+            limbs.push(carry as u32);
+        }
+        BigUint { limbs }
+    }
+
+    /// `self - other`.
+    ///
+    /// The call will panic if `other` is greater than `self`.
+    pub fn sub(&self, other: &BigUint) -> BigUint {
+        if *other > *self {
+            panic!("Subtraction would be negative!");
+        }
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0i64;
+        for (i, &limb) in self.limbs.iter().enumerate() {
+            let mut difference = i64::from(limb) - i64::from(other.limbs.get(i).cloned().unwrap_or(0)) - borrow;
+            borrow = 0;
+            if difference < 0 {
+                difference += 1 << 32;
+                borrow = 1;
+            }
+            limbs.push(difference as u32);
+        }
+        let mut result = BigUint { limbs };
+        result.normalize();
+        result
+    }
+
+    pub fn mul_u32(&self, factor: u32) -> BigUint {
+        let mut limbs = Vec::with_capacity(self.limbs.len() + 1);
+        let mut carry = 0u64;
+        for &limb in &self.limbs {
+            let product = u64::from(limb) * u64::from(factor) + carry;
+            limbs.push(product as u32);
+            carry = product >> 32;
+        }
+        if carry != 0 {
+            limbs.push(carry as u32);
+        }
+        let mut result = BigUint { limbs };
+        result.normalize();
+        result
+    }
+
+    /// `log2` of the number, or negative infinity for zero. Only the top 64 bits are used, which is
+    /// more than an `f64` can hold.
+    pub fn log2(&self) -> f64 {
+        let bits = self.bits();
+        if bits <= 64 {
+            return (self.limbs.iter().rev().fold(0u64, |acc, &limb| (acc << 32) | u64::from(limb)) as f64).log2();
+        }
+        let shift = bits - 64;
+        let top = (0..64).fold(0u64, |acc, i| (acc << 1) | u64::from(self.bit(shift + 63 - i)));
+        (top as f64).log2() + shift as f64
+    }
+
+    fn bit(&self, i: usize) -> bool {
+        self.limbs.get(i / 32).is_some_and(|&limb| limb & (1 << (i % 32)) != 0)
+    }
+
+    /// Select a number in `0..self` uniformly at random, in the same way as
+    /// `sampling::uniform_index`: with `bits` the number of bits of `self - 1`, fill `bits / 8`
+    /// bytes (rounded up), read them as a big-endian integer, keep its low `bits` bits, and try
+    /// again until the result is less than `self`.
+    ///
+    /// The call will panic if `self` is zero.
+    pub fn random_below<R: RngCore + CryptoRng>(&self, rng: &mut R) -> Result<BigUint, Error> {
+        if self.is_zero() {
+            panic!("Can't select a number from an empty range!");
+        }
+
+        let max = self.sub(&BigUint::from_u32(1));
+        let bits = max.bits();
+        let mut bytes = vec![0u8; bits.div_ceil(8)];
+        loop {
+            rng.try_fill_bytes(&mut bytes)?;
+            let mut limbs = vec![0u32; bytes.len().div_ceil(4)];
+            for (i, &byte) in bytes.iter().rev().enumerate() {
+                limbs[i / 4] |= u32::from(byte) << (8 * (i % 4));
+            }
+            if !bits.is_multiple_of(32) {
+                if let Some(top) = limbs.get_mut(bits / 32) {
+                    *top &= (1 << (bits % 32)) - 1;
+                }
+            }
+            let mut value = BigUint { limbs };
+            value.normalize();
+            if value <= max {
+                return Ok(value);
+            }
+        }
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &BigUint) -> Ordering {
+        self.limbs.len().cmp(&other.limbs.len()).then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::rand_chacha::ChaCha20Rng;
+    use super::super::rand_core::SeedableRng;
+    use super::super::insecure_testing::InsecureRng;
+
+    fn power(base: u32, exponent: usize) -> BigUint {
+        (0..exponent).fold(BigUint::from_u32(1), |n, _| n.mul_u32(base))
+    }
+
+    #[test]
+    fn arithmetic() {
+        let big = power(2, 100);
+        assert_eq!(big.bits(), 101);
+        assert_eq!(big.sub(&BigUint::from_u32(1)).bits(), 100);
+        assert_eq!(big.add(&big), power(2, 101));
+        assert_eq!(big.sub(&big), BigUint::zero());
+        assert_eq!(power(10, 30).add(&BigUint::from_u32(7)).sub(&power(10, 30)), BigUint::from_u32(7));
+        assert_eq!(BigUint::from_u32(0), BigUint::zero());
+        assert_eq!(BigUint::from_u32(5).mul_u32(0), BigUint::zero());
+        assert!(power(3, 50) > power(2, 79));
+        assert!(power(3, 50) < power(2, 80));
+    }
+
+    #[test]
+    fn logarithms() {
+        assert_eq!(BigUint::zero().log2(), f64::NEG_INFINITY);
+        assert_eq!(BigUint::from_u32(1).log2(), 0.0);
+        assert_eq!(power(2, 419).log2(), 419.0);
+        assert!((power(94, 64).log2() - 64.0 * 94f64.log2()).abs() < 1e-9);
+        assert!((power(10, 10).log2() - 10.0 * 10f64.log2()).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "would be negative")]
+    fn panics_on_negative_difference() {
+        let _ = BigUint::from_u32(1).sub(&BigUint::from_u32(2));
+    }
+
+    #[test]
+    fn random_numbers_are_in_range() {
+        let mut rng = InsecureRng(ChaCha20Rng::from_seed([7; 32]));
+        assert_eq!(BigUint::from_u32(1).random_below(&mut rng).unwrap(), BigUint::zero());
+
+        let mut seen = [false; 5];
+        for _ in 0..100 {
+            let n = BigUint::from_u32(5).random_below(&mut rng).unwrap();
+            seen[n.limbs.first().cloned().unwrap_or(0) as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        let bound = power(3, 100).add(&BigUint::from_u32(1));
+        let mut top_half = false;
+        for _ in 0..100 {
+            let n = bound.random_below(&mut rng).unwrap();
+            assert!(n < bound);
+            top_half |= n.bits() == bound.bits();
+        }
+        assert!(top_half);
+    }
+}
@@ -0,0 +1,369 @@
+//! `passgenr batch`, which creates a password for each account in a CSV file and writes them in
+//! the import format of a password manager. The first row of the CSV names the columns:
+//!
+//! ```text
+//! name,url,username,policy
+//! Mail,https://mail.example.com,alice,"minlength: 12; maxlength: 16; required: upper; required: digit;"
+//! Wiki,https://wiki.example.com,alice,
+//! ```
+//!
+//! Only `name` is required. `policy` holds passwordrules, as for `--rules`, and the password is as
+//! close to the default length as they allow; without rules it's printable ASCII.
+
+use std::fmt::Write;
+
+use passgenr::context::Context;
+use passgenr::rules::PasswordPolicy;
+
+use super::PASSWORD_LENGTH;
+
+/// The password managers whose import files can be written.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ImportFormat {
+    /// Bitwarden's unencrypted JSON export.
+    Bitwarden,
+    /// The CSV that KeePassXC exports, and KeePass and KeePassXC import.
+    KeePass,
+    /// 1Password's CSV import.
+    OnePassword,
+}
+
+/// The import formats by their names on the command line.
+pub const IMPORT_FORMATS: [(&str, ImportFormat); 3] = [
+    ("bitwarden", ImportFormat::Bitwarden),
+    ("keepass", ImportFormat::KeePass),
+    ("1password", ImportFormat::OnePassword),
+];
+
+impl ImportFormat {
+    pub fn from_name(name: &str) -> Option<ImportFormat> {
+        IMPORT_FORMATS.iter().find(|&&(n, _)| n.eq_ignore_ascii_case(name)).map(|&(_, format)| format)
+    }
+}
+
+/// An account to create a password for: one row of the input.
+#[derive(PartialEq, Debug)]
+pub struct AccountSpec {
+    pub name: String,
+    pub url: String,
+    pub username: String,
+    pub policy: PasswordPolicy,
+    pub length: usize,
+}
+
+/// An account with its new password.
+#[derive(PartialEq, Debug)]
+pub struct Account {
+    pub name: String,
+    pub url: String,
+    pub username: String,
+    pub password: String,
+}
+
+const COLUMNS: [&str; 4] = ["name", "url", "username", "policy"];
+
+/// Read the accounts from `csv`, checking every row before any password is generated.
+pub fn parse_accounts(csv: &str) -> Result<Vec<AccountSpec>, String> {
+    let mut records = parse_csv(csv)?.into_iter();
+    let header = match records.next() {
+        Some((_, header)) => header,
+        None => return Err("The file is empty; it must start with a header row.".to_owned()),
+    };
+
+    // the index of each of `COLUMNS` in the header
+    let mut indexes = [None; 4];
+    for (i, column) in header.iter().enumerate() {
+        match COLUMNS.iter().position(|c| c.eq_ignore_ascii_case(column.trim())) {
+            Some(j) if indexes[j].is_none() => indexes[j] = Some(i),
+            Some(_) => return Err(format!("The column \"{}\" appears twice in the header.", column)),
+            None => return Err(format!("Unknown column \"{}\" in the header.", column)),
+        }
+    }
+    if indexes[0].is_none() {
+        return Err("The header must have a \"name\" column.".to_owned());
+    }
+
+    let mut accounts = Vec::new();
+    for (line, record) in records {
+        if record.len() != header.len() {
+            return Err(format!("Line {} has {} fields, but the header has {}.", line, record.len(), header.len()));
+        }
+        let field = |column: usize| indexes[column].map(|i| record[i].clone()).unwrap_or_default();
+
+        let name = field(0);
+        if name.is_empty() {
+            return Err(format!("Line {}: The name is empty.", line));
+        }
+        let policy = match PasswordPolicy::parse(&field(3)) {
+            Ok(policy) => policy,
+            Err(e) => return Err(format!("Line {}: The password rules are invalid: {}.", line, e)),
+        };
+        let length = policy.clamp_length(PASSWORD_LENGTH);
+        if policy.entropy_bits(length) == f64::NEG_INFINITY {
+            return Err(format!("Line {}: No password can satisfy the password rules.", line));
+        }
+        accounts.push(AccountSpec { name, url: field(1), username: field(2), policy, length });
+    }
+    Ok(accounts)
+}
+
+/// Split RFC 4180 CSV into records of fields, each with the line it starts on. Fields may be
+/// quoted, with `""` for a quote, and lines may end in CRLF. Empty lines are skipped.
+fn parse_csv(csv: &str) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let mut records = Vec::new();
+    let mut chars = csv.chars().peekable();
+    let mut line = 1;
+
+    while chars.peek().is_some() {
+        let start = line;
+        let mut record = Vec::new();
+        loop {
+            let mut field = String::new();
+            if chars.peek() == Some(&'"') {
+                chars.next();
+                loop {
+                    match chars.next() {
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            field.push('"');
+                        },
+                        Some('"') => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            field.push(c);
+                        },
+                        None => return Err(format!("Line {}: A quoted field isn't closed.", start)),
+                    }
+                }
+                match chars.peek() {
+                    None | Some(&',') | Some(&'\n') | Some(&'\r') => {},
+                    Some(_) => return Err(format!("Line {}: Text follows a quoted field.", line)),
+                }
+            } else {
+                while let Some(&c) = chars.peek() {
+                    match c {
+                        ',' | '\n' | '\r' => break,
+                        '"' => return Err(format!("Line {}: A quote appears in an unquoted field.", line)),
+                        c => {
+                            field.push(c);
+                            chars.next();
+                        },
+                    }
+                }
+            }
+            record.push(field);
+
+            match chars.next() {
+                Some(',') => continue,
+                Some('\r') if chars.peek() == Some(&'\n') => { chars.next(); },
+                Some('\r') | Some('\n') | None => {},
+                Some(_) => unreachable!(),
+            }
+            line += 1;
+            break;
+        }
+        if record != [""] {
+            records.push((start, record));
+        }
+    }
+    Ok(records)
+}
+
+/// Generate a password for each account.
+pub fn generate_accounts(specs: &[AccountSpec]) -> Result<Vec<Account>, std::io::Error> {
+    specs.iter().map(|spec| {
+        Ok(Account {
+            name: spec.name.clone(),
+            url: spec.url.clone(),
+            username: spec.username.clone(),
+            password: spec.policy.random_password(spec.length)?,
+        })
+    }).collect()
+}
+
+/// The import file for `accounts`.
+pub fn write_accounts(accounts: &[Account], format: ImportFormat) -> String {
+    match format {
+        ImportFormat::Bitwarden => write_bitwarden(accounts),
+        ImportFormat::KeePass => {
+            let rows = accounts.iter().map(|account| {
+                vec!["Root", &account.name, &account.username, &account.password, &account.url, ""]
+            });
+            write_csv(&["Group", "Title", "Username", "Password", "URL", "Notes"], rows)
+        },
+        ImportFormat::OnePassword => {
+            let rows = accounts.iter().map(|account| {
+                vec![&account.name[..], &account.url, &account.username, &account.password, ""]
+            });
+            write_csv(&["Title", "Url", "Username", "Password", "Notes"], rows)
+        },
+    }
+}
+
+fn write_csv<'a, I: Iterator<Item = Vec<&'a str>>>(header: &[&'a str], rows: I) -> String {
+    let mut csv = String::new();
+    for row in Some(header.to_vec()).into_iter().chain(rows) {
+        let fields: Vec<String> = row.iter().map(|field| Context::Csv.escape(field)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+fn write_bitwarden(accounts: &[Account]) -> String {
+    let json = |s: &str| Context::Json.escape(s);
+    let mut items = Vec::new();
+    for account in accounts {
+        let uris = if account.url.is_empty() {
+            "[]".to_owned()
+        } else {
+            format!("[\n          {{\n            \"match\": null,\n            \"uri\": {}\n          }}\n        ]", json(&account.url))
+        };
+        let mut item = String::new();
+        writeln!(item, "    {{").unwrap();
+        writeln!(item, "      \"type\": 1,").unwrap();
+        writeln!(item, "      \"name\": {},", json(&account.name)).unwrap();
+        writeln!(item, "      \"notes\": null,").unwrap();
+        writeln!(item, "      \"favorite\": false,").unwrap();
+        writeln!(item, "      \"folderId\": null,").unwrap();
+        writeln!(item, "      \"login\": {{").unwrap();
+        writeln!(item, "        \"uris\": {},", uris).unwrap();
+        writeln!(item, "        \"username\": {},", json(&account.username)).unwrap();
+        writeln!(item, "        \"password\": {},", json(&account.password)).unwrap();
+        writeln!(item, "        \"totp\": null").unwrap();
+        writeln!(item, "      }}").unwrap();
+        write!(item, "    }}").unwrap();
+        items.push(item);
+    }
+
+    let items = if items.is_empty() { "[]".to_owned() } else { format!("[\n{}\n  ]", items.join(",\n")) };
+    format!("{{\n  \"encrypted\": false,\n  \"folders\": [],\n  \"items\": {}\n}}\n", items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    fn account(name: &str, url: &str, password: &str) -> Account {
+        Account { name: name.to_owned(), url: url.to_owned(), username: "alice".to_owned(), password: password.to_owned() }
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        let csv = "a,\"b,c\",\"say \"\"hi\"\"\"\r\n\n\"multi\nline\",,x\n";
+        assert_eq!(
+            parse_csv(csv).unwrap(),
+            vec![
+                (1, vec!["a".to_owned(), "b,c".to_owned(), "say \"hi\"".to_owned()]),
+                (3, vec!["multi\nline".to_owned(), "".to_owned(), "x".to_owned()]),
+            ]
+        );
+        assert_eq!(parse_csv("").unwrap(), vec![]);
+        assert_eq!(parse_csv("a,b").unwrap(), vec![(1, vec!["a".to_owned(), "b".to_owned()])]);
+
+        let cases = [
+            ("a\n\"b", "Line 2: A quoted field isn't closed."),
+            ("\"a\"b", "Line 1: Text follows a quoted field."),
+            ("a\nb\"c", "Line 2: A quote appears in an unquoted field."),
+        ];
+        for &(csv, message) in cases.iter() {
+            assert_eq!(parse_csv(csv).unwrap_err(), message);
+        }
+    }
+
+    #[test]
+    fn test_parse_accounts() {
+        let csv = "Name,URL,Policy\nMail,https://mail.example.com,\"maxlength: 16; required: digit;\"\nWiki,,\n";
+        let accounts = parse_accounts(csv).unwrap();
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].name, "Mail");
+        assert_eq!(accounts[0].url, "https://mail.example.com");
+        assert_eq!(accounts[0].username, "");
+        assert_eq!(accounts[0].length, 16);
+        assert_eq!(accounts[1].policy, PasswordPolicy::parse("").unwrap());
+        assert_eq!(accounts[1].length, PASSWORD_LENGTH);
+
+        for account in generate_accounts(&accounts).unwrap() {
+            let spec = accounts.iter().find(|spec| spec.name == account.name).unwrap();
+            let chars: Vec<String> = account.password.chars().map(String::from).collect();
+            let chars: Vec<&str> = chars.iter().map(String::as_str).collect();
+            assert!(spec.policy.is_compliant(&chars));
+        }
+    }
+
+    #[test]
+    fn test_tight_policies() {
+        // two compliant passwords of each length, out of 2^36 and 2^64
+        let csv = "name,policy\n\
+                   Short,\"minlength: 36; maxlength: 36; allowed: [ab]; max-consecutive: 1;\"\n\
+                   Long,\"allowed: [ab]; max-consecutive: 1;\"\n";
+        let specs = parse_accounts(csv).unwrap();
+        assert_eq!(specs[0].length, 36);
+        assert_eq!(specs[1].length, PASSWORD_LENGTH);
+
+        for (spec, account) in specs.iter().zip(generate_accounts(&specs).unwrap()) {
+            let half = spec.length / 2;
+            assert!(account.password == "ab".repeat(half) || account.password == "ba".repeat(half), "{}", account.password);
+        }
+    }
+
+    #[test]
+    fn test_invalid_accounts() {
+        let cases = [
+            ("", "The file is empty"),
+            ("url\nexample.com", "must have a \"name\" column"),
+            ("name,password\nMail,x", "Unknown column \"password\""),
+            ("name,NAME\nMail,Mail", "appears twice"),
+            ("name,url\nMail", "Line 2 has 1 fields, but the header has 2."),
+            ("name,policy\n,", "Line 2: The name is empty."),
+            ("name,policy\nMail,\"bogus: 1;\"", "Line 2: The password rules are invalid"),
+            ("name,policy\nMail,maxlength: 2; required: upper; required: lower; required: digit;", "Line 2: No password can satisfy the password rules."),
+        ];
+        for &(csv, message) in cases.iter() {
+            let e = parse_accounts(csv).unwrap_err();
+            assert!(e.contains(message), "{}", e);
+        }
+    }
+
+    #[test]
+    fn test_import_formats() {
+        for &(name, format) in IMPORT_FORMATS.iter() {
+            assert_eq!(ImportFormat::from_name(name), Some(format));
+        }
+        assert_eq!(ImportFormat::from_name("lastpass"), None);
+
+        let accounts = [account("Mail", "https://mail.example.com", "a\"b,c"), account("Wiki", "", "x\\y")];
+        assert_eq!(
+            write_accounts(&accounts, ImportFormat::KeePass),
+            "\"Group\",\"Title\",\"Username\",\"Password\",\"URL\",\"Notes\"\n\
+             \"Root\",\"Mail\",\"alice\",\"a\"\"b,c\",\"https://mail.example.com\",\"\"\n\
+             \"Root\",\"Wiki\",\"alice\",\"x\\y\",\"\",\"\"\n"
+        );
+        assert_eq!(
+            write_accounts(&accounts, ImportFormat::OnePassword),
+            "\"Title\",\"Url\",\"Username\",\"Password\",\"Notes\"\n\
+             \"Mail\",\"https://mail.example.com\",\"alice\",\"a\"\"b,c\",\"\"\n\
+             \"Wiki\",\"\",\"alice\",\"x\\y\",\"\"\n"
+        );
+        assert_eq!(write_accounts(&[], ImportFormat::Bitwarden), "{\n  \"encrypted\": false,\n  \"folders\": [],\n  \"items\": []\n}\n");
+    }
+
+    #[test]
+    fn test_bitwarden() {
+        let accounts = [account("Mail", "https://mail.example.com", "a\"b,c"), account("Wiki", "", "x\\y")];
+        let json: serde_json::Value = serde_json::from_str(&write_accounts(&accounts, ImportFormat::Bitwarden)).unwrap();
+        assert_eq!(json["encrypted"], false);
+        let items = json["items"].as_array().unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0]["type"], 1);
+        assert_eq!(items[0]["name"], "Mail");
+        assert_eq!(items[0]["login"]["uris"][0]["uri"], "https://mail.example.com");
+        assert_eq!(items[0]["login"]["username"], "alice");
+        assert_eq!(items[0]["login"]["password"], "a\"b,c");
+        assert_eq!(items[1]["login"]["uris"].as_array().unwrap().len(), 0);
+        assert_eq!(items[1]["login"]["password"], "x\\y");
+    }
+}
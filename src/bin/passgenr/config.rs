@@ -0,0 +1,374 @@
+//! The configuration file, which holds named profiles of default settings:
+//!
+//! ```toml
+//! [profile.wifi]
+//! charset = "alpha"
+//! length = 20
+//!
+//! [profile.default]
+//! charset = "words"
+//! group = 3
+//! group-sep = " "
+//! ```
+//!
+//! Settings are named after the long command-line options, and `charset` takes the name of a
+//! charset option. The `default` profile applies when `--profile` isn't given.
+//!
+//! The file is `$PASSGENR_CONFIG` if that is set. Otherwise it is the first that exists of
+//! `$XDG_CONFIG_HOME/passgenr/config.toml` (or `~/.config/passgenr/config.toml`) and
+//! `/etc/passgenr/config.toml`.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use toml::{Table, Value};
+
+use passgenr::context::Context;
+use passgenr::keyboard::Layout;
+use passgenr::languages::Language;
+
+use super::CommandLineCharset;
+
+pub const CONFIG_ENV_VAR: &str = "PASSGENR_CONFIG";
+const SYSTEM_CONFIG_PATH: &str = "/etc/passgenr/config.toml";
+
+/// Settings from one profile. Anything that isn't set falls back to the built-in default.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct Profile {
+    pub charset: Option<CommandLineCharset>,
+    pub rules: Option<String>,
+    pub length: Option<usize>,
+    pub password_count: Option<u32>,
+    pub group: Option<usize>,
+    pub group_separator: Option<String>,
+    pub check_symbol: bool,
+    pub show_entropy: bool,
+    pub language: Option<Language>,
+    pub fold_ascii: bool,
+    pub unicode_safe: bool,
+    pub keyboard: Option<Layout>,
+    pub safe_for: Option<Context>,
+    pub escape: Option<Context>,
+}
+
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct Config {
+    /// The file the configuration was read from, or `None` if there is none.
+    pub path: Option<PathBuf>,
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// Read the configuration file, or return an empty configuration if there isn't one.
+pub fn load_config() -> Result<Config, String> {
+    if let Some(path) = env::var_os(CONFIG_ENV_VAR).filter(|path| !path.is_empty()) {
+        return read_config(Path::new(&path));
+    }
+
+    let user_config_dir = match env::var_os("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()) {
+        Some(dir) => Some(PathBuf::from(dir)),
+        None => env::var_os("HOME").map(|home| Path::new(&home).join(".config")),
+    };
+    let user_config_path = user_config_dir.map(|dir| dir.join("passgenr").join("config.toml"));
+
+    for path in user_config_path.into_iter().chain(Some(PathBuf::from(SYSTEM_CONFIG_PATH))) {
+        if path.is_file() {
+            return read_config(&path);
+        }
+    }
+    Ok(Config::default())
+}
+
+fn read_config(path: &Path) -> Result<Config, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| format!("Couldn't read the configuration file {}: {}.", path.display(), e))?;
+    let mut config = parse_config(&text)
+        .map_err(|e| format!("The configuration file {} is invalid: {}", path.display(), e))?;
+    config.path = Some(path.to_path_buf());
+    Ok(config)
+}
+
+pub fn parse_config(text: &str) -> Result<Config, String> {
+    let table: Table = text.parse().map_err(|e: toml::de::Error| e.message().to_owned() + ".")?;
+
+    let mut config = Config::default();
+    for (key, value) in table {
+        if key != "profile" {
+            return Err(format!("Unknown setting \"{}\".", key));
+        }
+        let profiles = match value {
+            Value::Table(profiles) => profiles,
+            _ => return Err("\"profile\" must be a table of profiles.".to_owned()),
+        };
+        for (name, settings) in profiles {
+            let profile = match settings {
+                Value::Table(settings) => parse_profile(&settings).map_err(|e| format!("In profile \"{}\": {}", name, e))?,
+                _ => return Err(format!("Profile \"{}\" must be a table.", name)),
+            };
+            config.profiles.insert(name, profile);
+        }
+    }
+    Ok(config)
+}
+
+fn parse_profile(settings: &Table) -> Result<Profile, String> {
+    let mut profile = Profile::default();
+    for (key, value) in settings {
+        match key.as_str() {
+            "charset" => {
+                let name = string_setting(key, value)?;
+                match CommandLineCharset::from_name(&name) {
+                    Some(charset) => profile.charset = Some(charset),
+                    None => return Err(format!("Unknown charset \"{}\".", name)),
+                }
+            },
+            "rules" => profile.rules = Some(string_setting(key, value)?),
+            "length" => profile.length = Some(positive_setting(key, value)?),
+            "password-count" => {
+                match value.as_integer() {
+                    Some(n) if n >= 0 && n <= i64::from(u32::MAX) => profile.password_count = Some(n as u32),
+                    _ => return Err("\"password-count\" must be a non-negative number.".to_owned()),
+                }
+            },
+            "group" => profile.group = Some(positive_setting(key, value)?),
+            "group-sep" => profile.group_separator = Some(string_setting(key, value)?),
+            "check" => profile.check_symbol = bool_setting(key, value)?,
+            "entropy" => profile.show_entropy = bool_setting(key, value)?,
+            "lang" => {
+                let code = string_setting(key, value)?;
+                match Language::from_code(&code) {
+                    Some(language) => profile.language = Some(language),
+                    None => return Err(format!("Unknown language \"{}\".", code)),
+                }
+            },
+            "fold-ascii" => profile.fold_ascii = bool_setting(key, value)?,
+            "unicode-safe" => profile.unicode_safe = bool_setting(key, value)?,
+            "safe-for" => profile.safe_for = Some(context_setting(key, value)?),
+            "escape" => profile.escape = Some(context_setting(key, value)?),
+            "keyboard" => {
+                let code = string_setting(key, value)?;
+                match Layout::from_code(&code) {
+                    Some(layout) => profile.keyboard = Some(layout),
+                    None => return Err(format!("Unknown keyboard layout \"{}\".", code)),
+                }
+            },
+            _ => return Err(format!("Unknown setting \"{}\".", key)),
+        }
+    }
+
+    if profile.charset.is_some() && profile.rules.is_some() {
+        return Err("Only one of \"charset\" and \"rules\" can be set.".to_owned());
+    }
+    if profile.check_symbol && profile.charset != Some(CommandLineCharset::Crockford) {
+        return Err("\"check\" is only supported with charset = \"crockford\".".to_owned());
+    }
+    let other_kind = profile.rules.is_some() || profile.charset.is_some_and(|c| c != CommandLineCharset::Words);
+    if other_kind && (profile.language.is_some() || profile.fold_ascii) {
+        return Err("\"lang\" and \"fold-ascii\" are only supported with charset = \"words\".".to_owned());
+    }
+    if profile.rules.is_some() && profile.keyboard.is_some() {
+        return Err("\"keyboard\" isn't supported with \"rules\".".to_owned());
+    }
+    if profile.rules.is_some() && profile.safe_for.is_some() {
+        return Err("\"safe-for\" isn't supported with \"rules\".".to_owned());
+    }
+    Ok(profile)
+}
+
+fn string_setting(key: &str, value: &Value) -> Result<String, String> {
+    value.as_str().map(str::to_owned).ok_or_else(|| format!("\"{}\" must be a string.", key))
+}
+
+fn positive_setting(key: &str, value: &Value) -> Result<usize, String> {
+    match value.as_integer() {
+        Some(n) if n > 0 => Ok(n as usize),
+        _ => Err(format!("\"{}\" must be a positive number.", key)),
+    }
+}
+
+fn context_setting(key: &str, value: &Value) -> Result<Context, String> {
+    let name = string_setting(key, value)?;
+    Context::from_name(&name).ok_or_else(|| format!("Unknown context \"{}\".", name))
+}
+
+fn bool_setting(key: &str, value: &Value) -> Result<bool, String> {
+    value.as_bool().ok_or_else(|| format!("\"{}\" must be true or false.", key))
+}
+
+impl fmt::Display for Profile {
+    /// The equivalent command-line options.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut options = Vec::new();
+        if let Some(charset) = self.charset {
+            options.push(format!("--{}", charset.name()));
+        }
+        if let Some(ref rules) = self.rules {
+            options.push(format!("--rules {:?}", rules));
+        }
+        if let Some(length) = self.length {
+            options.push(format!("--length {}", length));
+        }
+        if let Some(count) = self.password_count {
+            options.push(format!("-p {}", count));
+        }
+        if let Some(group) = self.group {
+            options.push(format!("--group {}", group));
+        }
+        if let Some(ref separator) = self.group_separator {
+            options.push(format!("--group-sep {:?}", separator));
+        }
+        if self.check_symbol {
+            options.push("--check".to_owned());
+        }
+        if self.show_entropy {
+            options.push("--entropy".to_owned());
+        }
+        if let Some(language) = self.language {
+            options.push(format!("--lang {}", language.code()));
+        }
+        if self.fold_ascii {
+            options.push("--fold-ascii".to_owned());
+        }
+        if self.unicode_safe {
+            options.push("--unicode-safe".to_owned());
+        }
+        if let Some(layout) = self.keyboard {
+            options.push(format!("--keyboard {}", layout.code()));
+        }
+        if let Some(context) = self.safe_for {
+            options.push(format!("--safe-for {}", context.name()));
+        }
+        if let Some(context) = self.escape {
+            options.push(format!("--escape {}", context.name()));
+        }
+        write!(f, "{}", options.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config = parse_config(r#"
+            [profile.wifi]
+            charset = "alpha"
+            length = 20
+            safe-for = "url"
+
+            [profile.site]
+            rules = "minlength: 12; required: digit;"
+            password-count = 3
+            group = 4
+            group-sep = " "
+            check = false
+            entropy = true
+            escape = "shell"
+
+            [profile.german]
+            charset = "words"
+            lang = "de"
+            fold-ascii = true
+            keyboard = "de"
+
+            [profile.pairing]
+            charset = "emoji"
+            length = 8
+            unicode-safe = true
+        "#).unwrap();
+
+        assert_eq!(config.path, None);
+        assert_eq!(config.profiles.len(), 4);
+        assert_eq!(
+            config.profiles["wifi"],
+            Profile {
+                charset: Some(CommandLineCharset::Alpha),
+                length: Some(20),
+                safe_for: Some(Context::Url),
+                ..Profile::default()
+            }
+        );
+        assert_eq!(
+            config.profiles["site"],
+            Profile {
+                rules: Some("minlength: 12; required: digit;".to_owned()),
+                password_count: Some(3),
+                group: Some(4),
+                group_separator: Some(" ".to_owned()),
+                show_entropy: true,
+                escape: Some(Context::Shell),
+                ..Profile::default()
+            }
+        );
+        assert_eq!(
+            config.profiles["german"],
+            Profile {
+                charset: Some(CommandLineCharset::Words),
+                language: Some(Language::German),
+                fold_ascii: true,
+                keyboard: Some(Layout::German),
+                ..Profile::default()
+            }
+        );
+        assert_eq!(config.profiles["german"].to_string(), "--words --lang de --fold-ascii --keyboard de");
+        assert_eq!(
+            config.profiles["pairing"],
+            Profile {
+                charset: Some(CommandLineCharset::Emoji),
+                length: Some(8),
+                unicode_safe: true,
+                ..Profile::default()
+            }
+        );
+        assert_eq!(config.profiles["pairing"].to_string(), "--emoji --length 8 --unicode-safe");
+        assert_eq!(parse_config("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let cases = [
+            ("[profile.wifi", "expected"),
+            ("color = true", "Unknown setting \"color\""),
+            ("profile = 1", "must be a table of profiles"),
+            ("[profile]\nwifi = 1", "must be a table"),
+            ("[profile.wifi]\ncolor = true", "In profile \"wifi\": Unknown setting \"color\""),
+            ("[profile.wifi]\ncharset = \"klingon\"", "Unknown charset \"klingon\""),
+            ("[profile.wifi]\ncharset = 1", "must be a string"),
+            ("[profile.wifi]\nkeyboard = \"dvorak\"", "Unknown keyboard layout \"dvorak\""),
+            ("[profile.wifi]\nescape = \"sql\"", "Unknown context \"sql\""),
+            ("[profile.wifi]\nrules = \"minlength: 8;\"\nsafe-for = \"url\"", "isn't supported with \"rules\""),
+            ("[profile.wifi]\nrules = \"minlength: 8;\"\nkeyboard = \"de\"", "isn't supported with \"rules\""),
+            ("[profile.wifi]\nlength = 0", "must be a positive number"),
+            ("[profile.wifi]\npassword-count = -1", "must be a non-negative number"),
+            ("[profile.wifi]\ncheck = \"yes\"", "must be true or false"),
+            ("[profile.wifi]\ncharset = \"hex\"\nrules = \"minlength: 8;\"", "Only one of"),
+            ("[profile.wifi]\ncharset = \"hex\"\ncheck = true", "only supported with charset = \"crockford\""),
+            ("[profile.wifi]\nlang = \"xx\"", "Unknown language \"xx\""),
+            ("[profile.wifi]\ncharset = \"hex\"\nlang = \"de\"", "only supported with charset = \"words\""),
+            ("[profile.wifi]\nrules = \"minlength: 8;\"\nfold-ascii = true", "only supported with charset = \"words\""),
+        ];
+        for &(text, message) in cases.iter() {
+            match parse_config(text) {
+                Err(e) => assert!(e.contains(message), "{:?} gave {:?}", text, e),
+                Ok(_) => panic!("{:?} was accepted", text),
+            }
+        }
+    }
+
+    #[test]
+    fn test_display_profile() {
+        let profile = Profile {
+            charset: Some(CommandLineCharset::Crockford),
+            length: Some(16),
+            group: Some(4),
+            group_separator: Some(" ".to_owned()),
+            check_symbol: true,
+            ..Profile::default()
+        };
+        assert_eq!(profile.to_string(), "--crockford --length 16 --group 4 --group-sep \" \" --check");
+        assert_eq!(Profile::default().to_string(), "");
+    }
+}
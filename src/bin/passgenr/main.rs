@@ -0,0 +1,1852 @@
+extern crate passgenr;
+extern crate getopts;
+extern crate toml;
+#[cfg(test)]
+extern crate serde_json;
+
+mod batch;
+mod config;
+
+use std::io::Read;
+
+use batch::ImportFormat;
+use getopts::{Matches, Options};
+use config::{Config, Profile};
+use passgenr::charsets::{self, Charset};
+use passgenr::context::Context;
+use passgenr::keyboard::Layout;
+use passgenr::languages::{self, Language};
+use passgenr::recovery::RecoveryCodeHash;
+use passgenr::rules::PasswordPolicy;
+use passgenr::token::ChecksumAlgorithm;
+use passgenr::unicode;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum CommandLineCharset {
+    Hex,
+    Ascii,
+    Alpha,
+    Digit,
+    Lower,
+    Words,
+    Base32,
+    Crockford,
+    Base58,
+    Base64Url,
+    Emoji,
+}
+
+/// The charsets by the name of their long option, which is also their name in profiles.
+const CHARSET_NAMES: [(&str, CommandLineCharset); 11] = [
+    ("hex", CommandLineCharset::Hex),
+    ("ascii", CommandLineCharset::Ascii),
+    ("alpha", CommandLineCharset::Alpha),
+    ("digit", CommandLineCharset::Digit),
+    ("lower", CommandLineCharset::Lower),
+    ("words", CommandLineCharset::Words),
+    ("base32", CommandLineCharset::Base32),
+    ("crockford", CommandLineCharset::Crockford),
+    ("base58", CommandLineCharset::Base58),
+    ("base64url", CommandLineCharset::Base64Url),
+    ("emoji", CommandLineCharset::Emoji),
+];
+
+impl CommandLineCharset {
+    fn from_name(name: &str) -> Option<CommandLineCharset> {
+        CHARSET_NAMES.iter().find(|&&(n, _)| n == name).map(|&(_, charset)| charset)
+    }
+
+    fn name(self) -> &'static str {
+        CHARSET_NAMES.iter().find(|&&(_, c)| c == self).map(|&(name, _)| name).unwrap()
+    }
+}
+
+#[derive(PartialEq, Debug)]
+enum OptParseResult {
+    Help,
+    Generate(CommandLineCharset, usize, u32, OutputFormat),
+    GenerateFromRules(PasswordPolicy, usize, u32, OutputFormat),
+    Token(TokenArgs, u32),
+    RecoveryCodes(RecoveryCodeArgs),
+    Bip39(usize, u32),
+    ValidateBip39(String),
+    ListProfiles(Config),
+    AnalyzeWordlist(WordlistArgs),
+    Batch(BatchArgs),
+    Err(String)
+}
+
+#[derive(Clone, Default, PartialEq, Debug)]
+struct OutputFormat {
+    group: Option<(usize, String)>,
+    check_symbol: bool,
+    show_entropy: bool,
+    /// The language of the wordlist, for `CommandLineCharset::Words`.
+    language: Language,
+    fold_ascii: bool,
+    /// Only the elements of the charset that can be typed directly on this layout are used.
+    keyboard: Option<Layout>,
+    /// Only the elements of the charset that are safe in this context are used.
+    safe_for: Option<Context>,
+    /// The passwords are printed escaped for this context.
+    escape: Option<Context>,
+}
+
+#[derive(PartialEq, Debug)]
+struct TokenArgs {
+    prefix: String,
+    body_length: usize,
+    checksum: ChecksumAlgorithm,
+}
+
+#[derive(PartialEq, Debug)]
+struct RecoveryCodeArgs {
+    count: usize,
+    group_count: usize,
+    group_length: usize,
+    hash: Option<RecoveryCodeHash>,
+}
+
+#[derive(PartialEq, Debug)]
+struct WordlistArgs {
+    path: String,
+    /// A file of extra words to report as offensive, one per line.
+    blocklist: Option<String>,
+    /// List every pair instead of a few examples.
+    verbose: bool,
+}
+
+#[derive(PartialEq, Debug)]
+struct BatchArgs {
+    /// The CSV of accounts, or "-" for standard input.
+    path: String,
+    format: ImportFormat,
+}
+
+const PASSWORD_LENGTH: usize = 64;
+const PASSWORD_WORD_COUNT: usize = 10;
+const PASSWORD_EMOJI_COUNT: usize = 16;
+/// The check symbols that aren't also Crockford base32 characters.
+const EXTRA_CHECK_SYMBOLS: &str = "*~$=U";
+const TOKEN_BODY_LENGTH: usize = 30;
+const RECOVERY_CODE_COUNT: usize = 10;
+const BIP39_WORD_COUNT: usize = 24;
+/// How many prefix and edit distance pairs `wordlist analyze` shows without `--verbose`.
+const WORDLIST_EXAMPLE_COUNT: usize = 5;
+/// The profile used when `--profile` isn't given.
+const DEFAULT_PROFILE: &str = "default";
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let program = args[0].clone();
+
+    let (usage_name, opts, result) = match args.get(1).map(String::as_str) {
+        Some("token") => {
+            let opts = prepare_token_opts();
+            let result = parse_token_args(&opts, &args[2..]);
+            (format!("{} token", program), opts, result)
+        },
+        Some("recovery-codes") => {
+            let opts = prepare_recovery_code_opts();
+            let result = parse_recovery_code_args(&opts, &args[2..]);
+            (format!("{} recovery-codes", program), opts, result)
+        },
+        Some("bip39") => {
+            let opts = prepare_bip39_opts();
+            let result = parse_bip39_args(&opts, &args[2..]);
+            (format!("{} bip39", program), opts, result)
+        },
+        Some("profiles") => {
+            let opts = prepare_profiles_opts();
+            let result = parse_profiles_args(&opts, &args[2..], config::load_config);
+            (format!("{} profiles list", program), opts, result)
+        },
+        Some("wordlist") => {
+            let opts = prepare_wordlist_opts();
+            let result = parse_wordlist_args(&opts, &args[2..]);
+            (format!("{} wordlist analyze PATH", program), opts, result)
+        },
+        Some("batch") => {
+            let opts = prepare_batch_opts();
+            let result = parse_batch_args(&opts, &args[2..]);
+            (format!("{} batch PATH", program), opts, result)
+        },
+        _ => {
+            let opts = prepare_opts();
+            let result = parse_args(&opts, &args[1..], config::load_config);
+            (program, opts, result)
+        }
+    };
+
+    match result {
+        OptParseResult::Generate(charset, length, password_count, format) => {
+            generate_passwords(charset, length, password_count, &format);
+        },
+        OptParseResult::GenerateFromRules(policy, length, password_count, format) => {
+            generate_passwords_from_rules(&policy, length, password_count, &format);
+        },
+        OptParseResult::Token(token_args, token_count) => {
+            generate_tokens(&token_args, token_count);
+        },
+        OptParseResult::RecoveryCodes(recovery_code_args) => {
+            generate_recovery_codes(&recovery_code_args);
+        },
+        OptParseResult::Bip39(word_count, mnemonic_count) => {
+            for _ in 0..mnemonic_count {
+                // we'll panic on CSPRNG failure
+                println!("{}", passgenr::bip39::random_mnemonic(word_count).unwrap());
+            }
+        },
+        OptParseResult::ValidateBip39(mnemonic) => {
+            match passgenr::bip39::mnemonic_to_entropy(&mnemonic) {
+                Ok(_) => println!("The mnemonic is valid."),
+                Err(e) => {
+                    println!("The mnemonic is invalid: {}.", e);
+                    std::process::exit(1);
+                }
+            }
+        },
+        OptParseResult::ListProfiles(config) => {
+            list_profiles(&config);
+        },
+        OptParseResult::AnalyzeWordlist(wordlist_args) => {
+            if let Err(e) = analyze_wordlist(&wordlist_args) {
+                println!("Error: {}", e);
+                std::process::exit(1);
+            }
+        },
+        OptParseResult::Batch(batch_args) => {
+            if let Err(e) = generate_batch(&batch_args) {
+                println!("Error: {}", e);
+                std::process::exit(1);
+            }
+        },
+        OptParseResult::Help => {
+            print_usage(&usage_name, opts, None);
+            std::process::exit(0);
+        }
+        OptParseResult::Err(err_msg) => {
+            print_usage(&usage_name, opts, Some(&err_msg));
+            std::process::exit(1);
+        }
+    }
+}
+
+fn generate_passwords(charset: CommandLineCharset, length: usize, password_count: u32, format: &OutputFormat) {
+    let separator = charset_parameters(charset).2;
+    let elements = charset_elements(charset, format);
+    let charset_elements: Vec<&str> = elements.iter().map(String::as_str).collect();
+    if format.show_entropy {
+        eprintln!("Entropy: {:.1} bits", passgenr::entropy_bits(&charset_elements, length));
+    }
+    for _ in 0..password_count {
+        let check_symbol;
+        // we'll panic on CSPRNG failure
+        let mut elements = passgenr::random_password_elements(&charset_elements, length).unwrap();
+        if format.check_symbol {
+            check_symbol = passgenr::crockford::check_symbol(&elements.concat()).unwrap().to_string();
+            elements.push(&check_symbol);
+        }
+        let password = match format.group {
+            Some((group_size, ref group_separator)) => {
+                passgenr::join_grouped(&elements, separator, group_size, group_separator)
+            },
+            None => elements.join(separator)
+        };
+        print_password(&password, format);
+    }
+}
+
+fn generate_passwords_from_rules(policy: &PasswordPolicy, length: usize, password_count: u32, format: &OutputFormat) {
+    if format.show_entropy {
+        eprintln!("Entropy: {:.1} bits", policy.entropy_bits(length));
+    }
+    for _ in 0..password_count {
+        // we'll panic on CSPRNG failure
+        let elements = policy.random_password_elements(length).unwrap();
+        let password = match format.group {
+            Some((group_size, ref group_separator)) => {
+                passgenr::join_grouped(&elements, "", group_size, group_separator)
+            },
+            None => elements.concat()
+        };
+        print_password(&password, format);
+    }
+}
+
+fn print_password(password: &str, format: &OutputFormat) {
+    match format.escape {
+        Some(context) => println!("{}", context.escape(password)),
+        None => println!("{}", password),
+    }
+}
+
+/// The elements of a charset in the language of `format`, typeable on its keyboard and safe for
+/// its context.
+fn charset_elements(charset: CommandLineCharset, format: &OutputFormat) -> Vec<String> {
+    let words: Vec<String>;
+    let elements: Vec<&str> = if charset == CommandLineCharset::Words {
+        // the wordlist's availability is checked when parsing the arguments, and the built-in
+        // wordlists are tested to have no duplicates after folding
+        let wordlist = format.language.wordlist().unwrap();
+        words = languages::normalize_wordlist(&wordlist.to_vec(), format.fold_ascii).unwrap();
+        words.iter().map(String::as_str).collect()
+    } else {
+        charset_parameters(charset).0.to_vec()
+    };
+    let elements = match format.keyboard {
+        Some(layout) => layout.filter(&elements),
+        None => elements,
+    };
+    let elements = match format.safe_for {
+        Some(context) => context.filter(&elements),
+        None => elements,
+    };
+    elements.into_iter().map(str::to_owned).collect()
+}
+
+/// The elements, default length and separator of a charset.
+fn charset_parameters(charset: CommandLineCharset) -> (&'static dyn Charset<'static>, usize, &'static str) {
+    match charset {
+        CommandLineCharset::Hex => (&charsets::UPPERCASE_HEX, PASSWORD_LENGTH, ""),
+        CommandLineCharset::Ascii => (&charsets::ASCII, PASSWORD_LENGTH, ""),
+        CommandLineCharset::Alpha => (&charsets::ALPHANUMERIC, PASSWORD_LENGTH, ""),
+        CommandLineCharset::Digit => (&charsets::DECIMAL_DIGIT, PASSWORD_LENGTH, ""),
+        CommandLineCharset::Lower => (&charsets::LOWERCASE_ALPHABETIC, PASSWORD_LENGTH, ""),
+        CommandLineCharset::Words => (charsets::WORDS, PASSWORD_WORD_COUNT, "."),
+        CommandLineCharset::Base32 => (&charsets::BASE32, PASSWORD_LENGTH, ""),
+        CommandLineCharset::Crockford => (&charsets::CROCKFORD_BASE32, PASSWORD_LENGTH, ""),
+        CommandLineCharset::Base58 => (&charsets::BASE58, PASSWORD_LENGTH, ""),
+        CommandLineCharset::Base64Url => (&charsets::BASE64URL, PASSWORD_LENGTH, ""),
+        CommandLineCharset::Emoji => (&charsets::EMOJI, PASSWORD_EMOJI_COUNT, ""),
+    }
+}
+
+fn generate_tokens(token_args: &TokenArgs, token_count: u32) {
+    let format = passgenr::token::TokenFormat {
+        prefix: &token_args.prefix,
+        body_length: token_args.body_length,
+        checksum: token_args.checksum,
+    };
+    for _ in 0..token_count {
+        // we'll panic on CSPRNG failure
+        println!("{}", passgenr::token::generate_token(&format).unwrap());
+    }
+}
+
+fn generate_recovery_codes(recovery_code_args: &RecoveryCodeArgs) {
+    let format = passgenr::recovery::RecoveryCodeFormat {
+        group_count: recovery_code_args.group_count,
+        group_length: recovery_code_args.group_length,
+        ..passgenr::recovery::RecoveryCodeFormat::default()
+    };
+    // we'll panic on CSPRNG failure
+    for code in passgenr::recovery::recovery_codes(recovery_code_args.count, &format).unwrap() {
+        match recovery_code_args.hash {
+            Some(algorithm) => {
+                let hash = passgenr::recovery::hash_recovery_code(&format, &code, algorithm).unwrap();
+                println!("{}\t{}", code, hash);
+            },
+            None => println!("{}", code)
+        }
+    }
+}
+
+fn list_profiles(config: &Config) {
+    let path = match config.path {
+        Some(ref path) => path,
+        None => {
+            eprintln!("No configuration file was found.");
+            return;
+        }
+    };
+    eprintln!("Profiles in {}:", path.display());
+    let width = config.profiles.keys().map(String::len).max().unwrap_or(0);
+    for (name, profile) in &config.profiles {
+        println!("{:width$}  {}", name, profile, width = width);
+    }
+}
+
+/// Read a wordlist or blocklist, which has one word per line.
+fn read_words(path: &str) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("Couldn't read {}: {}.", path, e))
+}
+
+fn analyze_wordlist(wordlist_args: &WordlistArgs) -> Result<(), String> {
+    let text = read_words(&wordlist_args.path)?;
+    let words: Vec<&str> = text.lines().map(str::trim).filter(|word| !word.is_empty()).collect();
+
+    let blocklist_text = match wordlist_args.blocklist {
+        Some(ref path) => read_words(path)?,
+        None => String::new(),
+    };
+    let mut blocklist = passgenr::analysis::OFFENSIVE_WORDS.to_vec();
+    blocklist.extend(blocklist_text.lines().map(str::trim).filter(|word| !word.is_empty()));
+
+    let analysis = passgenr::analysis::analyze(&words, &blocklist);
+    let unique_count = analysis.word_count - analysis.duplicates.len();
+    let examples = if wordlist_args.verbose { usize::MAX } else { WORDLIST_EXAMPLE_COUNT };
+
+    println!("Words: {} ({:.2} bits each)", analysis.word_count, (unique_count.max(1) as f64).log2());
+    println!(
+        "Length: {} to {} characters, {:.2} on average",
+        analysis.min_length, analysis.max_length, analysis.average_length
+    );
+    println!("Duplicates: {}", analysis.duplicates.len());
+    for word in analysis.duplicates.iter().take(examples) {
+        println!("    {}", word);
+    }
+    println!("Words that are prefixes of others: {} pairs", analysis.prefix_pairs.len());
+    for &(word, longer) in analysis.prefix_pairs.iter().take(examples) {
+        println!("    {} / {}", word, longer);
+    }
+    println!(
+        "Uniquely decodable without a separator: {}",
+        if analysis.uniquely_decodable { "yes" } else { "no" }
+    );
+    println!("Words at edit distance 1: {} pairs", analysis.edit_distance_one_pairs.len());
+    for &(a, b) in analysis.edit_distance_one_pairs.iter().take(examples) {
+        println!("    {} / {}", a, b);
+    }
+    println!("Offensive words: {}", analysis.offensive_words.len());
+    for word in analysis.offensive_words.iter().take(examples) {
+        println!("    {}", word);
+    }
+    Ok(())
+}
+
+fn generate_batch(batch_args: &BatchArgs) -> Result<(), String> {
+    let csv = if batch_args.path == "-" {
+        let mut csv = String::new();
+        std::io::stdin().read_to_string(&mut csv).map_err(|e| format!("Couldn't read standard input: {}.", e))?;
+        csv
+    } else {
+        std::fs::read_to_string(&batch_args.path).map_err(|e| format!("Couldn't read {}: {}.", batch_args.path, e))?
+    };
+    let specs = batch::parse_accounts(&csv)?;
+    // we'll panic on CSPRNG failure
+    let accounts = batch::generate_accounts(&specs).unwrap();
+    print!("{}", batch::write_accounts(&accounts, batch_args.format));
+    Ok(())
+}
+
+fn prepare_opts() -> Options {
+    let mut opts = Options::new();
+
+    opts.optflag("x", "hex", &format!("{} hexadecimal characters", PASSWORD_LENGTH));
+    opts.optflag("a", "ascii", &format!("{} non-whitespace printable ASCII characters", PASSWORD_LENGTH));
+    opts.optflag("n", "alpha", &format!("{} alphanumeric characters", PASSWORD_LENGTH));
+    opts.optflag("d", "digit", &format!("{} decimal digits", PASSWORD_LENGTH));
+    opts.optflag("l", "lower", &format!("{} lowercase alphabetic characters", PASSWORD_LENGTH));
+    opts.optflag("w", "words", &format!("{} random words from a list of {}", PASSWORD_WORD_COUNT, charsets::WORDS.len()));
+    opts.optflag("", "base32", &format!("{} RFC 4648 base32 characters", PASSWORD_LENGTH));
+    opts.optflag("", "crockford", &format!("{} Crockford base32 characters", PASSWORD_LENGTH));
+    opts.optflag("", "base58", &format!("{} Bitcoin base58 characters", PASSWORD_LENGTH));
+    opts.optflag("", "base64url", &format!("{} URL-safe base64 characters", PASSWORD_LENGTH));
+    opts.optflag("", "emoji", &format!("{} emoji, e.g. for device pairing codes", PASSWORD_EMOJI_COUNT));
+    opts.optopt("", "group", "insert a separator after every N characters or words", "N");
+    opts.optopt("", "group-sep", "separator inserted by --group (default: -)", "SEP");
+    opts.optflag("", "check", "with --crockford, append a check symbol");
+    opts.optopt("", "lang", "with --words, the language of the words: en, de, fr, es or it (default: en)", "CODE");
+    opts.optflag("", "fold-ascii", "with --words, strip accents from the words");
+    opts.optopt("", "rules", &format!("up to {} characters satisfying a site's passwordrules, e.g. \"minlength: 12; required: upper; required: digit;\"", PASSWORD_LENGTH), "RULES");
+    opts.optopt("", "length", "number of characters or words, instead of the defaults above", "N");
+    opts.optopt("", "keyboard", "only use characters that can be typed without dead keys on a keyboard layout: us, uk, de, fr or mobile", "LAYOUT");
+    opts.optopt("", "safe-for", "only use characters that can be pasted unescaped into a context: shell, url, yaml, xml, json or csv", "CONTEXT");
+    opts.optopt("", "escape", "print the passwords quoted or escaped for a context: shell, url, yaml, xml, json or csv", "CONTEXT");
+    opts.optflag("", "unicode-safe", "check that every character of the charset is displayed as one character, even next to the others, so that --length counts what you see");
+    opts.optopt("", "profile", &format!("use the settings of a profile in the configuration file (default: {})", DEFAULT_PROFILE), "NAME");
+    opts.optflag("", "no-config", &format!("ignore the configuration file (${})", config::CONFIG_ENV_VAR));
+    opts.optflag("e", "entropy", "print the entropy of the passwords to stderr");
+    opts.optflag("h", "help", "show this help menu");
+    opts.optopt("p", "password-count", "number of passwords to generate", "N");
+
+    opts
+}
+
+fn parse_args<F: FnOnce() -> Result<Config, String>>(opts: &Options, args: &[String], load_config: F) -> OptParseResult {
+    let matches = match opts.parse(args) {
+        Ok(m) => { m }
+        Err(f) => {
+            return OptParseResult::Err(f.to_string());
+        }
+    };
+
+    if !matches.free.is_empty() {
+        return OptParseResult::Err("There are unnecessary command-line arguments.".to_owned());
+    }
+
+    if matches.opt_present("h") {
+        return OptParseResult::Help;
+    }
+
+    let profile = match select_profile(&matches, load_config) {
+        Ok(profile) => profile,
+        Err(e) => return OptParseResult::Err(e)
+    };
+
+    let password_count = match parse_password_count(&matches) {
+        Ok(n) if !matches.opt_present("p") => profile.password_count.unwrap_or(n),
+        Ok(n) => n,
+        Err(e) => return OptParseResult::Err(e)
+    };
+
+    let mut charset : Option<CommandLineCharset> = None;
+
+    let translation_table = [
+        ("x", CommandLineCharset::Hex),
+        ("a", CommandLineCharset::Ascii),
+        ("n", CommandLineCharset::Alpha),
+        ("d", CommandLineCharset::Digit),
+        ("l", CommandLineCharset::Lower),
+        ("w", CommandLineCharset::Words),
+        ("base32", CommandLineCharset::Base32),
+        ("crockford", CommandLineCharset::Crockford),
+        ("base58", CommandLineCharset::Base58),
+        ("base64url", CommandLineCharset::Base64Url),
+        ("emoji", CommandLineCharset::Emoji)
+    ];
+
+    for option in translation_table.iter() {
+        if matches.opt_present(option.0) {
+            if charset.is_some() {
+                return OptParseResult::Err("Only one kind of password can be generated at a time.".to_owned());
+            }
+            charset = Some(option.1);
+        }
+    }
+
+    let mut rules = matches.opt_str("rules");
+    if charset.is_some() && rules.is_some() {
+        return OptParseResult::Err("Only one kind of password can be generated at a time.".to_owned());
+    }
+
+    // The profile's kind of password, and its check symbol, only apply if no kind was given on the
+    // command line.
+    let kind_from_profile = charset.is_none() && rules.is_none();
+    if kind_from_profile {
+        charset = profile.charset;
+        rules = profile.rules.clone();
+    }
+
+    let policy = match rules {
+        Some(rules) => {
+            match PasswordPolicy::parse(&rules) {
+                Ok(policy) => Some(policy),
+                Err(e) => return OptParseResult::Err(format!("The password rules are invalid: {}.", e))
+            }
+        },
+        None => None
+    };
+
+    if charset.is_none() && policy.is_none() {
+        return OptParseResult::Err("Please specify which character set to generate the password from.".to_owned());
+    }
+
+    let length = if matches.opt_present("length") {
+        match parse_positive_number(&matches, "length", 0, "password length") {
+            Ok(n) => Some(n),
+            Err(e) => return OptParseResult::Err(e)
+        }
+    } else {
+        profile.length
+    };
+
+    let mut format = OutputFormat::default();
+
+    let group = match matches.opt_str("group") {
+        Some(s) => {
+            match s.parse() {
+                Ok(n) if n > 0 => Some(n),
+                _ => {
+                    return OptParseResult::Err("The group size is not a valid positive number.".to_owned());
+                }
+            }
+        },
+        None => profile.group
+    };
+
+    match group {
+        Some(n) => {
+            let group_separator = matches.opt_str("group-sep").or(profile.group_separator);
+            format.group = Some((n, group_separator.unwrap_or_else(|| "-".to_owned())));
+        },
+        None if matches.opt_present("group-sep") => {
+            return OptParseResult::Err("A group separator can only be given along with --group.".to_owned());
+        },
+        None => {}
+    }
+
+    format.check_symbol = matches.opt_present("check") || (kind_from_profile && profile.check_symbol);
+
+    if format.check_symbol && charset != Some(CommandLineCharset::Crockford) {
+        return OptParseResult::Err("Check symbols are only supported with --crockford.".to_owned());
+    }
+
+    format.show_entropy = matches.opt_present("e") || profile.show_entropy;
+
+    let language = match matches.opt_str("lang") {
+        Some(code) => {
+            match Language::from_code(&code) {
+                Some(language) => Some(language),
+                None => return OptParseResult::Err(format!("Unknown language \"{}\".", code))
+            }
+        },
+        None => None
+    };
+
+    if charset == Some(CommandLineCharset::Words) {
+        format.language = language.or(profile.language).unwrap_or_default();
+        format.fold_ascii = matches.opt_present("fold-ascii") || profile.fold_ascii;
+        if format.language.wordlist().is_none() {
+            let code = format.language.code();
+            return OptParseResult::Err(format!("This build doesn't include the \"{}\" wordlist (see the lang-{} feature).", code, code));
+        }
+    } else if language.is_some() || matches.opt_present("fold-ascii") {
+        return OptParseResult::Err("Languages are only supported with --words.".to_owned());
+    }
+
+    let keyboard = match matches.opt_str("keyboard") {
+        Some(code) => {
+            match Layout::from_code(&code) {
+                Some(layout) => Some(layout),
+                None => return OptParseResult::Err(format!("Unknown keyboard layout \"{}\".", code))
+            }
+        },
+        None => None
+    };
+
+    let safe_for = match parse_context(&matches, "safe-for") {
+        Ok(context) => context,
+        Err(e) => return OptParseResult::Err(e)
+    };
+
+    if charset.is_some() {
+        format.keyboard = keyboard.or(profile.keyboard);
+        format.safe_for = safe_for.or(profile.safe_for);
+    } else if keyboard.is_some() {
+        return OptParseResult::Err("Keyboard layouts aren't supported with --rules.".to_owned());
+    } else if safe_for.is_some() {
+        return OptParseResult::Err("--safe-for isn't supported with --rules.".to_owned());
+    }
+
+    format.escape = match parse_context(&matches, "escape") {
+        Ok(context) => context.or(profile.escape),
+        Err(e) => return OptParseResult::Err(e)
+    };
+
+    // Passwords from rules are ASCII, so only charsets need checking.
+    if let Some(charset) = charset {
+        let elements = charset_elements(charset, &format);
+        let elements: Vec<&str> = elements.iter().map(String::as_str).collect();
+        if elements.len() < 2 {
+            let mut restrictions = Vec::new();
+            if let Some(layout) = format.keyboard {
+                restrictions.push(format!("can be typed on the {} keyboard", layout.code()));
+            }
+            if let Some(context) = format.safe_for {
+                restrictions.push(format!("are safe for {}", context.name()));
+            }
+            return OptParseResult::Err(format!("Too few characters of the {} charset {}.", charset.name(), restrictions.join(" and ")));
+        }
+        let group = format.group.as_ref().map(|&(size, ref group_separator)| (size, group_separator.as_str()));
+        if !passgenr::is_unambiguous(&elements, charset_parameters(charset).2, group) {
+            return OptParseResult::Err(format!("The {} charset's elements can't be joined with the group separator unambiguously.", charset.name()));
+        }
+        if let Some(layout) = format.keyboard {
+            if format.check_symbol && !EXTRA_CHECK_SYMBOLS.chars().all(|c| layout.is_typeable(c)) {
+                return OptParseResult::Err(format!("Some check symbols can't be typed on the {} keyboard.", layout.code()));
+            }
+        }
+        if let Some(context) = format.safe_for {
+            if format.check_symbol && !EXTRA_CHECK_SYMBOLS.chars().all(|c| context.is_safe(c)) {
+                return OptParseResult::Err(format!("Some check symbols aren't safe for {}.", context.name()));
+            }
+            if let Some((_, ref group_separator)) = format.group {
+                if !group_separator.chars().all(|c| context.is_safe(c)) {
+                    return OptParseResult::Err(format!("The group separator \"{}\" isn't safe for {}.", group_separator, context.name()));
+                }
+            }
+        }
+        if matches.opt_present("unicode-safe") || profile.unicode_safe {
+            if let Err(e) = unicode::check_unicode_safe(&elements) {
+                return OptParseResult::Err(format!("The {} charset isn't Unicode-safe: {}.", charset.name(), e));
+            }
+        }
+    }
+
+    match policy {
+        Some(policy) => {
+            if let Some(length) = length {
+                if policy.entropy_bits(length) == f64::NEG_INFINITY {
+                    return OptParseResult::Err(format!("No password of length {} can satisfy the password rules.", length));
+                }
+            }
+            let length = length.unwrap_or_else(|| policy.clamp_length(PASSWORD_LENGTH));
+            if policy.entropy_bits(length) == f64::NEG_INFINITY {
+                return OptParseResult::Err("No password can satisfy the password rules.".to_owned());
+            }
+            OptParseResult::GenerateFromRules(policy, length, password_count, format)
+        },
+        None => {
+            let charset = charset.unwrap();
+            let length = length.unwrap_or(charset_parameters(charset).1);
+            OptParseResult::Generate(charset, length, password_count, format)
+        }
+    }
+}
+
+/// The context named by the option `name`, if it's given.
+fn parse_context(matches: &Matches, name: &str) -> Result<Option<Context>, String> {
+    match matches.opt_str(name) {
+        Some(context_name) => {
+            match Context::from_name(&context_name) {
+                Some(context) => Ok(Some(context)),
+                None => Err(format!("Unknown context \"{}\".", context_name))
+            }
+        },
+        None => Ok(None)
+    }
+}
+
+/// The profile named by `--profile`, or the default profile if there is one.
+fn select_profile<F: FnOnce() -> Result<Config, String>>(matches: &Matches, load_config: F) -> Result<Profile, String> {
+    if matches.opt_present("no-config") {
+        if matches.opt_present("profile") {
+            return Err("A profile can't be used along with --no-config.".to_owned());
+        }
+        return Ok(Profile::default());
+    }
+
+    let config = load_config()?;
+    match matches.opt_str("profile") {
+        Some(name) => {
+            config.profiles.get(&name).cloned().ok_or_else(|| format!("There is no profile named \"{}\".", name))
+        },
+        None => Ok(config.profiles.get(DEFAULT_PROFILE).cloned().unwrap_or_default())
+    }
+}
+
+fn prepare_token_opts() -> Options {
+    let mut opts = Options::new();
+
+    opts.optopt("", "prefix", "fixed prefix of every token (default: none)", "PREFIX");
+    opts.optopt("", "length", &format!("number of random characters in the token body (default: {})", TOKEN_BODY_LENGTH), "N");
+    opts.optopt("", "checksum", "checksum appended to the body: crc32, crc32c or none (default: crc32)", "ALGORITHM");
+    opts.optflag("h", "help", "show this help menu");
+    opts.optopt("p", "password-count", "number of tokens to generate", "N");
+
+    opts
+}
+
+fn parse_token_args(opts: &Options, args: &[String]) -> OptParseResult {
+    let matches = match opts.parse(args) {
+        Ok(m) => { m }
+        Err(f) => {
+            return OptParseResult::Err(f.to_string());
+        }
+    };
+
+    if !matches.free.is_empty() {
+        return OptParseResult::Err("There are unnecessary command-line arguments.".to_owned());
+    }
+
+    if matches.opt_present("h") {
+        return OptParseResult::Help;
+    }
+
+    let token_count = match parse_password_count(&matches) {
+        Ok(n) => n,
+        Err(e) => return OptParseResult::Err(e)
+    };
+
+    let body_length = match parse_positive_number(&matches, "length", TOKEN_BODY_LENGTH, "token length") {
+        Ok(n) => n,
+        Err(e) => return OptParseResult::Err(e)
+    };
+
+    let checksum = match matches.opt_str("checksum").as_deref() {
+        None | Some("crc32") => ChecksumAlgorithm::Crc32,
+        Some("crc32c") => ChecksumAlgorithm::Crc32c,
+        Some("none") => ChecksumAlgorithm::None,
+        Some(_) => {
+            return OptParseResult::Err("The checksum must be one of crc32, crc32c or none.".to_owned());
+        }
+    };
+
+    let token_args = TokenArgs {
+        prefix: matches.opt_str("prefix").unwrap_or_default(),
+        body_length,
+        checksum,
+    };
+
+    OptParseResult::Token(token_args, token_count)
+}
+
+fn prepare_recovery_code_opts() -> Options {
+    let mut opts = Options::new();
+
+    opts.optopt("", "count", &format!("number of codes in the set (default: {})", RECOVERY_CODE_COUNT), "N");
+    opts.optopt("", "groups", "number of hyphen-separated groups in each code (default: 2)", "N");
+    opts.optopt("", "group-length", "number of characters in each group (default: 5)", "N");
+    opts.optopt("", "hash", "also print a salted hash of each code for storage: sha256", "ALGORITHM");
+    opts.optflag("h", "help", "show this help menu");
+
+    opts
+}
+
+fn parse_recovery_code_args(opts: &Options, args: &[String]) -> OptParseResult {
+    let matches = match opts.parse(args) {
+        Ok(m) => { m }
+        Err(f) => {
+            return OptParseResult::Err(f.to_string());
+        }
+    };
+
+    if !matches.free.is_empty() {
+        return OptParseResult::Err("There are unnecessary command-line arguments.".to_owned());
+    }
+
+    if matches.opt_present("h") {
+        return OptParseResult::Help;
+    }
+
+    let default_format = passgenr::recovery::RecoveryCodeFormat::default();
+
+    let count = match parse_positive_number(&matches, "count", RECOVERY_CODE_COUNT, "code count") {
+        Ok(n) => n,
+        Err(e) => return OptParseResult::Err(e)
+    };
+
+    let group_count = match parse_positive_number(&matches, "groups", default_format.group_count, "group count") {
+        Ok(n) => n,
+        Err(e) => return OptParseResult::Err(e)
+    };
+
+    let group_length = match parse_positive_number(&matches, "group-length", default_format.group_length, "group length") {
+        Ok(n) => n,
+        Err(e) => return OptParseResult::Err(e)
+    };
+
+    let possible_codes = (default_format.charset.len() as u64).checked_pow((group_count * group_length) as u32);
+    if matches!(possible_codes, Some(n) if n < count as u64) {
+        return OptParseResult::Err("The codes are too short to make that many distinct codes.".to_owned());
+    }
+
+    let hash = match matches.opt_str("hash").as_deref() {
+        None => None,
+        Some("sha256") => Some(RecoveryCodeHash::Sha256),
+        Some(_) => {
+            return OptParseResult::Err("The hash must be sha256.".to_owned());
+        }
+    };
+
+    OptParseResult::RecoveryCodes(RecoveryCodeArgs { count, group_count, group_length, hash })
+}
+
+fn prepare_bip39_opts() -> Options {
+    let mut opts = Options::new();
+
+    opts.optopt("", "words", &format!("number of words: 12, 15, 18, 21 or 24 (default: {})", BIP39_WORD_COUNT), "N");
+    opts.optopt("", "validate", "check the checksum of an existing mnemonic instead of generating one", "MNEMONIC");
+    opts.optflag("h", "help", "show this help menu");
+    opts.optopt("p", "password-count", "number of mnemonics to generate", "N");
+
+    opts
+}
+
+fn parse_bip39_args(opts: &Options, args: &[String]) -> OptParseResult {
+    let matches = match opts.parse(args) {
+        Ok(m) => { m }
+        Err(f) => {
+            return OptParseResult::Err(f.to_string());
+        }
+    };
+
+    if !matches.free.is_empty() {
+        return OptParseResult::Err("There are unnecessary command-line arguments.".to_owned());
+    }
+
+    if matches.opt_present("h") {
+        return OptParseResult::Help;
+    }
+
+    if let Some(mnemonic) = matches.opt_str("validate") {
+        if matches.opt_present("words") || matches.opt_present("p") {
+            return OptParseResult::Err("A mnemonic can't be validated and generated at the same time.".to_owned());
+        }
+        return OptParseResult::ValidateBip39(mnemonic);
+    }
+
+    let mnemonic_count = match parse_password_count(&matches) {
+        Ok(n) => n,
+        Err(e) => return OptParseResult::Err(e)
+    };
+
+    let word_count = match matches.opt_str("words") {
+        Some(s) => {
+            match s.parse() {
+                Ok(n) if passgenr::bip39::WORD_COUNTS.contains(&n) => n,
+                _ => {
+                    return OptParseResult::Err("The word count must be 12, 15, 18, 21 or 24.".to_owned());
+                }
+            }
+        },
+        None => BIP39_WORD_COUNT
+    };
+
+    OptParseResult::Bip39(word_count, mnemonic_count)
+}
+
+fn prepare_profiles_opts() -> Options {
+    let mut opts = Options::new();
+
+    opts.optflag("h", "help", "show this help menu");
+
+    opts
+}
+
+fn parse_profiles_args<F: FnOnce() -> Result<Config, String>>(opts: &Options, args: &[String], load_config: F) -> OptParseResult {
+    let matches = match opts.parse(args) {
+        Ok(m) => { m }
+        Err(f) => {
+            return OptParseResult::Err(f.to_string());
+        }
+    };
+
+    if matches.opt_present("h") {
+        return OptParseResult::Help;
+    }
+
+    if matches.free != ["list"] {
+        return OptParseResult::Err("The only profiles command is \"list\".".to_owned());
+    }
+
+    match load_config() {
+        Ok(config) => OptParseResult::ListProfiles(config),
+        Err(e) => OptParseResult::Err(e)
+    }
+}
+
+fn prepare_wordlist_opts() -> Options {
+    let mut opts = Options::new();
+
+    opts.optopt("", "blocklist", "a file of more words to report as offensive, one per line", "PATH");
+    opts.optflag("v", "verbose", "list every pair of similar words, not just a few");
+    opts.optflag("h", "help", "show this help menu");
+
+    opts
+}
+
+fn parse_wordlist_args(opts: &Options, args: &[String]) -> OptParseResult {
+    let matches = match opts.parse(args) {
+        Ok(m) => { m }
+        Err(f) => {
+            return OptParseResult::Err(f.to_string());
+        }
+    };
+
+    if matches.opt_present("h") {
+        return OptParseResult::Help;
+    }
+
+    match matches.free.first().map(String::as_str) {
+        Some("analyze") => {},
+        _ => return OptParseResult::Err("The only wordlist command is \"analyze\".".to_owned()),
+    }
+    if matches.free.len() != 2 {
+        return OptParseResult::Err("The wordlist to analyze must be given as one path.".to_owned());
+    }
+
+    OptParseResult::AnalyzeWordlist(WordlistArgs {
+        path: matches.free[1].clone(),
+        blocklist: matches.opt_str("blocklist"),
+        verbose: matches.opt_present("v"),
+    })
+}
+
+fn prepare_batch_opts() -> Options {
+    let mut opts = Options::new();
+
+    opts.optopt("f", "format", "the password manager to import into: bitwarden (JSON, the default), keepass or 1password (CSV)", "FORMAT");
+    opts.optflag("h", "help", "show this help menu");
+
+    opts
+}
+
+fn parse_batch_args(opts: &Options, args: &[String]) -> OptParseResult {
+    let matches = match opts.parse(args) {
+        Ok(m) => { m }
+        Err(f) => {
+            return OptParseResult::Err(f.to_string());
+        }
+    };
+
+    if matches.opt_present("h") {
+        return OptParseResult::Help;
+    }
+
+    if matches.free.len() != 1 {
+        return OptParseResult::Err("The CSV of accounts must be given as one path, or \"-\" for standard input.".to_owned());
+    }
+    let format = match matches.opt_str("f") {
+        Some(name) => match ImportFormat::from_name(&name) {
+            Some(format) => format,
+            None => return OptParseResult::Err(format!("Unknown import format \"{}\".", name)),
+        },
+        None => ImportFormat::Bitwarden,
+    };
+
+    OptParseResult::Batch(BatchArgs { path: matches.free[0].clone(), format })
+}
+
+fn parse_positive_number(matches: &Matches, opt: &str, default: usize, what: &str) -> Result<usize, String> {
+    match matches.opt_str(opt) {
+        Some(s) => {
+            match s.parse() {
+                Ok(n) if n > 0 => Ok(n),
+                _ => Err(format!("The {} is not a valid positive number.", what))
+            }
+        },
+        None => Ok(default)
+    }
+}
+
+fn parse_password_count(matches: &Matches) -> Result<u32, String> {
+    match matches.opt_str("p") {
+        Some(s) => {
+            match s.parse() {
+                Ok(n) => Ok(n),
+                Err(_) => Err("The password count is not a valid number.".to_owned())
+            }
+        },
+        None => Ok(1)
+    }
+}
+
+fn print_usage(program: &str, opts: Options, error: Option<&str>) {
+    if let Some(err_message) = error {
+        println!("Error: {}", err_message);
+    }
+    let brief = format!("Usage: {} [options]", program);
+    print!("{}", opts.usage(&brief));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_config() -> Result<Config, String> {
+        Ok(Config::default())
+    }
+
+    #[test]
+    fn test_help() {
+        let opts = prepare_opts();
+        assert_eq!(OptParseResult::Help, parse_args(&opts, &["-h".to_owned()], no_config));
+    }
+
+    #[test]
+    fn test_charset_flags() {
+        let translation_table = [
+            ("x", CommandLineCharset::Hex),
+            ("a", CommandLineCharset::Ascii),
+            ("n", CommandLineCharset::Alpha),
+            ("d", CommandLineCharset::Digit),
+            ("l", CommandLineCharset::Lower),
+            ("w", CommandLineCharset::Words)
+        ];
+        for option in translation_table.iter() {
+            let opts = prepare_opts();
+            assert_eq!(
+                OptParseResult::Generate(option.1, charset_parameters(option.1).1, 1, OutputFormat::default()),
+                parse_args(&opts, &[format!("-{}", option.0)], no_config)
+            );
+        }
+    }
+
+    #[test]
+    fn test_long_charset_flags() {
+        let translation_table = [
+            ("hex", CommandLineCharset::Hex),
+            ("ascii", CommandLineCharset::Ascii),
+            ("alpha", CommandLineCharset::Alpha),
+            ("digit", CommandLineCharset::Digit),
+            ("lower", CommandLineCharset::Lower),
+            ("words", CommandLineCharset::Words),
+            ("base32", CommandLineCharset::Base32),
+            ("crockford", CommandLineCharset::Crockford),
+            ("base58", CommandLineCharset::Base58),
+            ("base64url", CommandLineCharset::Base64Url),
+            ("emoji", CommandLineCharset::Emoji)
+        ];
+        for option in translation_table.iter() {
+            let opts = prepare_opts();
+            assert_eq!(
+                OptParseResult::Generate(option.1, charset_parameters(option.1).1, 1, OutputFormat::default()),
+                parse_args(&opts, &[format!("--{}", option.0)], no_config)
+            );
+        }
+    }
+
+    #[test]
+    fn test_password_count() {
+        let opts = prepare_opts();
+        assert_eq!(
+            OptParseResult::Generate(CommandLineCharset::Hex, PASSWORD_LENGTH, 5, OutputFormat::default()),
+            parse_args(&opts, &["-x".to_owned(), "-p".to_owned(), "5".to_owned()], no_config)
+        );
+    }
+
+    #[test]
+    fn test_extra_arguments() {
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &["-h".to_owned(), "foo".to_owned()], no_config) {
+            assert!(e.contains("unnecessary command-line arguments"));
+        } else {
+            panic!("Argument parsing doesn't detect duplicate arguments.");
+        }
+    }
+
+    #[test]
+    fn test_unknown_argument() {
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &["-z".to_owned()], no_config) {
+            assert!(e.contains("Unrecognized option"));
+        } else {
+            panic!("Argument parsing doesn't detect unknown options.");
+        }
+    }
+
+    #[test]
+    fn test_multiple_charset_flags() {
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &["-x".to_owned(), "-a".to_owned()], no_config) {
+            assert!(e.contains("Only one kind"));
+        } else {
+            panic!("Argument parsing doesn't fail on multiple charset flags.");
+        }
+    }
+
+    #[test]
+    fn test_duplicate_charset_flags() {
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &["-x".to_owned(), "-x".to_owned()], no_config) {
+            assert!(e.contains("given more than once"));
+        } else {
+            panic!("Argument parsing doesn't fail on duplicate charset flags.");
+        }
+    }
+
+    #[test]
+    fn test_invalid_password_count() {
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &["-x".to_owned(), "-p".to_owned(), "foo".to_owned()], no_config) {
+            assert!(e.contains("not a valid number"));
+        } else {
+            panic!("Argument parsing doesn't fail on duplicate charset flags.");
+        }
+    }
+
+    #[test]
+    fn test_no_arguments() {
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &[], no_config) {
+            assert!(e.contains("specify which character set"));
+        } else {
+            panic!("Argument parsing doesn't fail on an empty argument list.");
+        }
+    }
+
+    #[test]
+    fn test_crockford_formatting() {
+        let opts = prepare_opts();
+        let args: Vec<String> = ["--crockford", "--group", "5", "--check"]
+            .iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            OptParseResult::Generate(
+                CommandLineCharset::Crockford,
+                PASSWORD_LENGTH,
+                1,
+                OutputFormat { group: Some((5, "-".to_owned())), check_symbol: true, show_entropy: false, ..OutputFormat::default() }
+            ),
+            parse_args(&opts, &args, no_config)
+        );
+    }
+
+    #[test]
+    fn test_grouping() {
+        let opts = prepare_opts();
+        let args: Vec<String> = ["--words", "--group", "3", "--group-sep", " "]
+            .iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            OptParseResult::Generate(
+                CommandLineCharset::Words,
+                PASSWORD_WORD_COUNT,
+                1,
+                OutputFormat { group: Some((3, " ".to_owned())), check_symbol: false, show_entropy: false, ..OutputFormat::default() }
+            ),
+            parse_args(&opts, &args, no_config)
+        );
+    }
+
+    #[test]
+    fn test_group_separator_requires_group() {
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &["--hex".to_owned(), "--group-sep".to_owned(), " ".to_owned()], no_config) {
+            assert!(e.contains("along with --group"));
+        } else {
+            panic!("Argument parsing doesn't fail on --group-sep without --group.");
+        }
+    }
+
+    #[test]
+    fn test_ambiguous_group_separator() {
+        let opts = prepare_opts();
+        let args = |args: &[&str]| args.iter().map(|&s| s.to_owned()).collect::<Vec<String>>();
+        for &group in ["1", "3"].iter() {
+            if let OptParseResult::Err(e) = parse_args(&opts, &args(&["--words", "--group", group, "--group-sep", ""]), no_config) {
+                assert_eq!(e, "The words charset's elements can't be joined with the group separator unambiguously.");
+            } else {
+                panic!("Argument parsing doesn't fail on an empty group separator with --words.");
+            }
+        }
+        assert!(matches!(parse_args(&opts, &args(&["--words", "--group", "3", "--group-sep", " "]), no_config), OptParseResult::Generate(..)));
+        assert!(matches!(parse_args(&opts, &args(&["--hex", "--group", "4", "--group-sep", ""]), no_config), OptParseResult::Generate(..)));
+    }
+
+    #[test]
+    fn test_check_symbol_requires_crockford() {
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &["--hex".to_owned(), "--check".to_owned()], no_config) {
+            assert!(e.contains("only supported with --crockford"));
+        } else {
+            panic!("Argument parsing doesn't fail on --check without --crockford.");
+        }
+    }
+
+    #[test]
+    fn test_invalid_group_size() {
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &["--crockford".to_owned(), "--group".to_owned(), "0".to_owned()], no_config) {
+            assert!(e.contains("not a valid positive number"));
+        } else {
+            panic!("Argument parsing doesn't fail on an invalid group size.");
+        }
+    }
+
+    #[test]
+    fn test_rules() {
+        let opts = prepare_opts();
+        let rules = "minlength: 12; required: upper; required: digit; allowed: [-_];";
+        assert_eq!(
+            OptParseResult::GenerateFromRules(
+                PasswordPolicy::parse(rules).unwrap(),
+                PASSWORD_LENGTH,
+                2,
+                OutputFormat { group: None, check_symbol: false, show_entropy: true, ..OutputFormat::default() }
+            ),
+            parse_args(&opts, &["--rules".to_owned(), rules.to_owned(), "-p".to_owned(), "2".to_owned(), "-e".to_owned()], no_config)
+        );
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &["--rules".to_owned(), "required: letters".to_owned()], no_config) {
+            assert!(e.contains("rules are invalid"));
+        } else {
+            panic!("Argument parsing doesn't fail on invalid password rules.");
+        }
+
+        let opts = prepare_opts();
+        let args = ["--rules".to_owned(), "maxlength: 2; required: upper; required: digit; required: lower".to_owned()];
+        if let OptParseResult::Err(e) = parse_args(&opts, &args, no_config) {
+            assert!(e.contains("No password can satisfy"));
+        } else {
+            panic!("Argument parsing doesn't fail on unsatisfiable password rules.");
+        }
+    }
+
+    #[test]
+    fn test_rules_and_charset() {
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &["--hex".to_owned(), "--rules".to_owned(), "minlength: 8".to_owned()], no_config) {
+            assert!(e.contains("Only one kind"));
+        } else {
+            panic!("Argument parsing doesn't fail on --rules with a charset flag.");
+        }
+    }
+
+    #[test]
+    fn test_token_defaults() {
+        let opts = prepare_token_opts();
+        assert_eq!(
+            OptParseResult::Token(
+                TokenArgs { prefix: "".to_owned(), body_length: TOKEN_BODY_LENGTH, checksum: ChecksumAlgorithm::Crc32 },
+                1
+            ),
+            parse_token_args(&opts, &[])
+        );
+    }
+
+    #[test]
+    fn test_token_options() {
+        let opts = prepare_token_opts();
+        let args: Vec<String> = ["--prefix", "myco_", "--length", "40", "--checksum", "crc32c", "-p", "3"]
+            .iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            OptParseResult::Token(
+                TokenArgs { prefix: "myco_".to_owned(), body_length: 40, checksum: ChecksumAlgorithm::Crc32c },
+                3
+            ),
+            parse_token_args(&opts, &args)
+        );
+    }
+
+    #[test]
+    fn test_token_invalid_length() {
+        for length in ["0", "foo"].iter() {
+            let opts = prepare_token_opts();
+            if let OptParseResult::Err(e) = parse_token_args(&opts, &["--length".to_owned(), length.to_string()]) {
+                assert!(e.contains("not a valid positive number"));
+            } else {
+                panic!("Argument parsing doesn't fail on an invalid token length.");
+            }
+        }
+    }
+
+    #[test]
+    fn test_token_invalid_checksum() {
+        let opts = prepare_token_opts();
+        if let OptParseResult::Err(e) = parse_token_args(&opts, &["--checksum".to_owned(), "md5".to_owned()]) {
+            assert!(e.contains("must be one of"));
+        } else {
+            panic!("Argument parsing doesn't fail on an unknown checksum.");
+        }
+    }
+
+    #[test]
+    fn test_recovery_code_defaults() {
+        let opts = prepare_recovery_code_opts();
+        assert_eq!(
+            OptParseResult::RecoveryCodes(
+                RecoveryCodeArgs { count: RECOVERY_CODE_COUNT, group_count: 2, group_length: 5, hash: None }
+            ),
+            parse_recovery_code_args(&opts, &[])
+        );
+    }
+
+    #[test]
+    fn test_recovery_code_options() {
+        let opts = prepare_recovery_code_opts();
+        let args: Vec<String> = ["--count", "16", "--groups", "3", "--group-length", "4", "--hash", "sha256"]
+            .iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            OptParseResult::RecoveryCodes(
+                RecoveryCodeArgs { count: 16, group_count: 3, group_length: 4, hash: Some(RecoveryCodeHash::Sha256) }
+            ),
+            parse_recovery_code_args(&opts, &args)
+        );
+    }
+
+    #[test]
+    fn test_recovery_code_too_many_codes() {
+        let opts = prepare_recovery_code_opts();
+        let args: Vec<String> = ["--count", "33", "--groups", "1", "--group-length", "1"]
+            .iter().map(|s| s.to_string()).collect();
+        if let OptParseResult::Err(e) = parse_recovery_code_args(&opts, &args) {
+            assert!(e.contains("too short"));
+        } else {
+            panic!("Argument parsing doesn't fail when too many codes are requested.");
+        }
+    }
+
+    #[test]
+    fn test_recovery_code_invalid_hash() {
+        let opts = prepare_recovery_code_opts();
+        if let OptParseResult::Err(e) = parse_recovery_code_args(&opts, &["--hash".to_owned(), "md5".to_owned()]) {
+            assert!(e.contains("must be sha256"));
+        } else {
+            panic!("Argument parsing doesn't fail on an unknown hash.");
+        }
+    }
+
+    #[test]
+    fn test_bip39() {
+        let opts = prepare_bip39_opts();
+        assert_eq!(OptParseResult::Bip39(BIP39_WORD_COUNT, 1), parse_bip39_args(&opts, &[]));
+        let opts = prepare_bip39_opts();
+        assert_eq!(
+            OptParseResult::Bip39(12, 3),
+            parse_bip39_args(&opts, &["--words".to_owned(), "12".to_owned(), "-p".to_owned(), "3".to_owned()])
+        );
+    }
+
+    #[test]
+    fn test_bip39_invalid_word_count() {
+        let opts = prepare_bip39_opts();
+        if let OptParseResult::Err(e) = parse_bip39_args(&opts, &["--words".to_owned(), "13".to_owned()]) {
+            assert!(e.contains("must be 12, 15, 18, 21 or 24"));
+        } else {
+            panic!("Argument parsing doesn't fail on an invalid BIP39 word count.");
+        }
+    }
+
+    #[test]
+    fn test_bip39_validate() {
+        let opts = prepare_bip39_opts();
+        assert_eq!(
+            OptParseResult::ValidateBip39("zoo zoo".to_owned()),
+            parse_bip39_args(&opts, &["--validate".to_owned(), "zoo zoo".to_owned()])
+        );
+
+        let opts = prepare_bip39_opts();
+        let args = ["--validate".to_owned(), "zoo zoo".to_owned(), "--words".to_owned(), "12".to_owned()];
+        if let OptParseResult::Err(e) = parse_bip39_args(&opts, &args) {
+            assert!(e.contains("at the same time"));
+        } else {
+            panic!("Argument parsing doesn't fail when validating and generating at once.");
+        }
+    }
+
+    const TEST_CONFIG: &str = r#"
+        [profile.wifi]
+        charset = "alpha"
+        length = 20
+
+        [profile.default]
+        charset = "words"
+        group = 3
+        group-sep = " "
+
+        [profile.site]
+        rules = "maxlength: 16; required: digit;"
+        password-count = 3
+
+        [profile.code]
+        charset = "crockford"
+        length = 12
+        check = true
+    "#;
+
+    fn test_config() -> Result<Config, String> {
+        config::parse_config(TEST_CONFIG)
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_length() {
+        let opts = prepare_opts();
+        assert_eq!(
+            OptParseResult::Generate(CommandLineCharset::Words, 6, 1, OutputFormat::default()),
+            parse_args(&opts, &args(&["--words", "--length", "6"]), no_config)
+        );
+
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &args(&["--hex", "--length", "0"]), no_config) {
+            assert!(e.contains("not a valid positive number"));
+        } else {
+            panic!("Argument parsing doesn't fail on an invalid length.");
+        }
+
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &args(&["--rules", "maxlength: 8;", "--length", "9"]), no_config) {
+            assert!(e.contains("No password of length 9"));
+        } else {
+            panic!("Argument parsing doesn't fail on a length the rules don't allow.");
+        }
+    }
+
+    #[test]
+    fn test_profile() {
+        let opts = prepare_opts();
+        assert_eq!(
+            OptParseResult::Generate(CommandLineCharset::Alpha, 20, 1, OutputFormat::default()),
+            parse_args(&opts, &args(&["--profile", "wifi"]), test_config)
+        );
+
+        let opts = prepare_opts();
+        assert_eq!(
+            OptParseResult::GenerateFromRules(
+                PasswordPolicy::parse("maxlength: 16; required: digit;").unwrap(),
+                16,
+                3,
+                OutputFormat::default()
+            ),
+            parse_args(&opts, &args(&["--profile", "site"]), test_config)
+        );
+    }
+
+    #[test]
+    fn test_default_profile() {
+        let opts = prepare_opts();
+        assert_eq!(
+            OptParseResult::Generate(
+                CommandLineCharset::Words,
+                PASSWORD_WORD_COUNT,
+                1,
+                OutputFormat { group: Some((3, " ".to_owned())), check_symbol: false, show_entropy: false, ..OutputFormat::default() }
+            ),
+            parse_args(&opts, &[], test_config)
+        );
+    }
+
+    #[test]
+    fn test_options_override_profile() {
+        let opts = prepare_opts();
+        assert_eq!(
+            OptParseResult::Generate(CommandLineCharset::Hex, 32, 2, OutputFormat::default()),
+            parse_args(&opts, &args(&["--profile", "wifi", "--hex", "--length", "32", "-p", "2"]), test_config)
+        );
+
+        let opts = prepare_opts();
+        assert_eq!(
+            OptParseResult::Generate(CommandLineCharset::Digit, 12, 1, OutputFormat::default()),
+            parse_args(&opts, &args(&["--profile", "code", "--digit"]), test_config)
+        );
+
+        let opts = prepare_opts();
+        assert_eq!(
+            OptParseResult::Generate(
+                CommandLineCharset::Words,
+                PASSWORD_WORD_COUNT,
+                1,
+                OutputFormat { group: Some((5, "/".to_owned())), check_symbol: false, show_entropy: false, ..OutputFormat::default() }
+            ),
+            parse_args(&opts, &args(&["--group", "5", "--group-sep", "/"]), test_config)
+        );
+
+        let opts = prepare_opts();
+        assert_eq!(
+            OptParseResult::GenerateFromRules(PasswordPolicy::parse("minlength: 8;").unwrap(), 64, 3, OutputFormat::default()),
+            parse_args(&opts, &args(&["--profile", "site", "--rules", "minlength: 8;"]), test_config)
+        );
+    }
+
+    #[test]
+    fn test_unknown_profile() {
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &args(&["--profile", "bank"]), test_config) {
+            assert!(e.contains("no profile named \"bank\""));
+        } else {
+            panic!("Argument parsing doesn't fail on an unknown profile.");
+        }
+    }
+
+    #[test]
+    fn test_no_config() {
+        let opts = prepare_opts();
+        let fail = || -> Result<Config, String> { panic!("The configuration was loaded.") };
+        assert_eq!(
+            OptParseResult::Generate(CommandLineCharset::Hex, PASSWORD_LENGTH, 1, OutputFormat::default()),
+            parse_args(&opts, &args(&["--hex", "--no-config"]), fail)
+        );
+
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &args(&["--no-config", "--profile", "wifi"]), fail) {
+            assert!(e.contains("along with --no-config"));
+        } else {
+            panic!("Argument parsing doesn't fail on --profile with --no-config.");
+        }
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let opts = prepare_opts();
+        let invalid = || config::parse_config("[profile.wifi]\nlength = 0");
+        if let OptParseResult::Err(e) = parse_args(&opts, &args(&["--hex"]), invalid) {
+            assert!(e.contains("must be a positive number"));
+        } else {
+            panic!("Argument parsing doesn't fail on an invalid configuration file.");
+        }
+    }
+
+    #[test]
+    fn test_profiles_list() {
+        let opts = prepare_profiles_opts();
+        assert_eq!(
+            OptParseResult::ListProfiles(test_config().unwrap()),
+            parse_profiles_args(&opts, &args(&["list"]), test_config)
+        );
+
+        for bad_args in [&[][..], &["show"][..], &["list", "wifi"][..]].iter() {
+            let opts = prepare_profiles_opts();
+            if let OptParseResult::Err(e) = parse_profiles_args(&opts, &args(bad_args), test_config) {
+                assert!(e.contains("only profiles command"));
+            } else {
+                panic!("Argument parsing doesn't fail on an unknown profiles command.");
+            }
+        }
+    }
+
+    #[test]
+    fn test_language() {
+        let opts = prepare_opts();
+        assert_eq!(
+            OptParseResult::Generate(
+                CommandLineCharset::Words,
+                PASSWORD_WORD_COUNT,
+                1,
+                OutputFormat { language: Language::English, fold_ascii: true, ..OutputFormat::default() }
+            ),
+            parse_args(&opts, &args(&["--words", "--lang", "EN", "--fold-ascii"]), no_config)
+        );
+
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &args(&["--words", "--lang", "xx"]), no_config) {
+            assert!(e.contains("Unknown language"));
+        } else {
+            panic!("Argument parsing doesn't fail on an unknown language.");
+        }
+
+        for bad_args in [&["--hex", "--lang", "en"][..], &["--hex", "--fold-ascii"][..]].iter() {
+            let opts = prepare_opts();
+            if let OptParseResult::Err(e) = parse_args(&opts, &args(bad_args), no_config) {
+                assert!(e.contains("only supported with --words"));
+            } else {
+                panic!("Argument parsing doesn't fail on a language without --words.");
+            }
+        }
+    }
+
+    #[test]
+    fn test_keyboard() {
+        let opts = prepare_opts();
+        assert_eq!(
+            OptParseResult::Generate(
+                CommandLineCharset::Ascii,
+                PASSWORD_LENGTH,
+                1,
+                OutputFormat { keyboard: Some(Layout::French), ..OutputFormat::default() }
+            ),
+            parse_args(&opts, &args(&["--ascii", "--keyboard", "FR"]), no_config)
+        );
+        let format = OutputFormat { keyboard: Some(Layout::French), ..OutputFormat::default() };
+        let elements = charset_elements(CommandLineCharset::Ascii, &format);
+        assert_eq!(elements.len(), 91);
+        assert!(!elements.contains(&"^".to_owned()));
+
+        let opts = prepare_opts();
+        let config = || config::parse_config("[profile.default]\nkeyboard = \"de\"");
+        assert_eq!(
+            OptParseResult::Generate(
+                CommandLineCharset::Hex,
+                PASSWORD_LENGTH,
+                1,
+                OutputFormat { keyboard: Some(Layout::German), ..OutputFormat::default() }
+            ),
+            parse_args(&opts, &args(&["--hex"]), config)
+        );
+
+        let cases = [
+            (&["--ascii", "--keyboard", "dvorak"][..], "Unknown keyboard layout \"dvorak\"."),
+            (&["--rules", "minlength: 8;", "--keyboard", "de"][..], "aren't supported with --rules"),
+            (&["--emoji", "--keyboard", "us"][..], "Too few characters of the emoji charset can be typed on the us keyboard."),
+            (&["--crockford", "--check", "--keyboard", "fr"][..], "Some check symbols can't be typed on the fr keyboard."),
+        ];
+        for &(bad_args, message) in cases.iter() {
+            let opts = prepare_opts();
+            if let OptParseResult::Err(e) = parse_args(&opts, &args(bad_args), no_config) {
+                assert!(e.contains(message), "{}", e);
+            } else {
+                panic!("Argument parsing doesn't fail on {:?}.", bad_args);
+            }
+        }
+
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &args(&["--crockford", "--check", "--keyboard", "de"]), no_config) {
+            panic!("{}", e);
+        }
+    }
+
+    #[test]
+    fn test_contexts() {
+        let opts = prepare_opts();
+        let format = OutputFormat {
+            safe_for: Some(Context::Shell),
+            escape: Some(Context::Json),
+            ..OutputFormat::default()
+        };
+        assert_eq!(
+            OptParseResult::Generate(CommandLineCharset::Ascii, PASSWORD_LENGTH, 1, format.clone()),
+            parse_args(&opts, &args(&["--ascii", "--safe-for", "shell", "--escape", "JSON"]), no_config)
+        );
+        assert_eq!(charset_elements(CommandLineCharset::Ascii, &format).len(), 72);
+
+        let format = OutputFormat {
+            keyboard: Some(Layout::French),
+            safe_for: Some(Context::Yaml),
+            ..OutputFormat::default()
+        };
+        assert_eq!(charset_elements(CommandLineCharset::Ascii, &format).len(), 74);
+
+        let opts = prepare_opts();
+        assert_eq!(
+            OptParseResult::GenerateFromRules(
+                PasswordPolicy::parse("minlength: 8;").unwrap(),
+                PASSWORD_LENGTH,
+                1,
+                OutputFormat { escape: Some(Context::Url), ..OutputFormat::default() }
+            ),
+            parse_args(&opts, &args(&["--rules", "minlength: 8;", "--escape", "url"]), no_config)
+        );
+
+        let cases = [
+            (&["--ascii", "--safe-for", "sql"][..], "Unknown context \"sql\"."),
+            (&["--ascii", "--escape", "sql"][..], "Unknown context \"sql\"."),
+            (&["--rules", "minlength: 8;", "--safe-for", "url"][..], "isn't supported with --rules"),
+            (&["--emoji", "--safe-for", "url"][..], "Too few characters of the emoji charset are safe for url."),
+            (&["--emoji", "--keyboard", "de", "--safe-for", "url"][..], "can be typed on the de keyboard and are safe for url."),
+            (&["--crockford", "--check", "--safe-for", "shell"][..], "Some check symbols aren't safe for shell."),
+            (&["--hex", "--group", "4", "--safe-for", "csv"][..], "The group separator \"-\" isn't safe for csv."),
+        ];
+        for &(bad_args, message) in cases.iter() {
+            let opts = prepare_opts();
+            if let OptParseResult::Err(e) = parse_args(&opts, &args(bad_args), no_config) {
+                assert!(e.contains(message), "{}", e);
+            } else {
+                panic!("Argument parsing doesn't fail on {:?}.", bad_args);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unicode_safe() {
+        for good_args in [&["--emoji", "--unicode-safe"][..], &["--hex", "--unicode-safe"][..]].iter() {
+            let opts = prepare_opts();
+            if let OptParseResult::Err(e) = parse_args(&opts, &args(good_args), no_config) {
+                panic!("{}", e);
+            }
+        }
+
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &args(&["--words", "--unicode-safe"]), no_config) {
+            assert!(e.contains("The words charset isn't Unicode-safe: the element \"abacus\" isn't a single grapheme."));
+        } else {
+            panic!("Argument parsing doesn't fail on words with --unicode-safe.");
+        }
+
+        let opts = prepare_opts();
+        let config = || config::parse_config("[profile.default]\nunicode-safe = true");
+        if let OptParseResult::Err(e) = parse_args(&opts, &args(&["--words"]), config) {
+            assert!(e.contains("isn't Unicode-safe"));
+        } else {
+            panic!("Argument parsing ignores unicode-safe in a profile.");
+        }
+    }
+
+    #[cfg(feature = "lang-de")]
+    #[test]
+    fn test_language_from_profile() {
+        let opts = prepare_opts();
+        let config = || config::parse_config("[profile.default]\nlang = \"de\"\nfold-ascii = true");
+        assert_eq!(
+            OptParseResult::Generate(
+                CommandLineCharset::Words,
+                PASSWORD_WORD_COUNT,
+                1,
+                OutputFormat { language: Language::German, fold_ascii: true, ..OutputFormat::default() }
+            ),
+            parse_args(&opts, &args(&["--words"]), config)
+        );
+
+        let opts = prepare_opts();
+        assert_eq!(
+            OptParseResult::Generate(CommandLineCharset::Hex, PASSWORD_LENGTH, 1, OutputFormat::default()),
+            parse_args(&opts, &args(&["--hex"]), config)
+        );
+    }
+
+    #[cfg(not(feature = "lang-de"))]
+    #[test]
+    fn test_missing_language() {
+        let opts = prepare_opts();
+        if let OptParseResult::Err(e) = parse_args(&opts, &args(&["--words", "--lang", "de"]), no_config) {
+            assert!(e.contains("lang-de feature"));
+        } else {
+            panic!("Argument parsing doesn't fail on a language that isn't compiled in.");
+        }
+    }
+
+    #[test]
+    fn test_wordlist_analyze() {
+        let opts = prepare_wordlist_opts();
+        assert_eq!(
+            OptParseResult::AnalyzeWordlist(WordlistArgs { path: "words.txt".to_owned(), blocklist: None, verbose: false }),
+            parse_wordlist_args(&opts, &args(&["analyze", "words.txt"]))
+        );
+        assert_eq!(
+            OptParseResult::AnalyzeWordlist(WordlistArgs {
+                path: "words.txt".to_owned(),
+                blocklist: Some("bad.txt".to_owned()),
+                verbose: true,
+            }),
+            parse_wordlist_args(&opts, &args(&["analyze", "-v", "words.txt", "--blocklist", "bad.txt"]))
+        );
+    }
+
+    #[test]
+    fn test_wordlist_invalid_arguments() {
+        let cases: [(&[&str], &str); 4] = [
+            (&[], "only wordlist command"),
+            (&["check", "words.txt"], "only wordlist command"),
+            (&["analyze"], "one path"),
+            (&["analyze", "a.txt", "b.txt"], "one path"),
+        ];
+        for &(bad_args, message) in cases.iter() {
+            let opts = prepare_wordlist_opts();
+            if let OptParseResult::Err(e) = parse_wordlist_args(&opts, &args(bad_args)) {
+                assert!(e.contains(message), "{:?} gave {:?}", bad_args, e);
+            } else {
+                panic!("Argument parsing doesn't fail on {:?}.", bad_args);
+            }
+        }
+    }
+
+    #[test]
+    fn test_batch() {
+        let opts = prepare_batch_opts();
+        assert_eq!(
+            OptParseResult::Batch(BatchArgs { path: "accounts.csv".to_owned(), format: ImportFormat::Bitwarden }),
+            parse_batch_args(&opts, &args(&["accounts.csv"]))
+        );
+        assert_eq!(
+            OptParseResult::Batch(BatchArgs { path: "-".to_owned(), format: ImportFormat::OnePassword }),
+            parse_batch_args(&opts, &args(&["--format", "1Password", "-"]))
+        );
+        assert_eq!(
+            OptParseResult::Batch(BatchArgs { path: "accounts.csv".to_owned(), format: ImportFormat::KeePass }),
+            parse_batch_args(&opts, &args(&["-f", "keepass", "accounts.csv"]))
+        );
+    }
+
+    #[test]
+    fn test_batch_invalid_arguments() {
+        let cases: [(&[&str], &str); 3] = [
+            (&[], "one path"),
+            (&["a.csv", "b.csv"], "one path"),
+            (&["--format", "lastpass", "a.csv"], "Unknown import format \"lastpass\""),
+        ];
+        for &(bad_args, message) in cases.iter() {
+            let opts = prepare_batch_opts();
+            if let OptParseResult::Err(e) = parse_batch_args(&opts, &args(bad_args)) {
+                assert!(e.contains(message), "{:?} gave {:?}", bad_args, e);
+            } else {
+                panic!("Argument parsing doesn't fail on {:?}.", bad_args);
+            }
+        }
+    }
+
+    #[test]
+    fn test_charset_names() {
+        for &(name, charset) in CHARSET_NAMES.iter() {
+            assert_eq!(CommandLineCharset::from_name(name), Some(charset));
+            assert_eq!(charset.name(), name);
+        }
+        assert_eq!(CommandLineCharset::from_name("klingon"), None);
+    }
+}
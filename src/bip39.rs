@@ -0,0 +1,209 @@
+//! BIP39 mnemonic phrases, e.g. for cryptocurrency wallet seeds, as specified in
+//! <https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki>.
+//!
+//! Unlike `random_password(charsets::BIP39_ENGLISH, ...)`, the phrases generated here end with the
+//! checksum bits that wallets require.
+//!
+//!     let mnemonic = passgenr::bip39::random_mnemonic(24).unwrap();
+//!     assert_eq!(mnemonic.split(' ').count(), 24);
+//!     assert!(passgenr::bip39::mnemonic_to_entropy(&mnemonic).is_ok());
+
+use std::error;
+use std::fmt;
+
+use super::rand_core::{OsRng, RngCore};
+use super::sha2::{Digest, Sha256};
+
+use super::charsets::{self, Charset};
+
+/// The number of words in each of the mnemonic lengths allowed by BIP39.
+pub const WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+const BITS_PER_WORD: usize = 11;
+
+/// The reason a mnemonic was rejected by `mnemonic_to_entropy`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum MnemonicError {
+    /// The mnemonic doesn't have one of the `WORD_COUNTS`.
+    WrongWordCount(usize),
+    /// The mnemonic contains a word that isn't in `charsets::BIP39_ENGLISH`.
+    UnknownWord(String),
+    /// The checksum bits at the end of the mnemonic are wrong.
+    BadChecksum,
+}
+
+impl fmt::Display for MnemonicError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MnemonicError::WrongWordCount(n) => write!(f, "a mnemonic can't have {} words", n),
+            MnemonicError::UnknownWord(ref word) => write!(f, "\"{}\" is not in the BIP39 wordlist", word),
+            MnemonicError::BadChecksum => write!(f, "the mnemonic's checksum is wrong"),
+        }
+    }
+}
+
+impl error::Error for MnemonicError {}
+
+/// Randomly generate a mnemonic of `word_count` words, separated by single spaces.
+///
+/// The call will panic if `word_count` is not one of `WORD_COUNTS`.
+pub fn random_mnemonic(word_count: usize) -> Result<String, std::io::Error> {
+    if !WORD_COUNTS.contains(&word_count) {
+        panic!("A BIP39 mnemonic must have 12, 15, 18, 21 or 24 words!");
+    }
+
+    // Every 3 words encode 32 bits of entropy and 1 bit of checksum.
+    let mut entropy = vec![0u8; word_count / 3 * 4];
+    OsRng.try_fill_bytes(&mut entropy)?;
+    Ok(entropy_to_mnemonic(&entropy))
+}
+
+/// Encode `entropy` as a mnemonic, separated by single spaces.
+///
+/// The call will panic if `entropy` is not 16, 20, 24, 28 or 32 bytes long.
+pub fn entropy_to_mnemonic(entropy: &[u8]) -> String {
+    if ![16, 20, 24, 28, 32].contains(&entropy.len()) {
+        panic!("BIP39 entropy must be 16, 20, 24, 28 or 32 bytes long!");
+    }
+
+    let checksum_bits = entropy.len() / 4;
+    let mut bits = entropy.to_vec();
+    bits.push(Sha256::digest(entropy)[0]);
+
+    let word_count = (entropy.len() * 8 + checksum_bits) / BITS_PER_WORD;
+    let words: Vec<&str> = (0..word_count)
+        .map(|i| charsets::BIP39_ENGLISH.element(read_bits(&bits, i * BITS_PER_WORD, BITS_PER_WORD)))
+        .collect();
+    words.join(" ")
+}
+
+/// Validate `mnemonic` and recover the entropy it encodes. Words may be separated by any
+/// whitespace.
+pub fn mnemonic_to_entropy(mnemonic: &str) -> Result<Vec<u8>, MnemonicError> {
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    if !WORD_COUNTS.contains(&words.len()) {
+        return Err(MnemonicError::WrongWordCount(words.len()));
+    }
+
+    let mut bits = vec![0u8; (words.len() * BITS_PER_WORD).div_ceil(8)];
+    for (i, word) in words.iter().enumerate() {
+        let index = match charsets::BIP39_ENGLISH.binary_search(word) {
+            Ok(index) => index,
+            Err(_) => return Err(MnemonicError::UnknownWord(word.to_string())),
+        };
+        write_bits(&mut bits, i * BITS_PER_WORD, BITS_PER_WORD, index);
+    }
+
+    let checksum_bits = words.len() / 3;
+    let entropy_length = checksum_bits * 4;
+    let checksum = read_bits(&bits, entropy_length * 8, checksum_bits);
+    bits.truncate(entropy_length);
+
+    let expected = usize::from(Sha256::digest(&bits)[0]) >> (8 - checksum_bits);
+    if checksum != expected {
+        return Err(MnemonicError::BadChecksum);
+    }
+
+    Ok(bits)
+}
+
+/// Read `count` bits, most significant first, starting `offset` bits into `bytes`.
+fn read_bits(bytes: &[u8], offset: usize, count: usize) -> usize {
+    (offset..offset + count).fold(0, |acc, bit| {
+        (acc << 1) | usize::from((bytes[bit / 8] >> (7 - bit % 8)) & 1)
+    })
+}
+
+/// Write the low `count` bits of `value`, most significant first, starting `offset` bits into
+/// `bytes`.
+fn write_bits(bytes: &mut [u8], offset: usize, count: usize, value: usize) {
+    for i in 0..count {
+        if (value >> (count - 1 - i)) & 1 == 1 {
+            let bit = offset + i;
+            bytes[bit / 8] |= 1 << (7 - bit % 8);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_VECTORS: &str = include_str!("../resources/bip39-test-vectors.txt");
+
+    fn test_vectors() -> Vec<(Vec<u8>, &'static str)> {
+        TEST_VECTORS.lines()
+            .filter(|line| !line.starts_with('#'))
+            .map(|line| {
+                let (entropy, mnemonic) = line.split_at(line.find(' ').unwrap());
+                let entropy = (0..entropy.len()).step_by(2)
+                    .map(|i| u8::from_str_radix(&entropy[i..i + 2], 16).unwrap())
+                    .collect();
+                (entropy, &mnemonic[1..])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn wordlist_is_canonical() {
+        assert_eq!(charsets::BIP39_ENGLISH.len(), 2048);
+        assert_eq!(charsets::BIP39_ENGLISH.get(0), Some("abandon"));
+        assert_eq!(charsets::BIP39_ENGLISH.get(2047), Some("zoo"));
+        // Lookups rely on the list being sorted.
+        assert!(charsets::BIP39_ENGLISH.to_vec().windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn official_test_vectors() {
+        let vectors = test_vectors();
+        assert_eq!(vectors.len(), 24);
+        for (entropy, mnemonic) in vectors {
+            assert_eq!(entropy_to_mnemonic(&entropy), mnemonic);
+            assert_eq!(mnemonic_to_entropy(mnemonic).unwrap(), entropy);
+        }
+    }
+
+    #[test]
+    fn random_mnemonics_are_valid() {
+        for word_count in WORD_COUNTS.iter() {
+            for _ in 0..20 {
+                let mnemonic = random_mnemonic(*word_count).unwrap();
+                assert_eq!(mnemonic.split(' ').count(), *word_count);
+                let entropy = mnemonic_to_entropy(&mnemonic).unwrap();
+                assert_eq!(entropy.len(), word_count / 3 * 4);
+                assert_eq!(entropy_to_mnemonic(&entropy), mnemonic);
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_mnemonics() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert_eq!(mnemonic_to_entropy(mnemonic), Err(MnemonicError::WrongWordCount(11)));
+        assert_eq!(mnemonic_to_entropy(""), Err(MnemonicError::WrongWordCount(0)));
+
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert_eq!(mnemonic_to_entropy(mnemonic), Err(MnemonicError::BadChecksum));
+
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abut";
+        assert_eq!(mnemonic_to_entropy(mnemonic), Err(MnemonicError::UnknownWord("abut".to_owned())));
+    }
+
+    #[test]
+    fn whitespace_is_tolerated() {
+        let mnemonic = "  abandon abandon abandon abandon abandon abandon\nabandon abandon abandon abandon\tabandon about ";
+        assert_eq!(mnemonic_to_entropy(mnemonic).unwrap(), vec![0u8; 16]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must have 12, 15, 18, 21 or 24 words")]
+    fn panics_on_invalid_word_count() {
+        let _ = random_mnemonic(13);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be 16, 20, 24, 28 or 32 bytes")]
+    fn panics_on_invalid_entropy_length() {
+        let _ = entropy_to_mnemonic(&[0u8; 15]);
+    }
+}
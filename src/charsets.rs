@@ -1,3 +1,176 @@
+//! The built-in character sets. Sets of characters are slices, while the wordlists are packed
+//! into a `Wordlist` by the build script, which saves a pointer and length per word in the binary.
+//! Each file in `resources/wordlists/` becomes a constant named after it: `WORDS` for passphrases,
+//! and `BIP39_ENGLISH`, the BIP39 English wordlist in its canonical order (see the `bip39` module
+//! for mnemonics with the correct checksum).
+//!
+//! The generation functions accept either kind of charset, or anything else that implements
+//! `Charset`:
+//!
+//!     use passgenr::charsets::{self, Charset};
+//!
+//!     assert_eq!(charsets::WORDS.get(0), Some("abacus"));
+//!     assert_eq!(charsets::ASCII.element(0), "!");
+//!     assert_eq!(passgenr::fixed_buffer::max_password_length(charsets::BIP39_ENGLISH, 12, " "), 107);
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A set of distinct elements for passwords to be made of.
+pub trait Charset<'a> {
+    /// The number of elements.
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The element at `index`, which must be less than `len()`.
+    fn element(&self, index: usize) -> &'a str;
+
+    /// Whether the elements are known to be distinct, so that the generation functions needn't
+    /// check for duplicates on every call. This is true of `Wordlist`s, which the build script
+    /// checks.
+    fn is_known_distinct(&self) -> bool {
+        false
+    }
+
+    /// All the elements, in order.
+    #[cfg(feature = "alloc")]
+    fn to_vec(&self) -> Vec<&'a str> {
+        (0..self.len()).map(|i| self.element(i)).collect()
+    }
+}
+
+impl<'a> Charset<'a> for [&'a str] {
+    fn len(&self) -> usize {
+        <[&str]>::len(self)
+    }
+
+    fn element(&self, index: usize) -> &'a str {
+        self[index]
+    }
+}
+
+impl<'a, const N: usize> Charset<'a> for [&'a str; N] {
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn element(&self, index: usize) -> &'a str {
+        self[index]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Charset<'a> for Vec<&'a str> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn element(&self, index: usize) -> &'a str {
+        self[index]
+    }
+}
+
+impl<'a, C: Charset<'a> + ?Sized> Charset<'a> for &C {
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn element(&self, index: usize) -> &'a str {
+        (**self).element(index)
+    }
+
+    fn is_known_distinct(&self) -> bool {
+        (**self).is_known_distinct()
+    }
+}
+
+/// A list of words stored as one string and a table of where each word starts, which takes four
+/// bytes per word rather than the sixteen of a `&str` (plus a relocation for its pointer).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Wordlist {
+    text: &'static str,
+    /// The little-endian `u32` offset of each word in `text`, followed by `text.len()`.
+    offsets: &'static [u8],
+}
+
+impl Wordlist {
+    pub(crate) const fn from_packed(text: &'static str, offsets: &'static [u8]) -> Wordlist {
+        Wordlist { text, offsets }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.offsets.len() / 4 - 1
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The word at `index`, or `None` if it's out of range.
+    pub fn get(&self, index: usize) -> Option<&'static str> {
+        if index < self.len() {
+            Some(&self.text[self.offset(index)..self.offset(index + 1)])
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'static str> {
+        let wordlist = *self;
+        (0..self.len()).map(move |i| wordlist.element(i))
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.iter().any(|w| w == word)
+    }
+
+    /// Like `slice::binary_search`, for wordlists in sorted order.
+    pub fn binary_search(&self, word: &str) -> Result<usize, usize> {
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match self.element(middle).cmp(word) {
+                core::cmp::Ordering::Less => low = middle + 1,
+                core::cmp::Ordering::Greater => high = middle,
+                core::cmp::Ordering::Equal => return Ok(middle),
+            }
+        }
+        Err(low)
+    }
+
+    fn offset(&self, index: usize) -> usize {
+        let bytes = &self.offsets[4 * index..4 * index + 4];
+        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize
+    }
+}
+
+impl Charset<'static> for Wordlist {
+    fn len(&self) -> usize {
+        Wordlist::len(self)
+    }
+
+    fn element(&self, index: usize) -> &'static str {
+        match self.get(index) {
+            Some(word) => word,
+            None => panic!("The index {} is out of range for a wordlist of {} words!", index, self.len()),
+        }
+    }
+
+    fn is_known_distinct(&self) -> bool {
+        true
+    }
+}
+
+impl fmt::Debug for Wordlist {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
 pub const UPPERCASE_HEX: &[&str] = &[
     "0","1","2","3","4","5","6","7","8","9","A","B","C","D","E","F"
 ];
@@ -30,7241 +203,128 @@ pub const LOWERCASE_ALPHABETIC: &[&str] = &[
     "a","b","c","d","e","f","g","h","i","j","k","l","m","n","o","p","q","r","s","t","u","v","w","x","y","z"
 ];
 
-pub const WORDS: &[&str] = &[
-"abacus",
-"abandon",
-"abbey",
-"abbot",
-"abdomen",
-"abduct",
-"abet",
-"abhor",
-"abolish",
-"abort",
-"abound",
-"abrupt",
-"abscess",
-"abscond",
-"absent",
-"absorb",
-"abstain",
-"abstract",
-"absurd",
-"abyss",
-"accent",
-"accept",
-"access",
-"accident",
-"acclaim",
-"accompanist",
-"accomplish",
-"accord",
-"accordion",
-"accost",
-"account",
-"accredit",
-"accustom",
-"ace",
-"acid",
-"acorn",
-"acquaint",
-"acquit",
-"acrid",
-"acrobat",
-"acronym",
-"act",
-"action",
-"activist",
-"actor",
-"actress",
-"actual",
-"acumen",
-"ad",
-"adapt",
-"add",
-"addict",
-"address",
-"adept",
-"adjoin",
-"adjourn",
-"adjunct",
-"adjust",
-"admit",
-"admonish",
-"ado",
-"adopt",
-"adorn",
-"adroit",
-"adult",
-"adulthood",
-"adverb",
-"advert",
-"advisor",
-"aeon",
-"aerial",
-"aerosol",
-"affair",
-"affect",
-"affection",
-"affidavit",
-"affirm",
-"affix",
-"afflict",
-"afford",
-"affront",
-"african",
-"aftereffect",
-"aftermath",
-"afternoon",
-"afterthought",
-"afterward",
-"age",
-"agenda",
-"agent",
-"aggressor",
-"agnostic",
-"agreement",
-"aid",
-"aids",
-"ail",
-"ailment",
-"aim",
-"aimless",
-"air",
-"aircraft",
-"airfield",
-"airmail",
-"airport",
-"airstrip",
-"alarm",
-"alarmist",
-"albino",
-"album",
-"alcohol",
-"ale",
-"alert",
-"alga",
-"algebra",
-"algorithm",
-"alias",
-"alibi",
-"alien",
-"alight",
-"align",
-"alkali",
-"all",
-"allah",
-"allay",
-"alley",
-"allot",
-"allow",
-"alloy",
-"almanac",
-"almond",
-"alphabet",
-"altar",
-"alter",
-"alto",
-"altruism",
-"aluminum",
-"amass",
-"amateur",
-"ambassador",
-"amber",
-"ambush",
-"ameba",
-"ameer",
-"amen",
-"amend",
-"american",
-"amethyst",
-"amir",
-"ammonia",
-"amnesia",
-"amoeba",
-"amount",
-"amp",
-"ampersand",
-"amphibian",
-"amulet",
-"anaemia",
-"anaesthesia",
-"analog",
-"analyst",
-"anarchist",
-"ancestor",
-"anchor",
-"ancient",
-"android",
-"anemia",
-"anesthesia",
-"angel",
-"anger",
-"angler",
-"anguish",
-"ani",
-"annex",
-"annoy",
-"annual",
-"annul",
-"anoint",
-"anorak",
-"answer",
-"ant",
-"antagonist",
-"antenna",
-"anthem",
-"anthropologist",
-"antic",
-"anticlimax",
-"antler",
-"antonym",
-"anus",
-"anvil",
-"anxious",
-"aorta",
-"apart",
-"apartheid",
-"ape",
-"apex",
-"aplomb",
-"appal",
-"apparatus",
-"apparel",
-"appeal",
-"appear",
-"append",
-"appendix",
-"applaud",
-"appoint",
-"apprehend",
-"apprenticeship",
-"approach",
-"apricot",
-"april",
-"apron",
-"apt",
-"aquarium",
-"aqueduct",
-"arbor",
-"arc",
-"arch",
-"archaeologist",
-"archbishop",
-"archeologist",
-"archer",
-"archipelago",
-"architect",
-"archway",
-"ardent",
-"ardor",
-"arduous",
-"are",
-"area",
-"arena",
-"argument",
-"aria",
-"aristocrat",
-"ark",
-"arm",
-"armadillo",
-"armament",
-"armchair",
-"armor",
-"armpit",
-"aroma",
-"arraign",
-"array",
-"arrest",
-"arrow",
-"arsenal",
-"arsenic",
-"arson",
-"art",
-"artefact",
-"artifact",
-"artisan",
-"artist",
-"ascend",
-"ascent",
-"ascertain",
-"ash",
-"ashtray",
-"asian",
-"ask",
-"asparagus",
-"aspect",
-"aspen",
-"asphalt",
-"aspirin",
-"ass",
-"assail",
-"assassin",
-"assault",
-"assent",
-"assert",
-"assess",
-"assessor",
-"asset",
-"assign",
-"assist",
-"assort",
-"asterisk",
-"asteroid",
-"asthma",
-"astonish",
-"astound",
-"astronaut",
-"asylum",
-"atheism",
-"atheist",
-"atlas",
-"atom",
-"attach",
-"attack",
-"attain",
-"attempt",
-"attend",
-"attest",
-"attic",
-"attorney",
-"attract",
-"auburn",
-"auction",
-"audio",
-"audit",
-"auditor",
-"auditorium",
-"augment",
-"august",
-"aunt",
-"aura",
-"auto",
-"autocrat",
-"autograph",
-"autumn",
-"avail",
-"avert",
-"avocado",
-"avoid",
-"avow",
-"await",
-"awaken",
-"award",
-"awe",
-"awful",
-"awkward",
-"ax",
-"axe",
-"axiom",
-"ay",
-"aye",
-"azalea",
-"babe",
-"baboon",
-"bachelor",
-"back",
-"backer",
-"backgammon",
-"background",
-"backhand",
-"backlash",
-"backlog",
-"backpack",
-"backtrack",
-"backward",
-"bacon",
-"bacteria",
-"bacterium",
-"bad",
-"badger",
-"badminton",
-"bag",
-"bagel",
-"bail",
-"bait",
-"bake",
-"baker",
-"bald",
-"bale",
-"balk",
-"ball",
-"ballad",
-"ballast",
-"ballerina",
-"ballet",
-"balloon",
-"ballot",
-"ballroom",
-"balm",
-"baloney",
-"bamboo",
-"ban",
-"banana",
-"band",
-"bandana",
-"bandanna",
-"bandit",
-"bandstand",
-"bandwagon",
-"bang",
-"banish",
-"banjo",
-"bank",
-"banker",
-"bankrupt",
-"banner",
-"banquet",
-"banter",
-"baptism",
-"bar",
-"barb",
-"barbarian",
-"barber",
-"bard",
-"bare",
-"bargain",
-"bark",
-"barley",
-"barn",
-"barnyard",
-"baron",
-"barrel",
-"barren",
-"barrier",
-"barter",
-"base",
-"basement",
-"bash",
-"basic",
-"basil",
-"basin",
-"bask",
-"basket",
-"bass",
-"bassoon",
-"bastard",
-"bat",
-"batch",
-"bath",
-"bathroom",
-"bathtub",
-"baton",
-"battalion",
-"batter",
-"battlefield",
-"battleship",
-"baud",
-"bawl",
-"bay",
-"bayonet",
-"bayou",
-"bazaar",
-"be",
-"beach",
-"beacon",
-"bead",
-"beak",
-"beaker",
-"beam",
-"bean",
-"bear",
-"beard",
-"bearer",
-"beast",
-"beat",
-"beater",
-"beautician",
-"beaver",
-"beckon",
-"bed",
-"bedbug",
-"bedlam",
-"bedrock",
-"bedroom",
-"bedspread",
-"bee",
-"beech",
-"beef",
-"beer",
-"beeswax",
-"beet",
-"befit",
-"befriend",
-"beg",
-"beggar",
-"begin",
-"behalf",
-"behavior",
-"behead",
-"behind",
-"behold",
-"belabor",
-"belch",
-"belief",
-"bell",
-"bellboy",
-"bellhop",
-"bellow",
-"belong",
-"belt",
-"bemoan",
-"bench",
-"bend",
-"benefactor",
-"benefit",
-"bent",
-"bequeath",
-"bequest",
-"beret",
-"berth",
-"beseech",
-"beset",
-"best",
-"bestial",
-"bestow",
-"bet",
-"betray",
-"better",
-"bettor",
-"bewilder",
-"bewitch",
-"bias",
-"bib",
-"bicker",
-"bid",
-"bide",
-"biennial",
-"bigamist",
-"bigot",
-"bike",
-"bikini",
-"bile",
-"bill",
-"billboard",
-"billfold",
-"billion",
-"billow",
-"bin",
-"bind",
-"binder",
-"bingo",
-"biologist",
-"birch",
-"bird",
-"birth",
-"birthday",
-"birthmark",
-"biscuit",
-"bisect",
-"bishop",
-"bison",
-"bit",
-"bitch",
-"bite",
-"bitter",
-"bittersweet",
-"blab",
-"black",
-"blackbird",
-"blackboard",
-"blacken",
-"blackhead",
-"blackjack",
-"blacklist",
-"blackmail",
-"blackout",
-"blacksmith",
-"blacktop",
-"bladder",
-"blade",
-"blame",
-"blanch",
-"blank",
-"blanket",
-"blare",
-"blast",
-"blatant",
-"blaze",
-"blazer",
-"bleach",
-"bleat",
-"bleed",
-"blemish",
-"blend",
-"bless",
-"blight",
-"blimp",
-"blind",
-"blindfold",
-"blink",
-"blinker",
-"blip",
-"bliss",
-"blister",
-"blitz",
-"blizzard",
-"blob",
-"bloc",
-"block",
-"blockhead",
-"blog",
-"blogger",
-"blond",
-"blood",
-"bloodhound",
-"bloom",
-"blossom",
-"blot",
-"blotch",
-"blotter",
-"blow",
-"blowout",
-"blowtorch",
-"blubber",
-"bludgeon",
-"blue",
-"bluebird",
-"bluegrass",
-"blueprint",
-"bluff",
-"blunder",
-"blunt",
-"blur",
-"blurt",
-"blush",
-"bluster",
-"boa",
-"boar",
-"board",
-"boarder",
-"boardwalk",
-"boast",
-"boat",
-"bob",
-"bobbin",
-"bobcat",
-"bobsled",
-"bode",
-"bodyguard",
-"bog",
-"boil",
-"boiler",
-"bold",
-"bologna",
-"boloney",
-"bolster",
-"bolt",
-"bomb",
-"bombard",
-"bomber",
-"bond",
-"bone",
-"boney",
-"bonnet",
-"bonus",
-"boo",
-"book",
-"bookend",
-"booklet",
-"bookmark",
-"bookshop",
-"bookworm",
-"boom",
-"boomerang",
-"boon",
-"boor",
-"boost",
-"booster",
-"boot",
-"booth",
-"bootleg",
-"border",
-"bore",
-"boredom",
-"born",
-"borough",
-"borrow",
-"bosom",
-"boss",
-"botanist",
-"botch",
-"bother",
-"bottleneck",
-"bottom",
-"bough",
-"boulder",
-"boulevard",
-"bound",
-"bouquet",
-"bourbon",
-"bout",
-"bow",
-"bowel",
-"bowl",
-"bowlder",
-"box",
-"boxcar",
-"boxer",
-"boy",
-"boycott",
-"boyfriend",
-"boyhood",
-"bra",
-"brace",
-"bracelet",
-"bracket",
-"brag",
-"braggart",
-"braid",
-"brain",
-"brainstorm",
-"brainwash",
-"brake",
-"bran",
-"branch",
-"brand",
-"brandish",
-"brass",
-"brassier",
-"brat",
-"bravado",
-"brave",
-"bravo",
-"brawl",
-"brawn",
-"bray",
-"brazen",
-"brazier",
-"breach",
-"bread",
-"breadth",
-"break",
-"breakdown",
-"breakfast",
-"breakthrough",
-"breast",
-"breath",
-"breather",
-"breed",
-"breeder",
-"brew",
-"bribe",
-"brick",
-"bridal",
-"bride",
-"bridegroom",
-"bridesmaid",
-"brief",
-"bright",
-"brighten",
-"brilliant",
-"brim",
-"brine",
-"bring",
-"brink",
-"brisk",
-"british",
-"broach",
-"broad",
-"broadcast",
-"broaden",
-"broccoli",
-"broil",
-"broiler",
-"broker",
-"broncho",
-"bronco",
-"brooch",
-"brood",
-"brook",
-"broom",
-"broth",
-"brother",
-"brotherhood",
-"brow",
-"browbeat",
-"brown",
-"brows",
-"brunch",
-"brunt",
-"brush",
-"brutal",
-"brute",
-"buck",
-"bucket",
-"bud",
-"budget",
-"buff",
-"buffalo",
-"buffer",
-"buffet",
-"buffoon",
-"bug",
-"bugger",
-"bugler",
-"build",
-"builder",
-"bulb",
-"bulk",
-"bull",
-"bulldog",
-"bullet",
-"bulletin",
-"bullfight",
-"bullfrog",
-"bullion",
-"bum",
-"bump",
-"bumper",
-"bun",
-"bunch",
-"bungalow",
-"bungler",
-"bunion",
-"bunk",
-"bunker",
-"burden",
-"bureau",
-"bureaucrat",
-"burger",
-"burglar",
-"burial",
-"burlap",
-"burn",
-"burner",
-"burnish",
-"burp",
-"burr",
-"burro",
-"burrow",
-"burst",
-"bus",
-"bush",
-"bushel",
-"businessman",
-"businesswoman",
-"buss",
-"bust",
-"but",
-"butcher",
-"butler",
-"butt",
-"butter",
-"buttercup",
-"buttermilk",
-"butterscotch",
-"buttock",
-"button",
-"buttress",
-"buy",
-"buyer",
-"buzz",
-"buzzard",
-"buzzer",
-"by",
-"bye",
-"bypass",
-"byte",
-"byway",
-"cab",
-"cabaret",
-"cabin",
-"cabinet",
-"cacao",
-"cactus",
-"cadet",
-"cafeteria",
-"cage",
-"cake",
-"calcium",
-"calculus",
-"calendar",
-"calf",
-"calico",
-"calk",
-"call",
-"caller",
-"callous",
-"callus",
-"calm",
-"camel",
-"cameo",
-"camera",
-"camp",
-"campaign",
-"camper",
-"campus",
-"can",
-"canal",
-"cancel",
-"cancer",
-"candid",
-"candlestick",
-"candor",
-"cane",
-"canker",
-"cannon",
-"canon",
-"cantaloup",
-"canteen",
-"canter",
-"canvas",
-"canvass",
-"canyon",
-"cap",
-"capacitor",
-"cape",
-"caper",
-"capitalist",
-"captain",
-"caption",
-"captor",
-"car",
-"caramel",
-"carat",
-"caravan",
-"carbon",
-"carburetor",
-"carcass",
-"card",
-"cardboard",
-"cardigan",
-"care",
-"career",
-"careful",
-"careless",
-"caress",
-"cargo",
-"caribou",
-"carol",
-"carp",
-"carpet",
-"carrier",
-"carrion",
-"carrot",
-"cart",
-"cartel",
-"carton",
-"cartoon",
-"cartoonist",
-"cartwheel",
-"case",
-"cash",
-"cashew",
-"cashier",
-"casino",
-"cask",
-"casket",
-"cassino",
-"cast",
-"castaway",
-"caster",
-"castoff",
-"casual",
-"cat",
-"cataclysm",
-"catalog",
-"catapult",
-"cataract",
-"catch",
-"catchup",
-"cater",
-"caterpillar",
-"catfish",
-"catholic",
-"catnap",
-"catnip",
-"catsup",
-"catwalk",
-"caucus",
-"caulk",
-"causal",
-"causeway",
-"caustic",
-"caution",
-"cautious",
-"cave",
-"caveat",
-"cavern",
-"caviar",
-"cavort",
-"caw",
-"ceaseless",
-"cedar",
-"cede",
-"cell",
-"cellar",
-"cellist",
-"cello",
-"cellular",
-"cement",
-"censor",
-"censorship",
-"census",
-"cent",
-"center",
-"central",
-"cereal",
-"certain",
-"chafe",
-"chaff",
-"chagrin",
-"chain",
-"chair",
-"chairman",
-"chairperson",
-"chalet",
-"chalk",
-"chamber",
-"chameleon",
-"champ",
-"champion",
-"championship",
-"chancellor",
-"channel",
-"chant",
-"chap",
-"chapel",
-"chaperon",
-"chaplain",
-"chapter",
-"char",
-"character",
-"charcoal",
-"chariot",
-"charisma",
-"charlatan",
-"charm",
-"chart",
-"charter",
-"chase",
-"chasm",
-"chasten",
-"chat",
-"chatter",
-"chatterbox",
-"chauffeur",
-"chauvinist",
-"cheap",
-"cheapen",
-"cheat",
-"check",
-"checker",
-"checkup",
-"cheek",
-"cheep",
-"cheer",
-"cheerful",
-"cheesecloth",
-"cheetah",
-"chef",
-"chemist",
-"cherish",
-"cherub",
-"cherubim",
-"chess",
-"chest",
-"chestnut",
-"chew",
-"chic",
-"chick",
-"chicken",
-"chide",
-"chief",
-"chieftain",
-"child",
-"childbirth",
-"childhood",
-"chile",
-"chili",
-"chill",
-"chilli",
-"chime",
-"chimney",
-"chimp",
-"chin",
-"china",
-"chink",
-"chintz",
-"chip",
-"chipmunk",
-"chipper",
-"chiropractor",
-"chirp",
-"chisel",
-"chloroform",
-"choir",
-"choke",
-"cholera",
-"chop",
-"chopper",
-"choral",
-"chord",
-"chore",
-"chorus",
-"chow",
-"chowder",
-"christen",
-"christian",
-"christmas",
-"chrome",
-"chromium",
-"chronic",
-"chrysanthemum",
-"chuck",
-"chug",
-"chum",
-"chunk",
-"church",
-"churn",
-"chute",
-"cider",
-"cigar",
-"cigaret",
-"cinch",
-"cinder",
-"cinema",
-"cinnamon",
-"cipher",
-"circuit",
-"circular",
-"circumvent",
-"circus",
-"cistern",
-"cite",
-"citizen",
-"citizenship",
-"citrus",
-"civic",
-"civil",
-"civilian",
-"clack",
-"claim",
-"clam",
-"clamber",
-"clamor",
-"clamp",
-"clan",
-"clang",
-"clank",
-"clap",
-"clapper",
-"clarinet",
-"clash",
-"clasp",
-"class",
-"classic",
-"classroom",
-"clatter",
-"claustrophobia",
-"claw",
-"clay",
-"clean",
-"cleaner",
-"cleans",
-"cleanser",
-"clear",
-"cleat",
-"cleaver",
-"clef",
-"cleft",
-"clench",
-"clergyman",
-"cleric",
-"clerk",
-"clever",
-"click",
-"client",
-"cliff",
-"climax",
-"climb",
-"climber",
-"clime",
-"clinch",
-"cling",
-"clinic",
-"clink",
-"clip",
-"clipboard",
-"cloak",
-"clock",
-"clockwork",
-"clod",
-"clog",
-"cloister",
-"clone",
-"close",
-"closet",
-"clot",
-"cloth",
-"clothespin",
-"cloud",
-"cloudburst",
-"clout",
-"clove",
-"clover",
-"clown",
-"club",
-"cluck",
-"clue",
-"clump",
-"cluster",
-"clutch",
-"clutter",
-"coach",
-"coal",
-"coarsen",
-"coast",
-"coaster",
-"coat",
-"coax",
-"cob",
-"cobalt",
-"cobbler",
-"cobra",
-"cobweb",
-"cock",
-"cockpit",
-"cockroach",
-"cocktail",
-"cocoa",
-"cocoanut",
-"coconut",
-"cocoon",
-"cod",
-"code",
-"coercion",
-"coexist",
-"coffer",
-"coffin",
-"cog",
-"cognac",
-"coil",
-"coin",
-"coke",
-"cold",
-"colic",
-"collar",
-"collect",
-"collector",
-"colon",
-"colonel",
-"color",
-"colt",
-"column",
-"coma",
-"comb",
-"combat",
-"come",
-"comedian",
-"comet",
-"comfort",
-"comic",
-"comma",
-"command",
-"commando",
-"commend",
-"comment",
-"commission",
-"commit",
-"common",
-"commonwealth",
-"commune",
-"communion",
-"communism",
-"communist",
-"compact",
-"companion",
-"companionship",
-"comparison",
-"compass",
-"compassion",
-"compatriot",
-"compel",
-"competitor",
-"complain",
-"complaint",
-"complement",
-"complex",
-"complexion",
-"compliment",
-"compost",
-"compound",
-"comprehend",
-"compress",
-"computer",
-"comradeship",
-"con",
-"conceal",
-"conceit",
-"concept",
-"concern",
-"concert",
-"concerto",
-"concoct",
-"concord",
-"concur",
-"condemn",
-"condescend",
-"condiment",
-"condom",
-"condominium",
-"condor",
-"conduct",
-"conductor",
-"cone",
-"confer",
-"confess",
-"confetti",
-"confirm",
-"conflict",
-"conform",
-"confound",
-"confront",
-"congeal",
-"congest",
-"congress",
-"congressman",
-"congresswoman",
-"conifer",
-"connect",
-"connector",
-"connoisseur",
-"conquer",
-"conqueror",
-"conquest",
-"conscious",
-"consensus",
-"consent",
-"consider",
-"consign",
-"consist",
-"consort",
-"constant",
-"constrain",
-"constraint",
-"constrict",
-"construct",
-"consul",
-"consult",
-"consumer",
-"contact",
-"contagion",
-"contain",
-"contempt",
-"contend",
-"content",
-"contest",
-"context",
-"continent",
-"contort",
-"contour",
-"contraband",
-"contract",
-"contractor",
-"contradict",
-"contrast",
-"contributor",
-"control",
-"convent",
-"convert",
-"convertor",
-"convey",
-"convict",
-"convoy",
-"coo",
-"cook",
-"cookbook",
-"cool",
-"cooler",
-"coop",
-"cooper",
-"cop",
-"cope",
-"copier",
-"copious",
-"copper",
-"copperhead",
-"coral",
-"cord",
-"cordial",
-"cordon",
-"corduroy",
-"core",
-"cork",
-"corkscrew",
-"corn",
-"cornea",
-"corner",
-"cornet",
-"cornmeal",
-"cornstarch",
-"corps",
-"corral",
-"correct",
-"correspond",
-"corridor",
-"corrupt",
-"corset",
-"cosmonaut",
-"cosmopolitan",
-"cosmos",
-"cost",
-"cot",
-"cotton",
-"cottontail",
-"cottonwood",
-"couch",
-"cougar",
-"cough",
-"council",
-"councillor",
-"councilor",
-"counsel",
-"counsellor",
-"counselor",
-"count",
-"countdown",
-"counter",
-"counteract",
-"counterattack",
-"counterfeit",
-"counterpart",
-"countersign",
-"countess",
-"countryman",
-"coup",
-"coupon",
-"courier",
-"court",
-"courteous",
-"courtroom",
-"courtship",
-"courtyard",
-"cousin",
-"cove",
-"cover",
-"covert",
-"covet",
-"cow",
-"coward",
-"cowboy",
-"cower",
-"cowgirl",
-"crab",
-"crack",
-"cracker",
-"crackpot",
-"craft",
-"craftsman",
-"crag",
-"cram",
-"cramp",
-"crane",
-"cranium",
-"crank",
-"crash",
-"crate",
-"crater",
-"crave",
-"crawfish",
-"crawl",
-"crayfish",
-"crayon",
-"craze",
-"creak",
-"cream",
-"creation",
-"creator",
-"credit",
-"creditor",
-"creed",
-"creek",
-"creep",
-"crepe",
-"crescendo",
-"crescent",
-"crest",
-"cretin",
-"crew",
-"crib",
-"cricket",
-"crime",
-"crimson",
-"crisp",
-"crisscross",
-"criterion",
-"critic",
-"croak",
-"crochet",
-"crock",
-"crocus",
-"crook",
-"crooked",
-"croon",
-"crop",
-"croquet",
-"cross",
-"crossbow",
-"crossroad",
-"crosswalk",
-"crossword",
-"crotch",
-"crouch",
-"crow",
-"crowbar",
-"crowd",
-"crown",
-"crucial",
-"crucifix",
-"crucifixion",
-"crude",
-"cruiser",
-"crumb",
-"crunch",
-"crush",
-"crust",
-"crustacean",
-"crutch",
-"crux",
-"crypt",
-"crystal",
-"cub",
-"cube",
-"cuckoo",
-"cue",
-"cuff",
-"cull",
-"culprit",
-"cult",
-"cunning",
-"cup",
-"cupboard",
-"cups",
-"cur",
-"curb",
-"curd",
-"cure",
-"curfew",
-"curio",
-"curios",
-"curious",
-"curl",
-"currant",
-"current",
-"curriculum",
-"curtail",
-"curtain",
-"curtsey",
-"cushion",
-"custard",
-"custodian",
-"custom",
-"cut",
-"cutback",
-"cutlet",
-"cutter",
-"cutthroat",
-"cyclist",
-"cymbal",
-"cynic",
-"cypher",
-"cypress",
-"cyst",
-"czar",
-"dab",
-"dachshund",
-"dad",
-"daffodil",
-"dagger",
-"dais",
-"dam",
-"dame",
-"damn",
-"damp",
-"dampen",
-"damsel",
-"dancer",
-"dandelion",
-"dandruff",
-"danger",
-"dapper",
-"dare",
-"daredevil",
-"dark",
-"darken",
-"darn",
-"dart",
-"dash",
-"dashboard",
-"date",
-"datum",
-"daub",
-"daughter",
-"daunt",
-"dawn",
-"day",
-"daybreak",
-"daydream",
-"daylight",
-"daze",
-"deacon",
-"dead",
-"deaden",
-"deadlock",
-"deaf",
-"deal",
-"dealer",
-"dean",
-"dear",
-"dearth",
-"death",
-"debit",
-"debrief",
-"debt",
-"debtor",
-"debug",
-"debunk",
-"debut",
-"decay",
-"deceit",
-"decent",
-"decibel",
-"deck",
-"decorum",
-"decoy",
-"deduct",
-"deed",
-"deem",
-"deep",
-"deepen",
-"deer",
-"default",
-"defeat",
-"defect",
-"defend",
-"defer",
-"defiant",
-"deficit",
-"deflect",
-"deform",
-"defraud",
-"defrost",
-"deft",
-"deign",
-"deject",
-"delay",
-"deli",
-"delicatessen",
-"delight",
-"delimit",
-"delirium",
-"deliver",
-"delta",
-"demagog",
-"demand",
-"demean",
-"demeanor",
-"demerit",
-"democrat",
-"demolish",
-"demon",
-"den",
-"denial",
-"denim",
-"dens",
-"dent",
-"dentist",
-"depart",
-"department",
-"depend",
-"depict",
-"deport",
-"deposit",
-"depot",
-"depress",
-"depth",
-"derail",
-"derelict",
-"derrick",
-"descend",
-"descent",
-"descriptor",
-"desert",
-"design",
-"desist",
-"desk",
-"despair",
-"despatch",
-"despot",
-"dessert",
-"destroy",
-"detach",
-"detail",
-"detain",
-"detect",
-"detector",
-"deter",
-"detest",
-"detour",
-"detract",
-"detriment",
-"develop",
-"devil",
-"devote",
-"devour",
-"devout",
-"dew",
-"diagram",
-"dial",
-"dialect",
-"dialog",
-"diamond",
-"diaper",
-"diaphragm",
-"dice",
-"dictatorship",
-"diction",
-"die",
-"diesel",
-"diet",
-"differ",
-"dig",
-"digest",
-"digit",
-"digress",
-"dike",
-"dilemma",
-"dill",
-"dim",
-"dime",
-"dimension",
-"diminish",
-"din",
-"dine",
-"diner",
-"dinner",
-"dinosaur",
-"dip",
-"diphtheria",
-"diphthong",
-"diploma",
-"diplomat",
-"direct",
-"director",
-"dirt",
-"disallow",
-"disappear",
-"disappoint",
-"disarm",
-"disarray",
-"disavow",
-"disband",
-"disbelief",
-"disc",
-"discard",
-"discern",
-"disciplinarian",
-"disclaim",
-"disco",
-"discomfort",
-"disconcert",
-"disconnect",
-"discord",
-"discount",
-"discredit",
-"discreet",
-"discus",
-"discuss",
-"disdain",
-"disembark",
-"disfavor",
-"disgust",
-"dish",
-"dishearten",
-"dishonest",
-"dishonor",
-"disillusion",
-"disinfect",
-"disinherit",
-"disjoint",
-"disk",
-"dismal",
-"dismay",
-"dismiss",
-"dismount",
-"disobey",
-"disown",
-"dispatch",
-"dispel",
-"display",
-"dispossess",
-"disquiet",
-"disregard",
-"disrepair",
-"disrespect",
-"disrupt",
-"dissect",
-"dissent",
-"dissimilar",
-"distant",
-"distend",
-"distil",
-"distinct",
-"distinguish",
-"distort",
-"distract",
-"distress",
-"distributor",
-"district",
-"distrust",
-"disturb",
-"ditch",
-"dither",
-"ditto",
-"dive",
-"diver",
-"divers",
-"divert",
-"divest",
-"dividend",
-"divisor",
-"do",
-"dock",
-"doctor",
-"document",
-"doe",
-"doer",
-"dog",
-"dogma",
-"dogwood",
-"dole",
-"doll",
-"dollar",
-"dolphin",
-"domain",
-"dome",
-"dominion",
-"domino",
-"don",
-"donkey",
-"donor",
-"donut",
-"doom",
-"door",
-"doorman",
-"doorstep",
-"doorway",
-"dope",
-"dose",
-"dot",
-"dote",
-"doubt",
-"dough",
-"doughnut",
-"dove",
-"down",
-"downpour",
-"downtown",
-"downward",
-"doze",
-"dozen",
-"drab",
-"draft",
-"draftsman",
-"drag",
-"dragon",
-"drain",
-"drama",
-"dramatist",
-"drape",
-"drastic",
-"draw",
-"drawback",
-"drawer",
-"drawl",
-"dread",
-"dream",
-"dreamer",
-"drench",
-"dress",
-"dresser",
-"drier",
-"drift",
-"driftwood",
-"drill",
-"drink",
-"drinker",
-"drip",
-"drive",
-"drivel",
-"driver",
-"driveway",
-"drone",
-"drool",
-"droop",
-"drop",
-"dropout",
-"drought",
-"drouth",
-"drove",
-"drown",
-"drug",
-"druggist",
-"drum",
-"drummer",
-"drumstick",
-"drunk",
-"drunkard",
-"drunken",
-"dryer",
-"dryness",
-"dub",
-"dubious",
-"duchess",
-"duck",
-"duct",
-"dud",
-"dude",
-"due",
-"duel",
-"duet",
-"dugout",
-"duke",
-"dull",
-"dumbfound",
-"dumfound",
-"dump",
-"dune",
-"dungeon",
-"dunk",
-"dupe",
-"duplex",
-"duress",
-"dusk",
-"dust",
-"dustpan",
-"dutch",
-"dwarf",
-"dwell",
-"dweller",
-"dye",
-"dynamo",
-"eager",
-"ear",
-"eardrum",
-"earl",
-"earmark",
-"earn",
-"earner",
-"earnest",
-"earring",
-"earshot",
-"earth",
-"earthworm",
-"easel",
-"east",
-"easter",
-"eat",
-"eavesdrop",
-"ebb",
-"echo",
-"ecologist",
-"economist",
-"ecosystem",
-"eczema",
-"edict",
-"edit",
-"editor",
-"eel",
-"effect",
-"effort",
-"effortless",
-"egg",
-"eggplant",
-"ego",
-"egoism",
-"egotist",
-"eight",
-"eighteen",
-"eighteenth",
-"eighth",
-"eightieth",
-"eject",
-"eke",
-"elbow",
-"elder",
-"elect",
-"elector",
-"electrician",
-"electron",
-"element",
-"eleven",
-"eleventh",
-"elf",
-"elicit",
-"elk",
-"elm",
-"email",
-"embalm",
-"embargo",
-"embark",
-"embarrass",
-"embed",
-"embellish",
-"ember",
-"emblem",
-"emboss",
-"embryo",
-"emerald",
-"emir",
-"emit",
-"emperor",
-"emphysema",
-"employ",
-"employe",
-"emporium",
-"empress",
-"enact",
-"enamel",
-"enchant",
-"encompass",
-"encroach",
-"encyclopaedia",
-"encyclopedia",
-"end",
-"endear",
-"endeavor",
-"endless",
-"endow",
-"enema",
-"english",
-"engross",
-"engulf",
-"enigma",
-"enjoy",
-"enlighten",
-"enlist",
-"enliven",
-"enough",
-"enrich",
-"enrol",
-"ensign",
-"entail",
-"enter",
-"entertain",
-"enthral",
-"enthusiasm",
-"enthusiast",
-"entomologist",
-"entrant",
-"entrap",
-"entreat",
-"entrench",
-"entrust",
-"envelop",
-"envious",
-"environment",
-"envoy",
-"eon",
-"epaulet",
-"epic",
-"epidermis",
-"epilog",
-"epitaph",
-"epithet",
-"epoch",
-"equal",
-"equestrian",
-"equilibrium",
-"equinox",
-"equip",
-"era",
-"eras",
-"erect",
-"err",
-"errand",
-"error",
-"erupt",
-"escort",
-"eskimo",
-"esophagus",
-"essay",
-"establish",
-"esteem",
-"etch",
-"ether",
-"ethic",
-"ethnic",
-"european",
-"evangelist",
-"eve",
-"even",
-"event",
-"evergreen",
-"evict",
-"evil",
-"ewe",
-"exact",
-"exalt",
-"exam",
-"exceed",
-"excel",
-"except",
-"excerpt",
-"excess",
-"exclaim",
-"execution",
-"executor",
-"exempt",
-"exert",
-"exhaust",
-"exhibit",
-"exhort",
-"exist",
-"exit",
-"exodus",
-"expand",
-"expect",
-"expel",
-"expend",
-"experiment",
-"expert",
-"explain",
-"explicit",
-"exploit",
-"export",
-"expound",
-"express",
-"expressway",
-"extend",
-"extent",
-"exterior",
-"extinct",
-"extinguish",
-"extol",
-"extort",
-"extortion",
-"extra",
-"extract",
-"extravert",
-"extremist",
-"extrovert",
-"exult",
-"eye",
-"eyebrow",
-"eyelash",
-"eyelid",
-"eyesight",
-"fabric",
-"face",
-"facet",
-"facial",
-"fact",
-"faction",
-"factor",
-"factual",
-"fad",
-"fade",
-"faggot",
-"fagot",
-"fail",
-"faint",
-"fair",
-"faith",
-"fake",
-"falcon",
-"fall",
-"fallout",
-"falsehood",
-"falsetto",
-"falter",
-"fame",
-"familiar",
-"fan",
-"fang",
-"fare",
-"farm",
-"farmer",
-"fascism",
-"fascist",
-"fashion",
-"fast",
-"fasten",
-"fat",
-"fatal",
-"fate",
-"father",
-"fatherhood",
-"fatherland",
-"fathom",
-"fatten",
-"faucet",
-"fault",
-"fauna",
-"favor",
-"fawn",
-"faze",
-"fear",
-"fearless",
-"feast",
-"feat",
-"feather",
-"fed",
-"federalist",
-"fee",
-"feed",
-"feedback",
-"feeder",
-"feel",
-"feeler",
-"feign",
-"feint",
-"fell",
-"fellow",
-"fellowship",
-"felon",
-"felt",
-"feminist",
-"fen",
-"fend",
-"fender",
-"ferment",
-"fern",
-"ferret",
-"fervent",
-"fervor",
-"fester",
-"festoon",
-"fetch",
-"fetich",
-"fetish",
-"fetter",
-"fetus",
-"feud",
-"feudal",
-"fever",
-"feverish",
-"few",
-"fez",
-"fiasco",
-"fib",
-"fibber",
-"fiber",
-"fiberglass",
-"fiction",
-"fiddler",
-"fidget",
-"field",
-"fiend",
-"fiendish",
-"fiesta",
-"fifteen",
-"fifteenth",
-"fifth",
-"fiftieth",
-"fig",
-"fight",
-"fighter",
-"figment",
-"figurehead",
-"filament",
-"filch",
-"file",
-"filet",
-"fill",
-"fillet",
-"film",
-"filter",
-"filth",
-"fin",
-"final",
-"finalist",
-"finch",
-"find",
-"fine",
-"finger",
-"fingernail",
-"fingerprint",
-"fingertip",
-"finish",
-"fir",
-"fire",
-"firearm",
-"fireman",
-"fireproof",
-"firewood",
-"firework",
-"firm",
-"first",
-"fiscal",
-"fish",
-"fisherman",
-"fission",
-"fist",
-"fit",
-"five",
-"fix",
-"fizz",
-"flag",
-"flagrant",
-"flagship",
-"flail",
-"flair",
-"flake",
-"flame",
-"flamingo",
-"flank",
-"flannel",
-"flap",
-"flapjack",
-"flare",
-"flash",
-"flashback",
-"flashlight",
-"flask",
-"flat",
-"flatten",
-"flatter",
-"flaunt",
-"flavor",
-"flaw",
-"flawless",
-"flea",
-"fleck",
-"flee",
-"fleet",
-"flesh",
-"flex",
-"flick",
-"flicker",
-"flier",
-"flight",
-"flinch",
-"fling",
-"flint",
-"flip",
-"flipper",
-"flirt",
-"flit",
-"float",
-"flock",
-"flog",
-"flood",
-"floodlight",
-"floor",
-"flop",
-"flora",
-"florist",
-"floss",
-"flotilla",
-"flounder",
-"flour",
-"flourish",
-"flout",
-"flow",
-"flower",
-"flu",
-"flue",
-"fluff",
-"fluid",
-"fluke",
-"flunk",
-"flunkey",
-"flush",
-"fluster",
-"flute",
-"flutist",
-"flutter",
-"flux",
-"flyer",
-"flyover",
-"foal",
-"foam",
-"focus",
-"fodder",
-"foe",
-"foetus",
-"fog",
-"fogey",
-"foghorn",
-"foil",
-"foist",
-"fold",
-"folder",
-"folk",
-"follow",
-"foment",
-"fond",
-"font",
-"food",
-"foodstuff",
-"fool",
-"foolish",
-"foot",
-"foothold",
-"footpath",
-"footprint",
-"footstep",
-"footstool",
-"footwear",
-"footwork",
-"foray",
-"forbad",
-"forbear",
-"forbid",
-"ford",
-"fore",
-"forearm",
-"forecast",
-"forefront",
-"forego",
-"foreground",
-"forehead",
-"foreign",
-"foreleg",
-"foreman",
-"foreshadow",
-"foresight",
-"forest",
-"foreswear",
-"forethought",
-"forewarn",
-"foreword",
-"forfeit",
-"forger",
-"forget",
-"forgo",
-"fork",
-"form",
-"formal",
-"format",
-"former",
-"formula",
-"forswear",
-"fort",
-"fortieth",
-"fortnight",
-"fortress",
-"forum",
-"forward",
-"fossil",
-"foster",
-"foul",
-"found",
-"founder",
-"fount",
-"fountain",
-"four",
-"fourteen",
-"fourteenth",
-"fourth",
-"fowl",
-"fox",
-"foyer",
-"fracas",
-"fraction",
-"fragment",
-"frame",
-"framework",
-"franc",
-"frank",
-"frantic",
-"fraud",
-"fray",
-"freak",
-"free",
-"freedom",
-"freeway",
-"freezer",
-"freight",
-"freighter",
-"frequent",
-"fresh",
-"freshen",
-"freshman",
-"fret",
-"friar",
-"friction",
-"friday",
-"friend",
-"friendship",
-"fright",
-"frighten",
-"frigid",
-"frill",
-"frisk",
-"fritter",
-"frock",
-"frog",
-"frolic",
-"frond",
-"front",
-"frontier",
-"frost",
-"frostbit",
-"froth",
-"frown",
-"frugal",
-"fruit",
-"fruition",
-"fruitless",
-"fuel",
-"fulcrum",
-"fulfil",
-"full",
-"fume",
-"fun",
-"function",
-"fund",
-"fundamentalist",
-"fungus",
-"funnel",
-"fur",
-"furious",
-"furl",
-"furlong",
-"furlough",
-"furnish",
-"furor",
-"furrow",
-"further",
-"fuse",
-"fusion",
-"fuss",
-"fuze",
-"fuzz",
-"gab",
-"gadget",
-"gag",
-"gage",
-"gain",
-"gait",
-"gal",
-"gala",
-"gale",
-"gall",
-"gallant",
-"galley",
-"gallon",
-"gallop",
-"gallows",
-"gambit",
-"gambler",
-"game",
-"gamut",
-"gander",
-"gang",
-"gangplank",
-"gangster",
-"gangway",
-"gap",
-"gape",
-"garb",
-"garden",
-"gardenia",
-"garland",
-"garlic",
-"garment",
-"garnet",
-"garnish",
-"garret",
-"garrison",
-"garter",
-"gas",
-"gash",
-"gasket",
-"gasp",
-"gate",
-"gateway",
-"gather",
-"gauntlet",
-"gavel",
-"gawk",
-"gay",
-"gaze",
-"gear",
-"gee",
-"gees",
-"gelatin",
-"geld",
-"gem",
-"gender",
-"gene",
-"general",
-"generic",
-"generous",
-"genes",
-"geneticist",
-"genial",
-"genius",
-"gent",
-"gentleman",
-"genus",
-"geologist",
-"geranium",
-"gerbil",
-"germ",
-"get",
-"getaway",
-"geyser",
-"ghetto",
-"ghost",
-"ghoul",
-"giant",
-"gibber",
-"gibberish",
-"gibe",
-"gift",
-"gig",
-"gild",
-"gill",
-"gilt",
-"gimmick",
-"gin",
-"ginger",
-"gingerbread",
-"gingham",
-"girder",
-"girl",
-"girlfriend",
-"girlhood",
-"girth",
-"gist",
-"give",
-"given",
-"gizzard",
-"glacier",
-"glad",
-"gladden",
-"glade",
-"glamor",
-"glamour",
-"gland",
-"glare",
-"glass",
-"glaze",
-"gleam",
-"glean",
-"glee",
-"glen",
-"glide",
-"glider",
-"glimmer",
-"glint",
-"glisten",
-"glitter",
-"gloat",
-"global",
-"globe",
-"gloom",
-"glorious",
-"gloss",
-"glove",
-"glow",
-"glower",
-"glue",
-"glut",
-"glutton",
-"glycerin",
-"gnarl",
-"gnash",
-"gnat",
-"gnaw",
-"gnome",
-"gnu",
-"go",
-"goad",
-"goal",
-"goat",
-"gob",
-"goblet",
-"goblin",
-"god",
-"godchild",
-"goddess",
-"godsend",
-"gold",
-"golden",
-"goldfish",
-"goldsmith",
-"golf",
-"golfer",
-"gondola",
-"goner",
-"gong",
-"goo",
-"good",
-"goodby",
-"goof",
-"goon",
-"gopher",
-"gore",
-"gorilla",
-"gospel",
-"gossip",
-"goulash",
-"gourd",
-"gourmet",
-"gout",
-"govern",
-"government",
-"governor",
-"gown",
-"grab",
-"grace",
-"gracious",
-"grade",
-"gradient",
-"gradual",
-"graffito",
-"graft",
-"grain",
-"gram",
-"grammar",
-"grand",
-"grandchild",
-"grandeur",
-"grandson",
-"grandstand",
-"granola",
-"grant",
-"grape",
-"grapefruit",
-"graph",
-"graphic",
-"grasp",
-"grass",
-"grate",
-"grater",
-"grave",
-"gravel",
-"graveyard",
-"gray",
-"graze",
-"great",
-"greed",
-"greek",
-"green",
-"greenback",
-"greenhorn",
-"greet",
-"gremlin",
-"grey",
-"greyhound",
-"grid",
-"gridiron",
-"grief",
-"grill",
-"grim",
-"grime",
-"grin",
-"grind",
-"grinder",
-"grip",
-"gripe",
-"grit",
-"groan",
-"grocer",
-"groin",
-"groom",
-"grope",
-"gross",
-"grotto",
-"grouch",
-"ground",
-"groundwork",
-"group",
-"grouper",
-"grove",
-"grovel",
-"grow",
-"grower",
-"growl",
-"growth",
-"grub",
-"gruel",
-"grunt",
-"guarantor",
-"guard",
-"guardian",
-"guerilla",
-"guerrilla",
-"guess",
-"guest",
-"guffaw",
-"guidebook",
-"guild",
-"guilt",
-"guitar",
-"gulch",
-"gulf",
-"gull",
-"gullet",
-"gulley",
-"gulp",
-"gum",
-"gumdrop",
-"gumption",
-"gun",
-"gunman",
-"gunner",
-"gunshot",
-"guru",
-"gush",
-"gusher",
-"gust",
-"gut",
-"gutter",
-"guy",
-"gybe",
-"gym",
-"gymnasium",
-"gymnast",
-"gynecologist",
-"habit",
-"habitat",
-"hack",
-"hacker",
-"hackney",
-"hacksaw",
-"haddock",
-"haemoglobin",
-"haemophilia",
-"hag",
-"hail",
-"hair",
-"haircut",
-"hairdo",
-"hale",
-"half",
-"halibut",
-"hall",
-"hallelujah",
-"hallmark",
-"halloween",
-"hallway",
-"halo",
-"halt",
-"halter",
-"ham",
-"hamlet",
-"hammer",
-"hammock",
-"hamper",
-"hamster",
-"hamstring",
-"hand",
-"handbag",
-"handbook",
-"handcuff",
-"handed",
-"handicap",
-"handicraft",
-"handiwork",
-"handkerchief",
-"handlebar",
-"handler",
-"handout",
-"handrail",
-"hands",
-"hang",
-"hangar",
-"hanger",
-"hangout",
-"hanker",
-"happen",
-"harass",
-"harbor",
-"hard",
-"harden",
-"hardship",
-"hardwood",
-"hare",
-"harem",
-"hark",
-"harlot",
-"harm",
-"harmless",
-"harmonica",
-"harp",
-"harpist",
-"harpoon",
-"harpsichord",
-"harrow",
-"harsh",
-"hart",
-"harvest",
-"hash",
-"hasten",
-"hat",
-"hatch",
-"hatchet",
-"hate",
-"haul",
-"haunt",
-"have",
-"haven",
-"havoc",
-"hawk",
-"hay",
-"haystack",
-"hazard",
-"haze",
-"hazel",
-"he",
-"head",
-"header",
-"headland",
-"headlight",
-"headrest",
-"headroom",
-"headway",
-"heal",
-"healer",
-"health",
-"heap",
-"hear",
-"hears",
-"hearsay",
-"heart",
-"heartbeat",
-"heartbreak",
-"heartburn",
-"hearten",
-"hearth",
-"heat",
-"heater",
-"heathen",
-"heather",
-"heaven",
-"heavyweight",
-"hebrew",
-"heckler",
-"hedgehog",
-"heed",
-"heel",
-"heifer",
-"height",
-"heighten",
-"heir",
-"heirloom",
-"heliport",
-"helium",
-"hell",
-"hello",
-"helm",
-"helmet",
-"help",
-"helper",
-"helpless",
-"hem",
-"hemlock",
-"hemoglobin",
-"hemophilia",
-"hemp",
-"hen",
-"henchman",
-"her",
-"herald",
-"herb",
-"herd",
-"here",
-"hermit",
-"hernia",
-"hero",
-"heroin",
-"heroism",
-"heron",
-"herring",
-"hew",
-"hexagon",
-"heyday",
-"hiatus",
-"hiccup",
-"hick",
-"hide",
-"hideaway",
-"hideous",
-"high",
-"highbrow",
-"highjack",
-"highland",
-"highlight",
-"highway",
-"hijack",
-"hike",
-"hiker",
-"hill",
-"hilt",
-"him",
-"hind",
-"hinder",
-"hindsight",
-"hint",
-"hinterland",
-"hip",
-"hippopotamus",
-"hire",
-"hiss",
-"historian",
-"hit",
-"hitch",
-"hive",
-"ho",
-"hoard",
-"hoarder",
-"hoax",
-"hobgoblin",
-"hobnob",
-"hobo",
-"hock",
-"hockey",
-"hoe",
-"hog",
-"hoist",
-"hold",
-"holder",
-"holdup",
-"hole",
-"holiday",
-"holler",
-"hollow",
-"holocaust",
-"holster",
-"home",
-"homeland",
-"homeless",
-"homesick",
-"homespun",
-"homestead",
-"homework",
-"homey",
-"homonym",
-"hone",
-"honest",
-"honey",
-"honeycomb",
-"honeymoon",
-"honk",
-"honor",
-"hood",
-"hoodlum",
-"hoodwink",
-"hoof",
-"hook",
-"hoop",
-"hoorah",
-"hooray",
-"hoot",
-"hop",
-"hope",
-"hopeless",
-"hopscotch",
-"horizon",
-"horn",
-"hornet",
-"horror",
-"horseback",
-"horseplay",
-"horseradish",
-"hose",
-"host",
-"hostel",
-"hostess",
-"hot",
-"hotel",
-"hothead",
-"hound",
-"hour",
-"hourglass",
-"houseboat",
-"household",
-"housework",
-"hovel",
-"hover",
-"how",
-"howl",
-"hub",
-"hubbub",
-"hue",
-"huff",
-"hug",
-"huge",
-"hulk",
-"hull",
-"hullabaloo",
-"hum",
-"human",
-"humanitarian",
-"humdrum",
-"humid",
-"hummingbird",
-"humor",
-"humorist",
-"hump",
-"hunch",
-"hunchback",
-"hundredth",
-"hunger",
-"hunk",
-"hunt",
-"hunter",
-"hurl",
-"hurrah",
-"hurray",
-"hurt",
-"husband",
-"hush",
-"husk",
-"hustler",
-"hut",
-"hutch",
-"hyacinth",
-"hyaena",
-"hybrid",
-"hydrant",
-"hydrogen",
-"hyena",
-"hymn",
-"hymnal",
-"hyphen",
-"hypnotist",
-"hypochondria",
-"hypochondriac",
-"hysteria",
-"ice",
-"iceberg",
-"icon",
-"id",
-"idea",
-"ideal",
-"idealist",
-"idiom",
-"idiot",
-"idol",
-"if",
-"igloo",
-"iguana",
-"ikon",
-"ill",
-"imbed",
-"imp",
-"impact",
-"impair",
-"impart",
-"impeach",
-"impel",
-"impend",
-"imperfect",
-"imperil",
-"impetus",
-"implant",
-"implement",
-"implicit",
-"import",
-"impostor",
-"impound",
-"impoverish",
-"impress",
-"impression",
-"imprint",
-"imprison",
-"impromptu",
-"in",
-"inbreed",
-"incest",
-"inch",
-"incident",
-"incisor",
-"incognito",
-"incorrect",
-"increment",
-"incur",
-"indent",
-"index",
-"indian",
-"indict",
-"indigo",
-"indirect",
-"individualist",
-"indoor",
-"induct",
-"industrialist",
-"inertia",
-"infant",
-"infect",
-"infer",
-"inferior",
-"inferno",
-"infest",
-"infidel",
-"infield",
-"infirm",
-"inflict",
-"influenza",
-"influx",
-"inform",
-"ingest",
-"ingrain",
-"inhabit",
-"inherit",
-"inhibit",
-"inhuman",
-"initial",
-"inject",
-"ink",
-"inland",
-"inlay",
-"inlet",
-"inn",
-"inning",
-"innuendo",
-"input",
-"inquest",
-"insect",
-"insert",
-"insight",
-"insignia",
-"insist",
-"insomnia",
-"inspect",
-"inspector",
-"instal",
-"instant",
-"instep",
-"instil",
-"instinct",
-"institution",
-"instruct",
-"instructor",
-"instrument",
-"insulin",
-"insult",
-"intellect",
-"intend",
-"intent",
-"inter",
-"interact",
-"intercept",
-"intercom",
-"interest",
-"interim",
-"interior",
-"interject",
-"interlock",
-"intern",
-"interplay",
-"interpret",
-"interrupt",
-"intersect",
-"interview",
-"intravenous",
-"intrench",
-"introvert",
-"intrust",
-"invalid",
-"invent",
-"inventor",
-"invert",
-"invest",
-"investor",
-"inward",
-"ion",
-"iota",
-"ire",
-"iris",
-"irk",
-"iron",
-"irregular",
-"islam",
-"island",
-"isthmus",
-"it",
-"italic",
-"itch",
-"item",
-"jab",
-"jabber",
-"jack",
-"jackal",
-"jackass",
-"jacket",
-"jackpot",
-"jade",
-"jagged",
-"jaguar",
-"jail",
-"jailer",
-"jailor",
-"jam",
-"jamb",
-"janitor",
-"jar",
-"jargon",
-"jaunt",
-"javelin",
-"jaw",
-"jay",
-"jaywalk",
-"jazz",
-"jealous",
-"jeer",
-"jell",
-"jellyfish",
-"jerk",
-"jersey",
-"jest",
-"jester",
-"jet",
-"jettison",
-"jew",
-"jewel",
-"jibe",
-"jig",
-"jigsaw",
-"jilt",
-"jinx",
-"job",
-"jockey",
-"jog",
-"jogger",
-"join",
-"joint",
-"joke",
-"joker",
-"jolt",
-"jot",
-"journal",
-"journalist",
-"journey",
-"jovial",
-"joy",
-"joyful",
-"joyous",
-"judaism",
-"judgement",
-"judgment",
-"judo",
-"jug",
-"juggler",
-"jugular",
-"jumbo",
-"jump",
-"jumper",
-"junction",
-"june",
-"junior",
-"junk",
-"junket",
-"junta",
-"juror",
-"just",
-"jut",
-"jute",
-"kangaroo",
-"karat",
-"kayak",
-"keel",
-"keen",
-"keep",
-"keeper",
-"keg",
-"kelp",
-"kennel",
-"kerchief",
-"kernel",
-"ketchup",
-"key",
-"keyboard",
-"keyword",
-"khaki",
-"kick",
-"kickback",
-"kickoff",
-"kid",
-"kidnap",
-"kidney",
-"kill",
-"killer",
-"kiln",
-"kilo",
-"kilogram",
-"kilowatt",
-"kilt",
-"kimono",
-"kind",
-"kindergarten",
-"kinfolk",
-"king",
-"kingdom",
-"kink",
-"kinship",
-"kiosk",
-"kiss",
-"kit",
-"kitchen",
-"kite",
-"kitten",
-"kiwi",
-"knack",
-"knapsack",
-"knead",
-"knee",
-"kneecap",
-"kneel",
-"knife",
-"knight",
-"knighthood",
-"knit",
-"knob",
-"knock",
-"knocker",
-"knockout",
-"knoll",
-"knot",
-"know",
-"koala",
-"koran",
-"kosher",
-"kowtow",
-"lab",
-"label",
-"labor",
-"labyrinth",
-"lace",
-"lack",
-"lacquer",
-"lad",
-"ladder",
-"lade",
-"ladybug",
-"lag",
-"laggard",
-"lagoon",
-"lair",
-"lake",
-"lamb",
-"lame",
-"lament",
-"lamp",
-"lampoon",
-"land",
-"landlord",
-"landmark",
-"landslid",
-"lane",
-"languish",
-"languor",
-"lantern",
-"lap",
-"lapel",
-"laps",
-"lard",
-"lark",
-"larva",
-"larynx",
-"laser",
-"lash",
-"lass",
-"last",
-"latch",
-"late",
-"later",
-"lateral",
-"latex",
-"lath",
-"lather",
-"latin",
-"latter",
-"laud",
-"laugh",
-"laughingstock",
-"laughter",
-"launch",
-"launcher",
-"launder",
-"laurel",
-"lava",
-"lavish",
-"law",
-"lawn",
-"lawsuit",
-"lawyer",
-"lay",
-"layer",
-"layman",
-"layout",
-"lead",
-"leader",
-"leadership",
-"leaf",
-"leaflet",
-"leak",
-"lean",
-"leap",
-"leapfrog",
-"learn",
-"leash",
-"least",
-"leather",
-"lectern",
-"ledger",
-"leech",
-"leek",
-"leer",
-"leeway",
-"left",
-"leg",
-"legal",
-"legend",
-"leggin",
-"legion",
-"lemon",
-"lend",
-"length",
-"lengthen",
-"lens",
-"lentil",
-"leopard",
-"leotard",
-"leper",
-"lesbian",
-"lesion",
-"less",
-"lessen",
-"lesson",
-"let",
-"letdown",
-"letter",
-"letterhead",
-"letup",
-"leukemia",
-"level",
-"lever",
-"lexicon",
-"liaison",
-"liar",
-"libel",
-"librarian",
-"lichen",
-"lick",
-"lid",
-"lie",
-"lieu",
-"life",
-"lifeboat",
-"lifeguard",
-"lift",
-"ligament",
-"light",
-"lighten",
-"lighter",
-"lightning",
-"lightweight",
-"like",
-"likelihood",
-"liken",
-"lilac",
-"lilt",
-"limb",
-"limber",
-"lime",
-"limelight",
-"limerick",
-"limit",
-"limp",
-"linchpin",
-"line",
-"linear",
-"linen",
-"liner",
-"linger",
-"lingo",
-"linguist",
-"liniment",
-"link",
-"linoleum",
-"lint",
-"lion",
-"lioness",
-"lip",
-"lipstick",
-"liqueur",
-"liquid",
-"liquor",
-"lisp",
-"list",
-"listen",
-"liter",
-"litter",
-"litterbug",
-"live",
-"livelihood",
-"liven",
-"liver",
-"livestock",
-"lizard",
-"llama",
-"load",
-"loaf",
-"loafer",
-"loam",
-"loan",
-"loath",
-"lob",
-"lobbyist",
-"lobe",
-"lobster",
-"local",
-"lock",
-"locker",
-"locket",
-"locksmith",
-"locust",
-"lodger",
-"loft",
-"log",
-"logarithm",
-"logic",
-"loin",
-"loincloth",
-"loiter",
-"loll",
-"lollipop",
-"lollypop",
-"lone",
-"long",
-"longhand",
-"longshoreman",
-"look",
-"lookout",
-"loom",
-"loon",
-"looney",
-"loop",
-"loosen",
-"loot",
-"lop",
-"lope",
-"lord",
-"lore",
-"lose",
-"loser",
-"loss",
-"lot",
-"lotion",
-"lotus",
-"loud",
-"love",
-"lover",
-"low",
-"lowdown",
-"lower",
-"loyal",
-"luck",
-"lug",
-"lull",
-"lumber",
-"lumberjack",
-"lump",
-"lunch",
-"luncheon",
-"lung",
-"lupin",
-"lurch",
-"lure",
-"lurk",
-"lush",
-"lust",
-"luster",
-"lute",
-"lye",
-"lymph",
-"lynch",
-"lynchpin",
-"lyre",
-"lyric",
-"ma",
-"macaroni",
-"mace",
-"machinist",
-"mackerel",
-"mad",
-"madam",
-"madcap",
-"madden",
-"madman",
-"maelstrom",
-"magenta",
-"maggot",
-"magic",
-"magician",
-"magnesium",
-"magnet",
-"magnolia",
-"maid",
-"maiden",
-"mail",
-"mailbox",
-"mailman",
-"maim",
-"main",
-"mainland",
-"mainstay",
-"maintain",
-"major",
-"make",
-"maker",
-"makeshift",
-"makeup",
-"malaria",
-"male",
-"malign",
-"mall",
-"mallard",
-"mallet",
-"malt",
-"maltreat",
-"mama",
-"mamma",
-"mammal",
-"mammoth",
-"man",
-"mandolin",
-"mane",
-"manger",
-"mango",
-"manhood",
-"mania",
-"maniac",
-"manicurist",
-"manifest",
-"manifesto",
-"manifold",
-"mankind",
-"mannequin",
-"manner",
-"manor",
-"mansion",
-"mantel",
-"manual",
-"manuscript",
-"map",
-"mar",
-"marathon",
-"march",
-"mare",
-"margin",
-"marigold",
-"marihuana",
-"marijuana",
-"marina",
-"mark",
-"marker",
-"market",
-"marksman",
-"maroon",
-"marrow",
-"marsh",
-"marshal",
-"marshmallow",
-"mart",
-"martyr",
-"martyrdom",
-"marvel",
-"marxism",
-"marxist",
-"mascara",
-"mascot",
-"mash",
-"mask",
-"masochist",
-"mason",
-"mass",
-"mast",
-"master",
-"mastermind",
-"mat",
-"matador",
-"match",
-"matchbook",
-"mate",
-"materialist",
-"math",
-"mathematician",
-"matriarch",
-"matrix",
-"matron",
-"matt",
-"matter",
-"mattress",
-"maul",
-"mausoleum",
-"maverick",
-"maxim",
-"maximum",
-"may",
-"mayhem",
-"mayor",
-"maze",
-"meadow",
-"meal",
-"mean",
-"meander",
-"meaning",
-"meat",
-"medal",
-"medallion",
-"meddler",
-"media",
-"medium",
-"medley",
-"meek",
-"meet",
-"megaton",
-"mellow",
-"melodrama",
-"melon",
-"melt",
-"member",
-"membership",
-"memento",
-"memo",
-"memoir",
-"memorandum",
-"mend",
-"menial",
-"mental",
-"menthol",
-"mention",
-"mentor",
-"menu",
-"meow",
-"merchant",
-"merciless",
-"mere",
-"merger",
-"meridian",
-"merit",
-"mermaid",
-"merriment",
-"mesh",
-"mess",
-"metal",
-"metaphor",
-"mete",
-"meteor",
-"meteorologist",
-"meter",
-"method",
-"metro",
-"metropolis",
-"mew",
-"miaow",
-"microfilm",
-"microsecond",
-"midday",
-"middleman",
-"midget",
-"midnight",
-"midriff",
-"midst",
-"midstream",
-"midway",
-"mien",
-"might",
-"migrant",
-"mike",
-"mild",
-"mildew",
-"mile",
-"militia",
-"milk",
-"milkman",
-"mill",
-"miller",
-"milligram",
-"million",
-"millionth",
-"millisecond",
-"mime",
-"mimic",
-"mincemeat",
-"mind",
-"minded",
-"mindless",
-"mine",
-"miner",
-"minibus",
-"minimum",
-"minion",
-"mink",
-"minnow",
-"minor",
-"minstrel",
-"mint",
-"minuet",
-"minus",
-"mire",
-"mirror",
-"mirth",
-"misbehavior",
-"mischief",
-"misconduct",
-"misdemeanor",
-"misdirect",
-"miser",
-"misfit",
-"mishap",
-"misinform",
-"misinterpret",
-"mislay",
-"mislead",
-"mismatch",
-"misprint",
-"misread",
-"misrepresent",
-"miss",
-"mission",
-"mist",
-"mistaken",
-"mistress",
-"mistrust",
-"misunderstand",
-"mite",
-"mitt",
-"mitten",
-"mix",
-"mixer",
-"moan",
-"moat",
-"mob",
-"moccasin",
-"mock",
-"mockingbird",
-"mode",
-"model",
-"modern",
-"modest",
-"modicum",
-"mohair",
-"moisten",
-"molar",
-"mold",
-"mole",
-"molest",
-"mollusc",
-"mollusk",
-"molt",
-"mom",
-"moment",
-"momentum",
-"momma",
-"monarch",
-"monday",
-"money",
-"mongrel",
-"monitor",
-"monk",
-"monkey",
-"monogram",
-"monolog",
-"monorail",
-"monsoon",
-"monster",
-"month",
-"monument",
-"moo",
-"mood",
-"moon",
-"moonbeam",
-"moonlight",
-"moor",
-"moos",
-"moot",
-"mop",
-"mope",
-"moral",
-"moralist",
-"morass",
-"moratorium",
-"more",
-"morn",
-"moron",
-"morsel",
-"mortal",
-"mortar",
-"mosaic",
-"moslem",
-"mosquito",
-"moss",
-"most",
-"motel",
-"moth",
-"mother",
-"motherhood",
-"motif",
-"motion",
-"motley",
-"motor",
-"motorist",
-"motorway",
-"motto",
-"mound",
-"mount",
-"mountain",
-"mourn",
-"mourner",
-"mouth",
-"move",
-"movement",
-"mover",
-"mow",
-"mower",
-"mr",
-"ms",
-"much",
-"muck",
-"mucus",
-"mud",
-"muff",
-"muffin",
-"muffler",
-"mug",
-"mugger",
-"mulch",
-"mule",
-"mull",
-"mum",
-"munch",
-"mural",
-"murder",
-"murmur",
-"muse",
-"museum",
-"mush",
-"mushroom",
-"music",
-"musician",
-"musk",
-"musket",
-"muslim",
-"muss",
-"mussel",
-"must",
-"mustang",
-"mustard",
-"muster",
-"mutant",
-"mute",
-"mutt",
-"mutter",
-"mutton",
-"mutual",
-"myriad",
-"mystic",
-"myth",
-"nab",
-"nag",
-"nail",
-"naked",
-"name",
-"nap",
-"napalm",
-"nape",
-"napkin",
-"narrow",
-"nasal",
-"nation",
-"nationalist",
-"naturalist",
-"naught",
-"nausea",
-"navel",
-"nay",
-"nazi",
-"near",
-"nearsighted",
-"neat",
-"nebula",
-"neck",
-"neckerchief",
-"nectar",
-"need",
-"needless",
-"needlework",
-"neglect",
-"neglig",
-"neigh",
-"neighbor",
-"neighborhood",
-"neon",
-"nephew",
-"nervous",
-"nest",
-"net",
-"network",
-"neurologist",
-"neuron",
-"neuter",
-"neutral",
-"neutron",
-"new",
-"newborn",
-"newscast",
-"newsprint",
-"newsstand",
-"newt",
-"next",
-"nice",
-"nick",
-"nickel",
-"night",
-"nightclub",
-"nightgown",
-"nil",
-"nincompoop",
-"nine",
-"nineteen",
-"nineteenth",
-"ninetieth",
-"ninth",
-"nip",
-"nit",
-"nite",
-"nitrogen",
-"nitwit",
-"no",
-"nobleman",
-"noblewoman",
-"nod",
-"node",
-"noiseless",
-"nomad",
-"nonconformist",
-"nonpartisan",
-"nonprofit",
-"nook",
-"noon",
-"norm",
-"normal",
-"north",
-"northeast",
-"northwest",
-"nose",
-"nostalgia",
-"nostril",
-"notch",
-"note",
-"notebook",
-"nothing",
-"noticeboard",
-"notion",
-"nougat",
-"nought",
-"noun",
-"nourish",
-"novel",
-"novelist",
-"now",
-"nucleus",
-"nude",
-"nugget",
-"null",
-"numb",
-"number",
-"nun",
-"nuptial",
-"nursemaid",
-"nut",
-"nutmeg",
-"nutrient",
-"nutriment",
-"nylon",
-"nymph",
-"oaf",
-"oak",
-"oar",
-"oath",
-"oatmeal",
-"obelisk",
-"obey",
-"object",
-"objection",
-"objector",
-"oblivion",
-"oblong",
-"obsess",
-"obstetrician",
-"obstruct",
-"obtain",
-"obvious",
-"occasion",
-"occur",
-"ocean",
-"octagon",
-"october",
-"octopus",
-"ocular",
-"odd",
-"ode",
-"odor",
-"of",
-"off",
-"offbeat",
-"offend",
-"offer",
-"offset",
-"offshoot",
-"often",
-"oh",
-"ohm",
-"oil",
-"ointment",
-"ok",
-"okay",
-"okra",
-"old",
-"omelet",
-"omen",
-"omit",
-"one",
-"onion",
-"onomatopoeia",
-"onrush",
-"onset",
-"onslaught",
-"onus",
-"onward",
-"opal",
-"open",
-"opera",
-"operand",
-"ophthalmologist",
-"opinion",
-"opium",
-"opossum",
-"opportunist",
-"oppress",
-"oppressor",
-"opt",
-"optic",
-"optician",
-"optimist",
-"optimum",
-"option",
-"optometrist",
-"opus",
-"oral",
-"orangutan",
-"orangutang",
-"orbit",
-"orchard",
-"orchestra",
-"orchid",
-"ordain",
-"ordeal",
-"order",
-"ore",
-"organ",
-"organist",
-"orient",
-"origin",
-"ornament",
-"ornithologist",
-"orphan",
-"orthodontist",
-"ostrich",
-"other",
-"otter",
-"our",
-"oust",
-"ouster",
-"out",
-"outbreak",
-"outburst",
-"outcast",
-"outclass",
-"outdo",
-"outdoor",
-"outfield",
-"outfit",
-"outgrow",
-"outgrowth",
-"outing",
-"outlast",
-"outlaw",
-"outlay",
-"outlet",
-"outlook",
-"outpost",
-"output",
-"outrun",
-"outset",
-"outskirt",
-"outsmart",
-"outstrip",
-"outward",
-"outweigh",
-"outwit",
-"oval",
-"oven",
-"over",
-"overbear",
-"overburden",
-"overcast",
-"overcoat",
-"overcrowd",
-"overdo",
-"overdraw",
-"overeat",
-"overflow",
-"overgrow",
-"overhand",
-"overhang",
-"overhaul",
-"overhead",
-"overhear",
-"overheat",
-"overlap",
-"overlay",
-"overload",
-"overlook",
-"overnight",
-"overpass",
-"overprint",
-"overreact",
-"overrun",
-"overs",
-"overshadow",
-"overshoot",
-"oversight",
-"oversleep",
-"overstep",
-"overt",
-"overthrow",
-"overturn",
-"overweight",
-"overwhelm",
-"overwork",
-"ovum",
-"owe",
-"owl",
-"own",
-"owner",
-"ownership",
-"ox",
-"oxygen",
-"oyster",
-"pa",
-"pace",
-"pacifist",
-"pack",
-"packer",
-"packet",
-"pact",
-"pad",
-"paddock",
-"padlock",
-"pagan",
-"page",
-"pageant",
-"pagoda",
-"pail",
-"pain",
-"painful",
-"painless",
-"paint",
-"pair",
-"pal",
-"pale",
-"paleontologist",
-"pall",
-"pallor",
-"palm",
-"palomino",
-"pamper",
-"pamphlet",
-"pan",
-"panacea",
-"pancreas",
-"panda",
-"pandemonium",
-"pander",
-"pane",
-"panel",
-"pang",
-"panic",
-"panorama",
-"pant",
-"panther",
-"papa",
-"papaya",
-"paper",
-"paperback",
-"paperweight",
-"paprika",
-"papyrus",
-"par",
-"paradox",
-"paraffin",
-"paragon",
-"paragraph",
-"parakeet",
-"parallel",
-"paranoid",
-"paraphernalia",
-"parasol",
-"parcel",
-"parch",
-"parchment",
-"pardon",
-"pare",
-"parent",
-"parenthood",
-"parish",
-"park",
-"parka",
-"parkway",
-"parliament",
-"parlor",
-"parrakeet",
-"parrot",
-"pars",
-"parsec",
-"parsley",
-"parsnip",
-"parson",
-"part",
-"partial",
-"particular",
-"partisan",
-"partizan",
-"partner",
-"partnership",
-"pass",
-"passageway",
-"passbook",
-"passion",
-"passport",
-"password",
-"past",
-"pasta",
-"pastel",
-"pastor",
-"pat",
-"patch",
-"patchwork",
-"pate",
-"patent",
-"path",
-"pathologist",
-"pathway",
-"patient",
-"patio",
-"patriarch",
-"patriot",
-"patrol",
-"patron",
-"patter",
-"pattern",
-"paunch",
-"pauper",
-"pave",
-"pavement",
-"pavilion",
-"paw",
-"pawn",
-"pay",
-"payer",
-"payment",
-"payoff",
-"pea",
-"peach",
-"peacock",
-"peak",
-"peal",
-"peanut",
-"pear",
-"pearl",
-"peas",
-"peasant",
-"peat",
-"pecan",
-"peck",
-"peculiar",
-"pedal",
-"pedant",
-"peddler",
-"pedestrian",
-"pediatrician",
-"pediatrist",
-"pedlar",
-"peek",
-"peel",
-"peep",
-"peer",
-"peg",
-"pelican",
-"pellet",
-"pelt",
-"pelvis",
-"pen",
-"penal",
-"pencil",
-"pendant",
-"pendulum",
-"penguin",
-"penicillin",
-"peninsula",
-"penis",
-"penmanship",
-"pennant",
-"pension",
-"pentagon",
-"peon",
-"pep",
-"pepper",
-"peppermint",
-"percent",
-"perch",
-"perfect",
-"perfectionist",
-"perform",
-"peril",
-"period",
-"perish",
-"perk",
-"permit",
-"perpendicular",
-"perplex",
-"persecutor",
-"persist",
-"person",
-"personnel",
-"pertain",
-"perturb",
-"pervert",
-"pessimist",
-"pest",
-"pester",
-"pet",
-"petal",
-"peter",
-"petrol",
-"petroleum",
-"petticoat",
-"petunia",
-"pew",
-"pewter",
-"phantom",
-"pharmacist",
-"phase",
-"pheasant",
-"phenomenon",
-"philanthropist",
-"phlegm",
-"phobia",
-"phone",
-"phoney",
-"phonograph",
-"phosphorus",
-"photo",
-"photograph",
-"photon",
-"phrase",
-"physic",
-"physician",
-"physicist",
-"pi",
-"pianist",
-"piano",
-"piccolo",
-"pick",
-"pickaback",
-"pickax",
-"picket",
-"pickpocket",
-"pickup",
-"picnic",
-"pie",
-"piecework",
-"pier",
-"pig",
-"pigeon",
-"piggyback",
-"pigment",
-"pigpen",
-"pigtail",
-"pike",
-"pile",
-"pilfer",
-"pilgrim",
-"pill",
-"pillar",
-"pillow",
-"pilot",
-"pin",
-"pinch",
-"pincushion",
-"pine",
-"pinion",
-"pink",
-"pinpoint",
-"pint",
-"pioneer",
-"pipe",
-"piranha",
-"pistachio",
-"pistol",
-"piston",
-"pit",
-"pitch",
-"pitcher",
-"pitchfork",
-"piteous",
-"pivot",
-"pizza",
-"placard",
-"place",
-"placenta",
-"placid",
-"plagiarist",
-"plaid",
-"plain",
-"plaintiff",
-"plan",
-"plane",
-"planet",
-"planetarium",
-"plank",
-"plankton",
-"planner",
-"plant",
-"plantain",
-"planter",
-"plasma",
-"plaster",
-"plastic",
-"plate",
-"plateau",
-"platform",
-"platinum",
-"platoon",
-"platter",
-"play",
-"player",
-"playground",
-"playpen",
-"playwright",
-"plaza",
-"plea",
-"plead",
-"pleas",
-"pleasant",
-"pleat",
-"plight",
-"plod",
-"plop",
-"plot",
-"plotter",
-"plough",
-"plow",
-"ploy",
-"pluck",
-"plug",
-"plum",
-"plumb",
-"plumber",
-"plume",
-"plummet",
-"plump",
-"plunder",
-"plunger",
-"plural",
-"plus",
-"plush",
-"plutonium",
-"plywood",
-"pneumonia",
-"poach",
-"poacher",
-"pocket",
-"pocketbook",
-"pockmark",
-"pod",
-"podium",
-"poem",
-"poet",
-"poetic",
-"poinsettia",
-"point",
-"pointer",
-"pointless",
-"poison",
-"poke",
-"poker",
-"polar",
-"pole",
-"policeman",
-"policewoman",
-"polio",
-"polish",
-"politician",
-"polka",
-"poll",
-"pollen",
-"pollster",
-"polo",
-"polygon",
-"polyp",
-"pomp",
-"poncho",
-"pond",
-"ponder",
-"pontoon",
-"pool",
-"poop",
-"poor",
-"pop",
-"popcorn",
-"poplar",
-"popular",
-"porcelain",
-"porch",
-"pore",
-"pork",
-"port",
-"portal",
-"portend",
-"portent",
-"porter",
-"portfolio",
-"portico",
-"portion",
-"portrait",
-"portray",
-"pose",
-"possess",
-"possessor",
-"possum",
-"post",
-"postcard",
-"poster",
-"posterior",
-"postman",
-"postmark",
-"postscript",
-"pot",
-"potassium",
-"potato",
-"potion",
-"potter",
-"pouch",
-"pound",
-"pour",
-"pout",
-"powder",
-"power",
-"powwow",
-"prank",
-"prawn",
-"pray",
-"prayer",
-"preach",
-"preacher",
-"precinct",
-"precursor",
-"predecessor",
-"predict",
-"preempt",
-"preen",
-"prefab",
-"prefer",
-"prefix",
-"premier",
-"premiss",
-"premium",
-"prepay",
-"present",
-"press",
-"pretend",
-"pretext",
-"pretzel",
-"prevail",
-"prevent",
-"preview",
-"previous",
-"prey",
-"price",
-"prick",
-"pride",
-"priest",
-"priestess",
-"priesthood",
-"prim",
-"prime",
-"primer",
-"primp",
-"princess",
-"print",
-"printer",
-"printout",
-"prior",
-"prism",
-"prison",
-"prize",
-"pro",
-"probe",
-"problem",
-"proceed",
-"process",
-"procession",
-"processor",
-"proclaim",
-"prod",
-"product",
-"profess",
-"profession",
-"professor",
-"proffer",
-"profit",
-"profound",
-"program",
-"progress",
-"prohibit",
-"project",
-"projector",
-"proletarian",
-"proletariat",
-"prolog",
-"prolong",
-"prom",
-"prompt",
-"prong",
-"pronoun",
-"proof",
-"proofread",
-"prop",
-"propaganda",
-"propel",
-"proper",
-"prophet",
-"proportion",
-"proprietor",
-"prose",
-"prosecutor",
-"prospect",
-"prospector",
-"prospectus",
-"prosper",
-"protagonist",
-"protect",
-"protector",
-"protein",
-"protest",
-"protestor",
-"protocol",
-"proton",
-"protract",
-"protractor",
-"proud",
-"prove",
-"proven",
-"proverb",
-"provision",
-"proviso",
-"prow",
-"prowess",
-"prowl",
-"prowler",
-"prude",
-"prune",
-"psalm",
-"pseudonym",
-"psych",
-"psychiatrist",
-"psychic",
-"psychoanalyst",
-"psychologist",
-"psychopath",
-"public",
-"publish",
-"puck",
-"pucker",
-"pueblo",
-"puff",
-"puke",
-"pull",
-"pulley",
-"pulp",
-"pulpit",
-"puma",
-"pummel",
-"pump",
-"pumpernickel",
-"pumpkin",
-"pun",
-"punch",
-"punctual",
-"pundit",
-"punish",
-"punk",
-"punt",
-"punter",
-"pup",
-"pupil",
-"puppet",
-"pure",
-"purport",
-"purr",
-"pursuit",
-"pus",
-"push",
-"pusher",
-"puss",
-"put",
-"putt",
-"putter",
-"pyramid",
-"pyre",
-"python",
-"quack",
-"quadrant",
-"quadruplet",
-"quail",
-"qualm",
-"quarrel",
-"quart",
-"quarter",
-"quarterback",
-"quartet",
-"quartz",
-"quash",
-"quaver",
-"quay",
-"queen",
-"queer",
-"quell",
-"quench",
-"quest",
-"question",
-"queue",
-"quick",
-"quicken",
-"quicksand",
-"quiet",
-"quill",
-"quilt",
-"quintet",
-"quintuplet",
-"quip",
-"quirk",
-"quit",
-"quitter",
-"quiver",
-"quiz",
-"quorum",
-"quota",
-"quotient",
-"rabbi",
-"rabbit",
-"raccoon",
-"race",
-"racetrack",
-"racial",
-"racism",
-"racist",
-"rack",
-"racket",
-"racoon",
-"racquet",
-"radar",
-"radial",
-"radio",
-"radish",
-"radium",
-"radius",
-"raft",
-"rafter",
-"rag",
-"ragamuffin",
-"rage",
-"ragged",
-"raid",
-"raider",
-"rail",
-"railroad",
-"railway",
-"rain",
-"rainbow",
-"raincoat",
-"raindrop",
-"rainstorm",
-"raisin",
-"rake",
-"ram",
-"rambler",
-"ramp",
-"ramrod",
-"ranch",
-"rancher",
-"rancor",
-"random",
-"rang",
-"ranger",
-"rank",
-"ransack",
-"ransom",
-"rant",
-"rap",
-"rape",
-"rapid",
-"rapist",
-"rapport",
-"rare",
-"rascal",
-"rash",
-"rasp",
-"rat",
-"rate",
-"ratio",
-"ration",
-"rational",
-"rattler",
-"raucous",
-"rave",
-"ravel",
-"raven",
-"ravish",
-"raw",
-"ray",
-"rayon",
-"raze",
-"razor",
-"re",
-"reach",
-"react",
-"reaction",
-"reactor",
-"read",
-"reader",
-"readjust",
-"real",
-"realism",
-"realist",
-"realm",
-"ream",
-"reap",
-"reaper",
-"reappear",
-"rear",
-"reason",
-"rebel",
-"rebellion",
-"rebind",
-"rebirth",
-"rebound",
-"rebuff",
-"rebuild",
-"rebut",
-"recant",
-"recap",
-"receipt",
-"recent",
-"receptionist",
-"recess",
-"reckless",
-"reckon",
-"reclaim",
-"recoil",
-"recollect",
-"recommend",
-"reconnect",
-"reconstruct",
-"record",
-"recount",
-"recoup",
-"recover",
-"recruit",
-"rector",
-"rectum",
-"recur",
-"recurs",
-"red",
-"redden",
-"redeem",
-"redesign",
-"redhead",
-"redirect",
-"redo",
-"redress",
-"reed",
-"reef",
-"reek",
-"reel",
-"reelect",
-"refer",
-"referendum",
-"reflect",
-"reflector",
-"reflex",
-"reform",
-"reformat",
-"refrain",
-"refresh",
-"refuel",
-"refuge",
-"refund",
-"refurbish",
-"regain",
-"regal",
-"regalia",
-"regard",
-"regatta",
-"regent",
-"regimen",
-"regiment",
-"region",
-"registrar",
-"regress",
-"regret",
-"regular",
-"rehash",
-"reign",
-"rein",
-"reindeer",
-"reject",
-"rejoin",
-"relationship",
-"relax",
-"relay",
-"relent",
-"relentless",
-"relic",
-"relief",
-"religion",
-"relinquish",
-"relish",
-"reload",
-"remain",
-"remark",
-"remind",
-"remiss",
-"remit",
-"remnant",
-"remodel",
-"rend",
-"render",
-"rendezvous",
-"renew",
-"renown",
-"rent",
-"rental",
-"reopen",
-"repair",
-"repay",
-"repeal",
-"repeat",
-"repel",
-"repent",
-"replenish",
-"replica",
-"report",
-"represent",
-"repress",
-"reprimand",
-"reprint",
-"reproach",
-"reprogram",
-"republican",
-"request",
-"reread",
-"rescind",
-"rescuer",
-"research",
-"resent",
-"reservoir",
-"reset",
-"resign",
-"resin",
-"resist",
-"resistor",
-"resort",
-"resound",
-"respect",
-"respond",
-"rest",
-"restart",
-"restful",
-"restless",
-"restrain",
-"restraint",
-"restrict",
-"resubmit",
-"result",
-"resurrect",
-"retail",
-"retain",
-"retard",
-"retch",
-"retina",
-"retort",
-"retract",
-"retreat",
-"retrospect",
-"return",
-"reunion",
-"rev",
-"revamp",
-"reveal",
-"revel",
-"revert",
-"review",
-"revisit",
-"revolt",
-"revolution",
-"reward",
-"rhino",
-"rhinoceros",
-"rhododendron",
-"rhubarb",
-"rhyme",
-"rhythm",
-"rib",
-"ribbon",
-"rice",
-"rich",
-"ricksha",
-"rickshaw",
-"ricochet",
-"rid",
-"ride",
-"rider",
-"rift",
-"rig",
-"right",
-"righteous",
-"rigid",
-"rigor",
-"rile",
-"rim",
-"rind",
-"ring",
-"ringlet",
-"ringworm",
-"rink",
-"riot",
-"rioter",
-"rip",
-"ripe",
-"ripen",
-"rise",
-"riser",
-"risk",
-"rite",
-"ritual",
-"rival",
-"river",
-"rivet",
-"roach",
-"road",
-"roadblock",
-"roam",
-"roar",
-"roast",
-"rob",
-"robber",
-"robe",
-"robin",
-"robot",
-"robust",
-"rock",
-"rocker",
-"rocket",
-"rod",
-"rodent",
-"rodeo",
-"roe",
-"role",
-"roll",
-"roller",
-"romp",
-"roof",
-"rook",
-"room",
-"roost",
-"rooster",
-"root",
-"rope",
-"rose",
-"roster",
-"rostrum",
-"rot",
-"rote",
-"rotor",
-"rotten",
-"rotunda",
-"rough",
-"roughen",
-"round",
-"roundabout",
-"rout",
-"row",
-"rowboat",
-"royal",
-"rub",
-"rubber",
-"rubbish",
-"ruckus",
-"rudder",
-"rude",
-"rue",
-"ruff",
-"ruffian",
-"rug",
-"rugged",
-"ruin",
-"rule",
-"ruler",
-"rum",
-"rumor",
-"rump",
-"run",
-"runaway",
-"rundown",
-"rune",
-"rung",
-"runner",
-"runt",
-"runway",
-"ruse",
-"rush",
-"rust",
-"rustic",
-"rustler",
-"rut",
-"ruthless",
-"rye",
-"sabbath",
-"saber",
-"saboteur",
-"sac",
-"sack",
-"sacrament",
-"sad",
-"sadden",
-"sadism",
-"sadist",
-"safari",
-"safe",
-"safeguard",
-"saffron",
-"sag",
-"saga",
-"sage",
-"sagebrush",
-"sail",
-"sailboat",
-"sailor",
-"saint",
-"sake",
-"saki",
-"salad",
-"salami",
-"sale",
-"salesman",
-"salesperson",
-"saleswoman",
-"salient",
-"saliva",
-"sallow",
-"salmon",
-"salon",
-"saloon",
-"salt",
-"same",
-"sanatorium",
-"sanction",
-"sand",
-"sandal",
-"sandbag",
-"sandman",
-"sandstorm",
-"sandwich",
-"sang",
-"sanitarium",
-"sap",
-"sarcasm",
-"sari",
-"sash",
-"satan",
-"satchel",
-"satin",
-"satirist",
-"saturday",
-"saucepan",
-"saucer",
-"sauerkraut",
-"sauna",
-"saunter",
-"save",
-"savior",
-"saviour",
-"savor",
-"saw",
-"sawdust",
-"say",
-"scab",
-"scaffold",
-"scalar",
-"scald",
-"scale",
-"scallop",
-"scalp",
-"scalpel",
-"scamper",
-"scan",
-"scandal",
-"scanner",
-"scant",
-"scapegoat",
-"scar",
-"scare",
-"scarecrow",
-"scarf",
-"scarlet",
-"scatter",
-"scatterbrain",
-"scenario",
-"scene",
-"scent",
-"scepter",
-"scheme",
-"schemer",
-"scholar",
-"scholarship",
-"school",
-"schoolboy",
-"schoolchild",
-"schooner",
-"scientist",
-"scissor",
-"scoff",
-"scold",
-"scollop",
-"scoop",
-"scoot",
-"scooter",
-"scope",
-"scorch",
-"score",
-"scorn",
-"scorpion",
-"scotch",
-"scoundrel",
-"scour",
-"scout",
-"scowl",
-"scram",
-"scrap",
-"scrapbook",
-"scrape",
-"scratch",
-"scrawl",
-"scream",
-"screech",
-"screen",
-"screw",
-"scribe",
-"script",
-"scroll",
-"scrub",
-"scruff",
-"scuff",
-"sculptor",
-"scum",
-"sea",
-"seafood",
-"seal",
-"seam",
-"seaman",
-"seamstress",
-"seaport",
-"sear",
-"search",
-"searchlight",
-"seasick",
-"season",
-"seat",
-"second",
-"secret",
-"sect",
-"section",
-"sector",
-"sedan",
-"sediment",
-"see",
-"seed",
-"seek",
-"seem",
-"seep",
-"seesaw",
-"segment",
-"select",
-"selector",
-"self",
-"selfish",
-"sell",
-"seller",
-"semen",
-"semicolon",
-"semiconductor",
-"seminar",
-"send",
-"senior",
-"sensor",
-"sensual",
-"sentiment",
-"sequel",
-"sequin",
-"sergeant",
-"serial",
-"serious",
-"sermon",
-"serpent",
-"serum",
-"servant",
-"server",
-"serviceman",
-"session",
-"set",
-"setback",
-"setter",
-"settlement",
-"settler",
-"seven",
-"seventeen",
-"seventeenth",
-"seventh",
-"sever",
-"sew",
-"sewer",
-"sex",
-"sexual",
-"shack",
-"shade",
-"shadow",
-"shaft",
-"shaikh",
-"shake",
-"shallow",
-"sham",
-"shame",
-"shampoo",
-"shamrock",
-"shape",
-"share",
-"shark",
-"sharp",
-"sharpen",
-"shatter",
-"shave",
-"shaver",
-"shawl",
-"shaykh",
-"she",
-"sheaf",
-"shear",
-"sheath",
-"shed",
-"sheen",
-"sheep",
-"sheepish",
-"sheer",
-"sheet",
-"sheik",
-"sheikh",
-"shelf",
-"shell",
-"shellfish",
-"shelter",
-"shepherd",
-"sherbert",
-"sherbet",
-"sheriff",
-"shield",
-"shift",
-"shimmer",
-"shin",
-"shine",
-"ship",
-"shipment",
-"shipwreck",
-"shirk",
-"shirt",
-"shiver",
-"shoal",
-"shock",
-"shoe",
-"shoo",
-"shoot",
-"shop",
-"shopper",
-"shore",
-"short",
-"shorten",
-"shorthand",
-"shot",
-"shotgun",
-"shoulder",
-"shout",
-"shove",
-"shovel",
-"show",
-"showdown",
-"shower",
-"showman",
-"shrapnel",
-"shred",
-"shrew",
-"shrewd",
-"shriek",
-"shrill",
-"shrimp",
-"shrine",
-"shrink",
-"shrivel",
-"shroud",
-"shrub",
-"shrug",
-"shuck",
-"shudder",
-"shun",
-"shunt",
-"shut",
-"shutter",
-"shyness",
-"sic",
-"sick",
-"sicken",
-"side",
-"sideshow",
-"sidestep",
-"sidetrack",
-"sidewalk",
-"siesta",
-"sift",
-"sigh",
-"sight",
-"sign",
-"signal",
-"signpost",
-"silent",
-"silicon",
-"silk",
-"sill",
-"silo",
-"silt",
-"silver",
-"silversmith",
-"similar",
-"simmer",
-"sin",
-"sinew",
-"sing",
-"singer",
-"singular",
-"sink",
-"sinner",
-"sinus",
-"sip",
-"siphon",
-"sir",
-"sire",
-"siren",
-"sirloin",
-"sirup",
-"sister",
-"sisterhood",
-"sit",
-"site",
-"sitter",
-"six",
-"sixteen",
-"sixteenth",
-"sixth",
-"sixtieth",
-"size",
-"skate",
-"skateboard",
-"skater",
-"skein",
-"skeleton",
-"skeptic",
-"sketch",
-"skew",
-"skewer",
-"ski",
-"skid",
-"skill",
-"skillet",
-"skim",
-"skimp",
-"skin",
-"skinflint",
-"skip",
-"skipper",
-"skirmish",
-"skirt",
-"skit",
-"skulk",
-"skull",
-"skunk",
-"sky",
-"skylight",
-"skyrocket",
-"slab",
-"slack",
-"slacken",
-"slake",
-"slam",
-"slander",
-"slang",
-"slant",
-"slap",
-"slapstick",
-"slash",
-"slat",
-"slate",
-"slaughter",
-"slave",
-"slay",
-"sled",
-"sleek",
-"sleep",
-"sleeper",
-"sleet",
-"sleigh",
-"slender",
-"slew",
-"slice",
-"slick",
-"slide",
-"slight",
-"slim",
-"slime",
-"sling",
-"slingshot",
-"slink",
-"slip",
-"slipper",
-"slit",
-"slither",
-"sliver",
-"slob",
-"slobber",
-"slog",
-"slogan",
-"slop",
-"slope",
-"slosh",
-"slot",
-"sloth",
-"slouch",
-"slow",
-"slug",
-"slum",
-"slumber",
-"slump",
-"slur",
-"slush",
-"slut",
-"slyness",
-"smack",
-"small",
-"smallpox",
-"smart",
-"smash",
-"smear",
-"smell",
-"smelt",
-"smidgen",
-"smidgeon",
-"smidgin",
-"smile",
-"smirk",
-"smite",
-"smith",
-"smock",
-"smog",
-"smoke",
-"smoker",
-"smokestack",
-"smolder",
-"smooth",
-"smother",
-"smoulder",
-"smug",
-"smuggler",
-"smut",
-"snack",
-"snag",
-"snail",
-"snake",
-"snap",
-"snapshot",
-"snare",
-"snarl",
-"snatch",
-"sneak",
-"sneaker",
-"sneer",
-"snicker",
-"sniff",
-"snip",
-"snipe",
-"sniper",
-"snippet",
-"snitch",
-"snob",
-"snoop",
-"snore",
-"snorkel",
-"snort",
-"snot",
-"snout",
-"snow",
-"snowdrift",
-"snowplow",
-"snowstorm",
-"snub",
-"snuff",
-"snug",
-"so",
-"soak",
-"soap",
-"soar",
-"sob",
-"sober",
-"soccer",
-"social",
-"socialist",
-"sociologist",
-"sock",
-"socket",
-"sod",
-"soda",
-"sodium",
-"sofa",
-"soft",
-"soften",
-"soil",
-"sojourn",
-"solder",
-"soldier",
-"sole",
-"solemn",
-"solicit",
-"solicitor",
-"solid",
-"solo",
-"soloist",
-"solvent",
-"somersault",
-"somewhat",
-"son",
-"sonata",
-"song",
-"sonnet",
-"soot",
-"sop",
-"soprano",
-"sorceress",
-"sore",
-"sorrow",
-"sort",
-"soul",
-"sound",
-"soundproof",
-"soup",
-"sour",
-"south",
-"southeast",
-"southern",
-"southpaw",
-"southward",
-"southwest",
-"souvenir",
-"sovereign",
-"sow",
-"spa",
-"space",
-"spacecraft",
-"spaceship",
-"spade",
-"spaghetti",
-"span",
-"spaniel",
-"spank",
-"spanner",
-"spar",
-"spare",
-"spark",
-"sparkler",
-"sparrow",
-"spars",
-"spasm",
-"spat",
-"spatter",
-"spatula",
-"spawn",
-"spay",
-"speak",
-"speaker",
-"spear",
-"spearhead",
-"spearmint",
-"special",
-"specialist",
-"specimen",
-"speck",
-"spectacular",
-"specter",
-"spectrum",
-"speech",
-"speed",
-"speedboat",
-"spell",
-"spellbind",
-"spend",
-"spendthrift",
-"sperm",
-"spew",
-"sphere",
-"sphinx",
-"spice",
-"spider",
-"spigot",
-"spike",
-"spill",
-"spin",
-"spinach",
-"spinal",
-"spine",
-"spinster",
-"spiral",
-"spire",
-"spirit",
-"spit",
-"spite",
-"spiteful",
-"splash",
-"splatter",
-"spleen",
-"splendid",
-"splendor",
-"splice",
-"splint",
-"splinter",
-"split",
-"spoil",
-"spoke",
-"spokesman",
-"spokesperson",
-"spokeswoman",
-"sponsor",
-"spoof",
-"spook",
-"spool",
-"spoon",
-"spoons",
-"spore",
-"sport",
-"sportsmanship",
-"spot",
-"spotlight",
-"spout",
-"sprain",
-"sprawl",
-"spray",
-"spread",
-"spreadsheet",
-"spree",
-"sprig",
-"spring",
-"springboard",
-"sprinkler",
-"sprint",
-"sprinter",
-"sprout",
-"spruce",
-"spud",
-"spunk",
-"spur",
-"spurn",
-"spurt",
-"sputter",
-"squad",
-"squadron",
-"squalid",
-"squall",
-"squalor",
-"squander",
-"squash",
-"squat",
-"squawk",
-"squeak",
-"squeal",
-"squelch",
-"squid",
-"squint",
-"squirm",
-"squirrel",
-"squirt",
-"stab",
-"stack",
-"stadium",
-"staff",
-"stag",
-"stage",
-"stagecoach",
-"stagger",
-"stain",
-"stair",
-"stairway",
-"stake",
-"stale",
-"stalk",
-"stall",
-"stallion",
-"stalwart",
-"stamina",
-"stammer",
-"stamp",
-"stamped",
-"stanch",
-"stand",
-"standard",
-"standoff",
-"standpoint",
-"stanza",
-"stapler",
-"star",
-"starboard",
-"starch",
-"stardom",
-"stare",
-"starfish",
-"starlight",
-"start",
-"starter",
-"state",
-"statement",
-"statesman",
-"statesmanship",
-"static",
-"station",
-"statistician",
-"status",
-"staunch",
-"stave",
-"stay",
-"steak",
-"steal",
-"stealth",
-"steam",
-"steel",
-"steep",
-"steer",
-"stem",
-"stench",
-"stencil",
-"step",
-"stereo",
-"stern",
-"stew",
-"steward",
-"stewardess",
-"stick",
-"sticker",
-"stickler",
-"stiff",
-"stiffen",
-"stigma",
-"still",
-"stimulus",
-"sting",
-"stinger",
-"stink",
-"stint",
-"stir",
-"stirrup",
-"stitch",
-"stock",
-"stockyard",
-"stoke",
-"stole",
-"stolid",
-"stomach",
-"stomp",
-"stone",
-"stool",
-"stoop",
-"stop",
-"stopgap",
-"stopper",
-"stopwatch",
-"store",
-"storeroom",
-"storey",
-"stork",
-"storm",
-"stout",
-"stove",
-"stow",
-"stowaway",
-"straggler",
-"straight",
-"straighten",
-"straightforward",
-"straightjacket",
-"strain",
-"strainer",
-"strait",
-"straitjacket",
-"strand",
-"stranger",
-"strap",
-"stratagem",
-"stratum",
-"straw",
-"stray",
-"streak",
-"stream",
-"streamer",
-"street",
-"streetcar",
-"strength",
-"strengthen",
-"strenuous",
-"stress",
-"stretch",
-"stretcher",
-"strew",
-"strict",
-"stride",
-"strife",
-"strike",
-"striker",
-"string",
-"strip",
-"stripe",
-"strive",
-"stroke",
-"stroll",
-"stroller",
-"strong",
-"stronghold",
-"strum",
-"strut",
-"stub",
-"stubborn",
-"stud",
-"student",
-"studio",
-"stuff",
-"stump",
-"stun",
-"stunt",
-"stupid",
-"stupor",
-"stutter",
-"style",
-"sub",
-"subject",
-"sublet",
-"submit",
-"subscript",
-"subset",
-"subsist",
-"subtract",
-"suburb",
-"suburban",
-"subvert",
-"subway",
-"succeed",
-"success",
-"successor",
-"succinct",
-"succor",
-"succumb",
-"suck",
-"sucker",
-"suction",
-"sudden",
-"sue",
-"sued",
-"suffer",
-"suffix",
-"sugar",
-"suggest",
-"suit",
-"suitor",
-"sulfur",
-"sulk",
-"sullen",
-"sulphur",
-"sultan",
-"sum",
-"summer",
-"summit",
-"summon",
-"summons",
-"sun",
-"sunburn",
-"sunday",
-"sundial",
-"sundown",
-"sunlight",
-"sunscreen",
-"sunset",
-"suntan",
-"sunup",
-"super",
-"superb",
-"superior",
-"supermarket",
-"superscript",
-"superstar",
-"supervisor",
-"supper",
-"supplant",
-"supplement",
-"supplier",
-"support",
-"suppress",
-"sure",
-"surf",
-"surfboard",
-"surgeon",
-"surmount",
-"surpass",
-"surplus",
-"surround",
-"survey",
-"surveyor",
-"survivor",
-"suspect",
-"suspend",
-"suspicion",
-"sustain",
-"swab",
-"swagger",
-"swallow",
-"swamp",
-"swan",
-"swap",
-"swarm",
-"swat",
-"sway",
-"swear",
-"sweat",
-"sweater",
-"sweep",
-"sweeper",
-"sweet",
-"sweeten",
-"sweetheart",
-"swell",
-"swift",
-"swig",
-"swill",
-"swim",
-"swindler",
-"swine",
-"swing",
-"swipe",
-"swirl",
-"swish",
-"switch",
-"switchboard",
-"swivel",
-"swoon",
-"swoop",
-"sword",
-"swordfish",
-"syllabus",
-"symbol",
-"symptom",
-"synagog",
-"synonym",
-"syntax",
-"syphon",
-"syrup",
-"system",
-"tab",
-"tablecloth",
-"tablespoon",
-"tablespoons",
-"tablet",
-"tabloid",
-"taboo",
-"tabu",
-"tacit",
-"tack",
-"taco",
-"tact",
-"tactic",
-"tactless",
-"tag",
-"tail",
-"taillight",
-"tailor",
-"tailspin",
-"taint",
-"take",
-"takeoff",
-"taker",
-"talc",
-"tale",
-"talent",
-"talisman",
-"talk",
-"talker",
-"tallow",
-"talon",
-"tame",
-"tamper",
-"tan",
-"tandem",
-"tang",
-"tangent",
-"tango",
-"tank",
-"tankard",
-"tanker",
-"tantrum",
-"tap",
-"tape",
-"taper",
-"tar",
-"tarantula",
-"target",
-"tariff",
-"tarnish",
-"tarpaulin",
-"tart",
-"tartan",
-"tartar",
-"task",
-"tassel",
-"tattoo",
-"taunt",
-"taurus",
-"tavern",
-"tax",
-"taxi",
-"taxicab",
-"tea",
-"teach",
-"teacher",
-"teacup",
-"teak",
-"team",
-"teamster",
-"teamwork",
-"teapot",
-"tear",
-"teardrop",
-"teas",
-"teaspoon",
-"teat",
-"technician",
-"tedious",
-"tedium",
-"tee",
-"teem",
-"teen",
-"teeter",
-"teeth",
-"teetotal",
-"telegram",
-"telegraph",
-"tell",
-"teller",
-"temper",
-"temperament",
-"tempest",
-"tempo",
-"tempt",
-"ten",
-"tenant",
-"tend",
-"tender",
-"tendon",
-"tendril",
-"tenement",
-"tenet",
-"tenor",
-"tens",
-"tension",
-"tent",
-"tenth",
-"term",
-"terminus",
-"terrain",
-"terrier",
-"terror",
-"terrorist",
-"test",
-"testament",
-"tester",
-"tetanus",
-"tether",
-"text",
-"textbook",
-"textual",
-"thank",
-"that",
-"thatch",
-"thaw",
-"theater",
-"theft",
-"their",
-"theist",
-"theme",
-"then",
-"theologian",
-"theorem",
-"theorist",
-"therapist",
-"there",
-"thermal",
-"thermostat",
-"thesaurus",
-"these",
-"thick",
-"thicken",
-"thicket",
-"thief",
-"thigh",
-"thin",
-"thing",
-"think",
-"thinker",
-"third",
-"thirst",
-"thirteen",
-"thirteenth",
-"thirtieth",
-"thong",
-"thorn",
-"thorough",
-"thou",
-"thought",
-"thoughtless",
-"thousand",
-"thousandth",
-"thrash",
-"thread",
-"threat",
-"threaten",
-"three",
-"thresh",
-"thresher",
-"threshold",
-"thrift",
-"thrill",
-"thriller",
-"thrive",
-"throat",
-"throb",
-"throne",
-"throng",
-"throw",
-"throwback",
-"thrust",
-"thud",
-"thug",
-"thumb",
-"thumbtack",
-"thump",
-"thunder",
-"thunderbolt",
-"thunderstorm",
-"thursday",
-"thwart",
-"thyme",
-"thyroid",
-"tiara",
-"tick",
-"ticket",
-"tidbit",
-"tide",
-"tie",
-"tier",
-"tiff",
-"tiger",
-"tight",
-"tighten",
-"tightwad",
-"tile",
-"till",
-"tilt",
-"timber",
-"time",
-"timer",
-"timid",
-"tin",
-"tinder",
-"ting",
-"tinker",
-"tinsel",
-"tint",
-"tip",
-"tipi",
-"tire",
-"tired",
-"tit",
-"titbit",
-"titter",
-"to",
-"toad",
-"toadstool",
-"toast",
-"toaster",
-"tobacco",
-"toboggan",
-"today",
-"toddler",
-"toe",
-"toenail",
-"toga",
-"toil",
-"toilet",
-"token",
-"toll",
-"tomahawk",
-"tomato",
-"tomb",
-"tomboy",
-"tomcat",
-"tome",
-"tomorrow",
-"ton",
-"tone",
-"tong",
-"tonic",
-"tonight",
-"tonsil",
-"tool",
-"toot",
-"tooth",
-"toothbrush",
-"toothpick",
-"top",
-"topaz",
-"topic",
-"torch",
-"torment",
-"tormentor",
-"tornado",
-"torpedo",
-"torrent",
-"torrid",
-"torso",
-"tortilla",
-"toss",
-"tot",
-"total",
-"totalitarian",
-"tote",
-"totem",
-"totter",
-"toucan",
-"touch",
-"touchdown",
-"tough",
-"toughen",
-"tour",
-"tourist",
-"tournament",
-"tourniquet",
-"tout",
-"tow",
-"toward",
-"towel",
-"tower",
-"town",
-"toxin",
-"toy",
-"trace",
-"track",
-"tract",
-"traction",
-"tractor",
-"trade",
-"trademark",
-"trader",
-"traffic",
-"tragic",
-"trail",
-"trailer",
-"train",
-"trainer",
-"trait",
-"traitor",
-"tramp",
-"tranquil",
-"transact",
-"transcend",
-"transcript",
-"transfer",
-"transform",
-"transgress",
-"transient",
-"transistor",
-"transit",
-"transmit",
-"transplant",
-"transport",
-"trap",
-"trapezoid",
-"trapper",
-"trash",
-"trauma",
-"travel",
-"trawl",
-"trawler",
-"tray",
-"tread",
-"treason",
-"treat",
-"treatment",
-"tree",
-"trek",
-"trellis",
-"tremor",
-"trench",
-"trend",
-"trespass",
-"trial",
-"tribe",
-"trick",
-"trickster",
-"trigger",
-"trill",
-"trillion",
-"trim",
-"trinket",
-"trio",
-"trip",
-"tripe",
-"triplet",
-"tripod",
-"triumph",
-"trivia",
-"trivial",
-"troll",
-"trolley",
-"troop",
-"trooper",
-"trot",
-"trough",
-"trouser",
-"trout",
-"trowel",
-"truant",
-"truce",
-"truck",
-"true",
-"truism",
-"trump",
-"trumpet",
-"trunk",
-"trust",
-"truth",
-"tryout",
-"tsar",
-"tub",
-"tuba",
-"tube",
-"tuck",
-"tuesday",
-"tuft",
-"tug",
-"tuition",
-"tulip",
-"tumbler",
-"tumor",
-"tumult",
-"tuna",
-"tundra",
-"tune",
-"tuner",
-"tunic",
-"tunnel",
-"turban",
-"tureen",
-"turf",
-"turkey",
-"turmoil",
-"turn",
-"turnip",
-"turnout",
-"turret",
-"turtleneck",
-"tusk",
-"tutor",
-"tuxedo",
-"twang",
-"tweak",
-"tweed",
-"tweet",
-"twelfth",
-"twentieth",
-"twig",
-"twilight",
-"twin",
-"twine",
-"twirl",
-"twist",
-"twister",
-"twitch",
-"twitter",
-"two",
-"tycoon",
-"type",
-"typeset",
-"typhoid",
-"typhoon",
-"typhus",
-"typist",
-"tyrant",
-"tzar",
-"udder",
-"ulcer",
-"ultimatum",
-"ultraviolet",
-"umbrella",
-"unblock",
-"unburden",
-"unclean",
-"uncommon",
-"under",
-"underbrush",
-"undercut",
-"underdog",
-"undergo",
-"underground",
-"undergrowth",
-"underlay",
-"underneath",
-"underpass",
-"undershirt",
-"understand",
-"undertow",
-"underwear",
-"underweight",
-"underworld",
-"undo",
-"undress",
-"unearth",
-"unequal",
-"uneven",
-"unfair",
-"unfasten",
-"unfit",
-"unfold",
-"unfurl",
-"unhook",
-"unicorn",
-"uniform",
-"union",
-"unison",
-"unit",
-"unjust",
-"unkind",
-"unknown",
-"unleash",
-"unload",
-"unlock",
-"unman",
-"unmask",
-"unpack",
-"unpopular",
-"unravel",
-"unread",
-"unrest",
-"unsay",
-"unscrew",
-"unseat",
-"unsound",
-"unveil",
-"unwilling",
-"unwind",
-"unwrap",
-"up",
-"upbeat",
-"upend",
-"uphold",
-"upholster",
-"upkeep",
-"uplift",
-"upper",
-"upright",
-"uproar",
-"uproot",
-"upset",
-"upshot",
-"upstart",
-"uptown",
-"upturn",
-"upward",
-"uranium",
-"urban",
-"urchin",
-"urgent",
-"urn",
-"use",
-"useless",
-"user",
-"usher",
-"usual",
-"usurp",
-"utensil",
-"uterus",
-"utilitarian",
-"utmost",
-"utter",
-"vacuum",
-"vagabond",
-"vagina",
-"vagrant",
-"valet",
-"valid",
-"valley",
-"valor",
-"van",
-"vandal",
-"vane",
-"vanguard",
-"vanilla",
-"vanish",
-"vanquish",
-"vapor",
-"variant",
-"various",
-"varnish",
-"vase",
-"vast",
-"vat",
-"vault",
-"veal",
-"vector",
-"veer",
-"vegetarian",
-"vehement",
-"veil",
-"vein",
-"velour",
-"velvet",
-"vend",
-"vender",
-"vendor",
-"veneer",
-"venison",
-"venom",
-"vent",
-"ventriloquist",
-"veranda",
-"verandah",
-"verb",
-"verbal",
-"verdict",
-"vermin",
-"vernacular",
-"version",
-"vertebra",
-"vertigo",
-"vessel",
-"vest",
-"vestment",
-"vet",
-"veteran",
-"veterinarian",
-"veto",
-"vex",
-"viaduct",
-"vial",
-"vicar",
-"vice",
-"vicious",
-"victim",
-"victor",
-"video",
-"vie",
-"view",
-"viewer",
-"viewpoint",
-"vigil",
-"vigilant",
-"vigor",
-"villa",
-"villain",
-"vine",
-"vinegar",
-"vineyard",
-"vinyl",
-"viola",
-"violent",
-"violet",
-"violin",
-"viper",
-"virgin",
-"virtual",
-"virtuoso",
-"virtuous",
-"virus",
-"visa",
-"vise",
-"vision",
-"visit",
-"visitor",
-"visor",
-"vista",
-"visual",
-"vital",
-"vitamin",
-"vivid",
-"vizor",
-"vocal",
-"vocalist",
-"vodka",
-"void",
-"volcano",
-"volley",
-"volt",
-"vomit",
-"voodoo",
-"vortex",
-"vote",
-"voter",
-"vouch",
-"voucher",
-"vow",
-"vowel",
-"vulgar",
-"wad",
-"wade",
-"wafer",
-"waft",
-"wag",
-"wage",
-"wager",
-"wagon",
-"waif",
-"wail",
-"waist",
-"wait",
-"waiter",
-"waitress",
-"waiver",
-"wake",
-"waken",
-"walk",
-"walker",
-"walkout",
-"wall",
-"wallet",
-"wallop",
-"wallow",
-"walnut",
-"walrus",
-"waltz",
-"wand",
-"wander",
-"wane",
-"want",
-"wanton",
-"war",
-"ward",
-"warden",
-"warhead",
-"warm",
-"warmth",
-"warn",
-"warp",
-"warpath",
-"warrant",
-"warren",
-"warrior",
-"wart",
-"wash",
-"washcloth",
-"washer",
-"washout",
-"washroom",
-"wasp",
-"wastebasket",
-"wasteland",
-"watch",
-"watchdog",
-"watchman",
-"watchword",
-"water",
-"watercolor",
-"waterfront",
-"watermark",
-"watermelon",
-"waterproof",
-"waterway",
-"watt",
-"wave",
-"wavelength",
-"waver",
-"wax",
-"way",
-"waylay",
-"weak",
-"weaken",
-"wealth",
-"wean",
-"weapon",
-"wear",
-"weasel",
-"weather",
-"weaver",
-"web",
-"wed",
-"wedlock",
-"wednesday",
-"wee",
-"weed",
-"week",
-"weekday",
-"weekend",
-"weep",
-"weigh",
-"weight",
-"weird",
-"weirdo",
-"weld",
-"welder",
-"well",
-"welt",
-"welter",
-"werewolf",
-"west",
-"western",
-"wet",
-"whack",
-"whale",
-"whaler",
-"wharf",
-"what",
-"wheat",
-"wheel",
-"wheelbarrow",
-"wheelchair",
-"when",
-"where",
-"whet",
-"whiff",
-"while",
-"whim",
-"whimper",
-"whine",
-"whip",
-"whir",
-"whirl",
-"whirlpool",
-"whirlwind",
-"whirr",
-"whisk",
-"whisker",
-"whiskey",
-"whisper",
-"white",
-"whiten",
-"whitewash",
-"whiz",
-"whizz",
-"whole",
-"wholes",
-"whoop",
-"whopper",
-"whore",
-"wick",
-"wicked",
-"wicker",
-"wicket",
-"wide",
-"widen",
-"widow",
-"width",
-"wield",
-"wife",
-"wig",
-"wigwam",
-"wild",
-"wildcat",
-"wilder",
-"will",
-"willing",
-"willow",
-"wilt",
-"win",
-"winch",
-"wind",
-"window",
-"windscreen",
-"windshield",
-"wine",
-"wing",
-"wink",
-"winner",
-"winter",
-"wipe",
-"wiper",
-"wire",
-"wisdom",
-"wise",
-"wisecrack",
-"wish",
-"wisp",
-"wist",
-"wit",
-"witch",
-"witchcraft",
-"withdraw",
-"wither",
-"withhold",
-"within",
-"withstand",
-"wive",
-"wiz",
-"wizard",
-"woe",
-"wok",
-"wolf",
-"woman",
-"womanhood",
-"womankind",
-"womb",
-"wombat",
-"won",
-"wonder",
-"wonderland",
-"wont",
-"woo",
-"wood",
-"woodchuck",
-"wooden",
-"woodland",
-"woodsman",
-"woodwind",
-"woodwork",
-"woof",
-"wool",
-"woolen",
-"word",
-"work",
-"workbench",
-"workbook",
-"worker",
-"workman",
-"workmanship",
-"workout",
-"workshop",
-"world",
-"worm",
-"worsen",
-"worship",
-"worst",
-"worth",
-"would",
-"wound",
-"wow",
-"wrangler",
-"wrap",
-"wrapper",
-"wrath",
-"wreak",
-"wreath",
-"wreck",
-"wren",
-"wrench",
-"wrest",
-"wrestler",
-"wretch",
-"wretched",
-"wring",
-"wringer",
-"wrist",
-"wristwatch",
-"writ",
-"write",
-"writer",
-"wrong",
-"wrongdoer",
-"xmas",
-"yacht",
-"yack",
-"yak",
-"yam",
-"yank",
-"yap",
-"yard",
-"yardstick",
-"yarn",
-"yawn",
-"year",
-"yearn",
-"yeast",
-"yell",
-"yellow",
-"yelp",
-"yen",
-"yes",
-"yesterday",
-"yew",
-"yiddish",
-"yield",
-"yock",
-"yodel",
-"yoga",
-"yoghourt",
-"yoghurt",
-"yogurt",
-"yoke",
-"yokel",
-"yolk",
-"you",
-"young",
-"youngster",
-"your",
-"youth",
-"yowl",
-"yuck",
-"zeal",
-"zebra",
-"zenith",
-"zero",
-"zest",
-"zigzag",
-"zillion",
-"zinc",
-"zip",
-"zipper",
-"zodiac",
-"zombi",
-"zone",
-"zoo",
-"zoologist",
-"zoom",
-"zucchini",
+/// The RFC 4648 base32 alphabet.
+pub const BASE32: &[&str] = &[
+    "A","B","C","D","E","F","G","H","I","J","K","L","M","N","O","P","Q","R","S","T","U","V","W","X","Y","Z",
+    "2","3","4","5","6","7"
+];
+
+/// Douglas Crockford's base32 alphabet, which leaves out the easily-confused I, L, O and U. See the
+/// `crockford` module for check symbols and reading codes typed in by humans.
+pub const CROCKFORD_BASE32: &[&str] = &[
+    "0","1","2","3","4","5","6","7","8","9",
+    "A","B","C","D","E","F","G","H","J","K","M","N","P","Q","R","S","T","V","W","X","Y","Z"
+];
+
+/// The Bitcoin base58 alphabet, which leaves out 0, O, I and l.
+pub const BASE58: &[&str] = &[
+    "1","2","3","4","5","6","7","8","9",
+    "A","B","C","D","E","F","G","H","J","K","L","M","N","P","Q","R","S","T","U","V","W","X","Y","Z",
+    "a","b","c","d","e","f","g","h","i","j","k","m","n","o","p","q","r","s","t","u","v","w","x","y","z"
 ];
+
+/// The RFC 4648 URL- and filename-safe base64 alphabet.
+pub const BASE64URL: &[&str] = &[
+    "A","B","C","D","E","F","G","H","I","J","K","L","M","N","O","P","Q","R","S","T","U","V","W","X","Y","Z",
+    "a","b","c","d","e","f","g","h","i","j","k","l","m","n","o","p","q","r","s","t","u","v","w","x","y","z",
+    "0","1","2","3","4","5","6","7","8","9",
+    "-","_"
+];
+
+/// 256 visually distinct emoji, for codes that are compared by eye, such as device pairing codes.
+/// Each is a single code point that is drawn as an emoji by default (from Emoji 1.0 or earlier, so
+/// it's widely supported), and no two can combine into one grapheme when joined. Like the other
+/// non-ASCII charsets, a password's `len()` is in bytes; see `unicode::grapheme_length`.
+pub const EMOJI: &[&str] = &[
+    "🐒","🐕","🐺","🐈","🦁","🐅","🐎","🦄","🐄","🐖","🐐","🐫","🐘","🐁","🐇","🐻",
+    "🐼","🐾","🦃","🐓","🐦","🐧","🐸","🐊","🐢","🐍","🐉","🐳","🐬","🐟","🐙","🐚",
+    "🦀","🐌","🐛","🐜","🐝","🦂","💐","🌹","🌻","🌷","🌱","🌲","🌳","🌴","🌵","🍀",
+    "🍁","🍄","🍇","🍉","🍋","🍌","🍍","🍎","🍐","🍑","🍒","🍓","🍅","🍆","🌽","🍞",
+    "🧀","🍗","🍔","🍟","🍕","🌭","🌮","🌯","🍳","🍲","🍿","🍱","🍙","🍜","🍝","🍣",
+    "🍤","🍦","🍩","🍪","🎂","🍫","🍬","🍭","☕","🍾","🍷","🍸","🍹","🍺","🍴","🌍",
+    "🌋","🏠","🏥","🏫","🏭","🏰","🗽","⛪","⛲","⛺","🌅","🎠","🎡","🎢","🎪","🚂",
+    "🚌","🚑","🚒","🚓","🚕","🚗","🚙","🚚","🚜","🚲","⛽","🚦","🚧","⚓","⛵","🚤",
+    "🚢","🛫","🚁","🚀","⌛","⌚","⏰","🌑","🌕","🌙","🌞","⭐","🌠","🌌","🌀","🌈",
+    "🌂","☔","⚡","⛄","🔥","💧","🌊","🎃","🎄","🎆","✨","🎈","🎉","🎀","🎁","🎫",
+    "🏆","🏅","⚽","⚾","🏀","🏐","🏈","🎾","🎳","🏓","🏸","⛳","🎣","🎿","🎯","🎱",
+    "🔮","🎮","🎰","🎲","🃏","🎭","🎨","👓","👔","👕","👖","👗","👘","👙","👜","🎒",
+    "👟","👠","👑","🎩","🎓","💄","💍","💎","🔔","📢","🎵","🎤","🎧","📻","🎷","🎺",
+    "🎸","🎹","🎻","📱","📞","🔋","🔌","💻","💾","🎥","📺","📷","🔍","💡","🔦","🏮",
+    "📖","📚","📜","📰","🔖","💰","💵","💳","📦","📮","📝","💼","📁","📅","📈","📋",
+    "📌","📎","📏","🔒","🔑","🔨","🔧","🔗","🔬","🔭","📡","🚪","🚽","🚿","🛁","🗿"
+];
+
+/// The lowercase Greek alphabet, without the final sigma.
+pub const GREEK_LOWERCASE: &[&str] = &[
+    "α","β","γ","δ","ε","ζ","η","θ","ι","κ","λ","μ","ν","ξ","ο","π","ρ","σ","τ","υ","φ","χ","ψ","ω"
+];
+
+/// The 46 basic hiragana, without voicing marks or small kana.
+pub const HIRAGANA: &[&str] = &[
+    "あ","い","う","え","お","か","き","く","け","こ","さ","し","す","せ","そ","た","ち","つ","て","と",
+    "な","に","ぬ","ね","の","は","ひ","ふ","へ","ほ","ま","み","む","め","も","や","ゆ","よ",
+    "ら","り","る","れ","ろ","わ","を","ん"
+];
+
+// `WORDS`, the wordlist for passphrases, and `BIP39_ENGLISH`.
+include!(concat!(env!("OUT_DIR"), "/charsets.rs"));
+
+/// The built-in character sets, by the lowercase form of their constant names.
+pub const NAMED: &[(&str, &dyn Charset<'static>)] = &[
+    ("uppercase_hex", &UPPERCASE_HEX),
+    ("lowercase_hex", &LOWERCASE_HEX),
+    ("alphanumeric", &ALPHANUMERIC),
+    ("ascii", &ASCII),
+    ("decimal_digit", &DECIMAL_DIGIT),
+    ("lowercase_alphabetic", &LOWERCASE_ALPHABETIC),
+    ("base32", &BASE32),
+    ("crockford_base32", &CROCKFORD_BASE32),
+    ("base58", &BASE58),
+    ("base64url", &BASE64URL),
+    ("words", WORDS),
+    ("bip39_english", BIP39_ENGLISH),
+    ("emoji", &EMOJI),
+    ("greek_lowercase", &GREEK_LOWERCASE),
+    ("hiragana", &HIRAGANA),
+];
+
+/// Look up a built-in character set by name (see `NAMED`), ignoring case.
+///
+///     assert_eq!(passgenr::charsets::by_name("BASE58").unwrap().len(), 58);
+///     assert!(passgenr::charsets::by_name("klingon").is_none());
+pub fn by_name(name: &str) -> Option<&'static dyn Charset<'static>> {
+    NAMED.iter().find(|&&(n, _)| n.eq_ignore_ascii_case(name)).map(|&(_, charset)| charset)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packed_wordlists() {
+        let words = include_str!("../resources/wordlists/words.txt").lines().collect::<Vec<&str>>();
+        assert_eq!(WORDS.to_vec(), words);
+        assert_eq!(WORDS.iter().count(), WORDS.len());
+        assert_eq!(WORDS.get(WORDS.len() - 1), Some("zucchini"));
+        assert_eq!(WORDS.get(WORDS.len()), None);
+        assert_eq!(WORDS.binary_search("abacus"), Ok(0));
+        assert_eq!(WORDS.binary_search("zzz"), Err(WORDS.len()));
+        assert!(WORDS.contains("zoom"));
+        assert!(!WORDS.contains("zoomzoom"));
+        assert!(!WORDS.is_empty());
+    }
+
+    #[test]
+    fn only_wordlists_are_known_distinct() {
+        assert!(WORDS.is_known_distinct());
+        assert!((&BIP39_ENGLISH).is_known_distinct());
+        assert!(!ASCII.is_known_distinct());
+        assert!(!["a", "b"][..].is_known_distinct());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn element_out_of_range() {
+        WORDS.element(WORDS.len());
+    }
+}
@@ -0,0 +1,235 @@
+//! Contexts that passwords get pasted into, such as shell commands and URLs, where some of the
+//! characters of `charsets::ASCII` have a special meaning. There are two ways to deal with them:
+//! `Context::filter` leaves the unsafe characters out of a charset, so that every password can be
+//! pasted as it is, and `Context::escape` quotes or escapes a password that has them.
+//!
+//!     use passgenr::context::Context;
+//!
+//!     assert!(Context::Shell.is_safe('@'));
+//!     assert!(!Context::Shell.is_safe('$'));
+//!     assert!(!Context::Url.is_safe('@'));
+//!
+//! Only characters are checked, so a YAML value like `123` or `no` that is safe character by
+//! character can still be read as something other than a string. Escape passwords for YAML when
+//! the charset could produce one.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::fmt::Write;
+
+#[cfg(feature = "alloc")]
+use charsets::Charset;
+
+/// Somewhere a password is pasted into.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Context {
+    /// An argument in a POSIX shell command.
+    Shell,
+    /// A component of a URL, such as a query parameter.
+    Url,
+    /// A YAML scalar.
+    Yaml,
+    /// XML text or an attribute value.
+    Xml,
+    /// A JSON string.
+    Json,
+    /// A CSV field, which may also be opened in a spreadsheet.
+    Csv,
+}
+
+pub const CONTEXTS: &[Context] = &[
+    Context::Shell,
+    Context::Url,
+    Context::Yaml,
+    Context::Xml,
+    Context::Json,
+    Context::Csv,
+];
+
+impl Context {
+    /// The context with the given name, `shell`, `url`, `yaml`, `xml`, `json` or `csv`, ignoring
+    /// case.
+    pub fn from_name(name: &str) -> Option<Context> {
+        CONTEXTS.iter().cloned().find(|context| context.name().eq_ignore_ascii_case(name))
+    }
+
+    /// The name of the context, as accepted by `from_name`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Context::Shell => "shell",
+            Context::Url => "url",
+            Context::Yaml => "yaml",
+            Context::Xml => "xml",
+            Context::Json => "json",
+            Context::Csv => "csv",
+        }
+    }
+
+    /// The printable ASCII characters that are unsafe in this context.
+    fn unsafe_ascii(self) -> &'static str {
+        match self {
+            // quotes, expansions, globs, redirections and command separators
+            Context::Shell => "!\"#$&'()*;<>?[\\]^`{|}~",
+            // everything but RFC 3986's unreserved characters
+            Context::Url => "!\"#$%&'()*+,/:;<=>?@[\\]^`{|}",
+            // the indicators that can start a plain scalar, and ':' and '#', which end one
+            Context::Yaml => "!\"#%&'*,:>?@[]`{|}",
+            Context::Xml => "\"&'<>",
+            Context::Json => "\"\\",
+            // the separators of CSV, and the characters that start a spreadsheet formula
+            Context::Csv => "\",;=+-@",
+        }
+    }
+
+    /// Whether `c` can be pasted into this context as it is. Whitespace and control characters
+    /// never can, and other non-ASCII characters can anywhere except in URLs.
+    pub fn is_safe(self, c: char) -> bool {
+        if c.is_whitespace() || c.is_control() {
+            false
+        } else if c.is_ascii() {
+            !self.unsafe_ascii().contains(c)
+        } else {
+            self != Context::Url
+        }
+    }
+
+    /// The elements of `charset` that consist only of safe characters, in their original order.
+    #[cfg(feature = "alloc")]
+    pub fn filter<'a, C: Charset<'a> + ?Sized>(self, charset: &C) -> Vec<&'a str> {
+        charset.to_vec().into_iter().filter(|element| element.chars().all(|c| self.is_safe(c))).collect()
+    }
+
+    /// `password` rendered so that it can be pasted into this context and read back unchanged:
+    ///
+    /// - shell: in single quotes, with each `'` written as `'\''`
+    /// - url: percent-encoded, except for RFC 3986's unreserved characters
+    /// - yaml: a double-quoted scalar
+    /// - xml: with `&`, `<`, `>`, `"` and `'` as entity references, not quoted
+    /// - json: a JSON string, in double quotes
+    /// - csv: an RFC 4180 field in double quotes, with each `"` doubled
+    ///
+    /// Quoting doesn't stop a spreadsheet from evaluating a CSV field that starts with `=`, `+`,
+    /// `-` or `@` as a formula; filter those out for `Context::Csv` instead.
+    ///
+    ///     use passgenr::context::Context;
+    ///
+    ///     assert_eq!(Context::Shell.escape("it's"), "'it'\\''s'");
+    ///     assert_eq!(Context::Url.escape("a b/c"), "a%20b%2Fc");
+    ///     assert_eq!(Context::Json.escape("\"\\"), "\"\\\"\\\\\"");
+    #[cfg(feature = "alloc")]
+    pub fn escape(self, password: &str) -> String {
+        let mut escaped = String::with_capacity(password.len() + 2);
+        match self {
+            Context::Shell => {
+                escaped.push('\'');
+                escaped.push_str(&password.replace('\'', "'\\''"));
+                escaped.push('\'');
+            },
+            Context::Url => {
+                for &byte in password.as_bytes() {
+                    if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+                        escaped.push(char::from(byte));
+                    } else {
+                        write!(escaped, "%{:02X}", byte).unwrap();
+                    }
+                }
+            },
+            Context::Yaml | Context::Json => {
+                escaped.push('"');
+                for c in password.chars() {
+                    match c {
+                        '"' => escaped.push_str("\\\""),
+                        '\\' => escaped.push_str("\\\\"),
+                        c if c.is_control() => write!(escaped, "\\u{:04x}", u32::from(c)).unwrap(),
+                        c => escaped.push(c),
+                    }
+                }
+                escaped.push('"');
+            },
+            Context::Xml => {
+                for c in password.chars() {
+                    match c {
+                        '&' => escaped.push_str("&amp;"),
+                        '<' => escaped.push_str("&lt;"),
+                        '>' => escaped.push_str("&gt;"),
+                        '"' => escaped.push_str("&quot;"),
+                        '\'' => escaped.push_str("&apos;"),
+                        c => escaped.push(c),
+                    }
+                }
+            },
+            Context::Csv => {
+                escaped.push('"');
+                escaped.push_str(&password.replace('"', "\"\""));
+                escaped.push('"');
+            },
+        }
+        escaped
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use charsets;
+
+    #[test]
+    fn names() {
+        for &context in CONTEXTS {
+            assert_eq!(Context::from_name(context.name()), Some(context));
+        }
+        assert_eq!(Context::from_name("JSON"), Some(Context::Json));
+        assert_eq!(Context::from_name("sql"), None);
+    }
+
+    #[test]
+    fn filters() {
+        assert_eq!(Context::Shell.filter(charsets::ASCII).concat(), "%+,-./0123456789:=@ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefghijklmnopqrstuvwxyz");
+        assert_eq!(Context::Url.filter(charsets::ASCII).len(), 66);
+        assert_eq!(Context::Yaml.filter(charsets::ASCII).len(), 76);
+        assert_eq!(Context::Xml.filter(charsets::ASCII).len(), 89);
+        assert_eq!(Context::Json.filter(charsets::ASCII).len(), 92);
+        assert_eq!(Context::Csv.filter(charsets::ASCII).len(), 87);
+
+        for &context in CONTEXTS {
+            assert_eq!(context.filter(charsets::ALPHANUMERIC), charsets::ALPHANUMERIC.to_vec());
+            assert_eq!(context.filter(charsets::WORDS).len(), charsets::WORDS.len());
+            assert!(!context.is_safe(' '));
+            assert!(!context.is_safe('\n'));
+        }
+        assert_eq!(Context::Url.filter(&["schön", "schon"]), vec!["schon"]);
+        assert_eq!(Context::Shell.filter(&["schön", "it's"]), vec!["schön"]);
+    }
+
+    #[test]
+    fn escapes() {
+        let password = "a'b\"c\\d&e<f>g h,i=j\u{7}k€";
+        assert_eq!(Context::Shell.escape(password), "'a'\\''b\"c\\d&e<f>g h,i=j\u{7}k€'");
+        assert_eq!(Context::Url.escape(password), "a%27b%22c%5Cd%26e%3Cf%3Eg%20h%2Ci%3Dj%07k%E2%82%AC");
+        assert_eq!(Context::Yaml.escape(password), "\"a'b\\\"c\\\\d&e<f>g h,i=j\\u0007k€\"");
+        assert_eq!(Context::Json.escape(password), "\"a'b\\\"c\\\\d&e<f>g h,i=j\\u0007k€\"");
+        assert_eq!(Context::Xml.escape(password), "a&apos;b&quot;c\\d&amp;e&lt;f&gt;g h,i=j\u{7}k€");
+        assert_eq!(Context::Csv.escape(password), "\"a'b\"\"c\\d&e<f>g h,i=j\u{7}k€\"");
+        assert_eq!(Context::Shell.escape(""), "''");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn safe_passwords_need_no_escaping() {
+        for &context in CONTEXTS {
+            let password = ::random_password(&context.filter(charsets::ASCII), 200, "").unwrap();
+            let escaped = context.escape(&password);
+            match context {
+                Context::Url | Context::Xml => assert_eq!(escaped, password),
+                Context::Shell => assert_eq!(escaped, format!("'{}'", password)),
+                // a backslash is safe in a plain scalar, but an escape in a double-quoted one
+                Context::Yaml => assert_eq!(escaped, format!("\"{}\"", password.replace('\\', "\\\\"))),
+                _ => assert_eq!(escaped, format!("\"{}\"", password)),
+            }
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Check symbols and human-friendly formatting for codes made of `charsets::CROCKFORD_BASE32`
+//! characters, following <https://www.crockford.com/base32.html>.
+//!
+//!     # #[cfg(feature = "std")] {
+//!     use passgenr::{charsets, crockford, random_password};
+//!
+//!     let code = random_password(charsets::CROCKFORD_BASE32, 10, "").unwrap();
+//!     let printed = crockford::hyphenate(&crockford::append_check_symbol(&code), 4);
+//!     assert!(crockford::verify_check_symbol(&printed.to_lowercase()));
+//!     # }
+
+use alloc::string::String;
+
+/// The 32 encoding symbols followed by the 5 extra symbols that may only appear as a check symbol.
+const CHECK_SYMBOLS: &[u8; 37] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+
+/// Convert a code typed in by a human to its canonical form: hyphens are removed, letters are
+/// upper-cased, and the commonly-confused I and L are read as 1 and O as 0.
+///
+/// Returns `None` if the code contains anything other than Crockford base32 symbols and hyphens.
+pub fn normalize(code: &str) -> Option<String> {
+    code.chars()
+        .filter(|&c| c != '-')
+        .map(|c| normalize_symbol(c).map(char::from))
+        .collect()
+}
+
+/// Compute the check symbol for `code`, which is the value of `code` modulo 37.
+///
+/// Returns `None` if `code` is not valid Crockford base32 (see `normalize`).
+pub fn check_symbol(code: &str) -> Option<char> {
+    let mut remainder = 0;
+    for c in code.chars().filter(|&c| c != '-') {
+        let value = symbol_value(normalize_symbol(c)?)?;
+        remainder = (remainder * 32 + value) % 37;
+    }
+    Some(char::from(CHECK_SYMBOLS[remainder]))
+}
+
+/// Append the check symbol for `code` to it.
+///
+/// The call will panic if `code` is not valid Crockford base32.
+pub fn append_check_symbol(code: &str) -> String {
+    match check_symbol(code) {
+        Some(check) => alloc::format!("{}{}", code, check),
+        None => panic!("The code is not valid Crockford base32!"),
+    }
+}
+
+/// Check whether the last symbol of `code` is the correct check symbol for the rest of it. Case,
+/// hyphens and I/L/O substitutions are tolerated as in `normalize`.
+pub fn verify_check_symbol(code: &str) -> bool {
+    let code = code.trim_end_matches('-');
+    let last = match code.chars().last() {
+        Some(c) => c,
+        None => return false,
+    };
+    let body = &code[..code.len() - last.len_utf8()];
+    let last = match last.to_ascii_uppercase() {
+        'I' | 'L' => '1',
+        'O' => '0',
+        c => c,
+    };
+    check_symbol(body) == Some(last)
+}
+
+/// Insert a hyphen after every `group_size` characters of `code`.
+///
+/// The call will panic if `group_size` is zero.
+pub fn hyphenate(code: &str, group_size: usize) -> String {
+    if group_size == 0 {
+        panic!("The group size must be nonzero!");
+    }
+
+    let mut hyphenated = String::with_capacity(code.len() + code.len() / group_size);
+    for (i, c) in code.chars().enumerate() {
+        if i > 0 && i % group_size == 0 {
+            hyphenated.push('-');
+        }
+        hyphenated.push(c);
+    }
+    hyphenated
+}
+
+fn normalize_symbol(c: char) -> Option<u8> {
+    match c.to_ascii_uppercase() {
+        'I' | 'L' => Some(b'1'),
+        'O' => Some(b'0'),
+        'U' => None,
+        c @ '0'..='9' | c @ 'A'..='Z' => Some(c as u8),
+        _ => None,
+    }
+}
+
+fn symbol_value(symbol: u8) -> Option<usize> {
+    CHECK_SYMBOLS[..32].iter().position(|&s| s == symbol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::charsets;
+
+    #[test]
+    fn check_symbols_table_matches_charset() {
+        for (symbol, elt) in CHECK_SYMBOLS.iter().zip(charsets::CROCKFORD_BASE32.iter()) {
+            assert_eq!(char::from(*symbol).to_string(), *elt);
+        }
+    }
+
+    #[test]
+    fn known_check_symbols() {
+        assert_eq!(check_symbol(""), Some('0'));
+        assert_eq!(check_symbol("0"), Some('0'));
+        assert_eq!(check_symbol("Z"), Some('Z'));
+        // 32 = 32 mod 37, the first of the extra check symbols.
+        assert_eq!(check_symbol("10"), Some('*'));
+        // 36 = 36 mod 37, the last of the extra check symbols.
+        assert_eq!(check_symbol("14"), Some('U'));
+        // 37 = 0 mod 37.
+        assert_eq!(check_symbol("15"), Some('0'));
+        // 1234 = 13 mod 37.
+        assert_eq!(check_symbol("16J"), Some('D'));
+    }
+
+    #[test]
+    fn normalization() {
+        assert_eq!(normalize("abc-def"), Some("ABCDEF".to_owned()));
+        assert_eq!(normalize("IiLlOo"), Some("111100".to_owned()));
+        assert_eq!(normalize("ABU"), None);
+        assert_eq!(normalize("AB CD"), None);
+        assert_eq!(check_symbol("o1-i"), check_symbol("011"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn check_symbol_roundtrip() {
+        for _ in 0..100 {
+            let code = super::super::random_password(charsets::CROCKFORD_BASE32, 10, "").unwrap();
+            let checked = append_check_symbol(&code);
+            assert!(verify_check_symbol(&checked));
+            assert!(verify_check_symbol(&hyphenate(&checked, 5).to_lowercase()));
+        }
+    }
+
+    #[test]
+    fn check_symbol_detects_errors() {
+        assert!(verify_check_symbol("14U"));
+        assert!(verify_check_symbol("14u"));
+        assert!(!verify_check_symbol("15U"));
+        assert!(!verify_check_symbol("41U"));
+        assert!(!verify_check_symbol(""));
+        assert!(!verify_check_symbol("AB!0"));
+    }
+
+    #[test]
+    fn hyphenation() {
+        assert_eq!(hyphenate("", 4), "");
+        assert_eq!(hyphenate("ABCD", 4), "ABCD");
+        assert_eq!(hyphenate("ABCDE", 4), "ABCD-E");
+        assert_eq!(hyphenate("ABCDEFGH", 4), "ABCD-EFGH");
+    }
+
+    #[test]
+    #[should_panic(expected = "group size must be nonzero")]
+    fn panics_on_zero_group_size() {
+        let _ = hyphenate("ABCD", 0);
+    }
+}
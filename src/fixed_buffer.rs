@@ -0,0 +1,219 @@
+//! Password generation into a caller-supplied buffer, for environments without an allocator.
+//!
+//!     # extern crate passgenr;
+//!     # extern crate rand_core;
+//!     # fn main() {
+//!     # #[cfg(feature = "std")] {
+//!     use passgenr::{charsets, fixed_buffer};
+//!
+//!     let mut buffer = [0u8; 32];
+//!     let password = fixed_buffer::random_password(
+//!         &mut rand_core::OsRng, charsets::ALPHANUMERIC, 20, "", &mut buffer
+//!     ).unwrap();
+//!     assert_eq!(password.len(), 20);
+//!     # }
+//!     # }
+//!
+//! The passwords are the same as `random_password_with_rng` (with the `alloc` feature) would
+//! generate from the same RNG output.
+
+use core::fmt;
+use core::str;
+
+use super::rand_core::{self, CryptoRng, RngCore};
+
+use super::charsets::Charset;
+use super::is_unambiguous;
+use super::sampling;
+
+/// The reason `random_password` failed.
+#[derive(Debug)]
+pub enum FixedBufferError {
+    /// The password didn't fit in the buffer. See `max_password_length`.
+    BufferTooSmall,
+    /// The RNG failed.
+    Rng(rand_core::Error),
+}
+
+impl fmt::Display for FixedBufferError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FixedBufferError::BufferTooSmall => write!(f, "the password doesn't fit in the buffer"),
+            FixedBufferError::Rng(ref err) => write!(f, "the RNG failed: {}", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FixedBufferError {}
+
+impl From<rand_core::Error> for FixedBufferError {
+    fn from(err: rand_core::Error) -> FixedBufferError {
+        FixedBufferError::Rng(err)
+    }
+}
+
+/// The length in bytes of the longest password of `count` elements of `charset` separated by
+/// `separator`. A buffer of this length is always big enough for `random_password`.
+pub fn max_password_length<'a, C: Charset<'a> + ?Sized>(charset: &C, count: usize, separator: &str) -> usize {
+    let longest = (0..charset.len()).map(|i| charset.element(i).len()).max().unwrap_or(0);
+    longest * count + separator.len() * count.saturating_sub(1)
+}
+
+/// Like `random_password_with_rng`, but the password is written to the start of `buffer`, and the
+/// part of `buffer` holding it is returned.
+///
+/// If the password doesn't fit, `buffer` is zeroed and `FixedBufferError::BufferTooSmall` is
+/// returned. It is also zeroed if the RNG fails. The same panics as `random_password_with_rng` apply.
+pub fn random_password<'a, 'b, R: RngCore + CryptoRng, C: Charset<'a> + ?Sized>(
+    rng: &mut R,
+    charset: &C,
+    count: usize,
+    separator: &str,
+    buffer: &'b mut [u8]
+) -> Result<&'b str, FixedBufferError> {
+    if charset.len() < 2 {
+        panic!("The character set is too small (only 0 or 1 elements) to generate distinct passwords!");
+    }
+
+    if !elements_are_distinct(charset) {
+        panic!("The character set contained duplicate elements!");
+    }
+
+    if !is_unambiguous(charset, separator, None) {
+        panic!("The elements of the character set can't be joined with the separator unambiguously!");
+    }
+
+    let mut length = 0;
+    for i in 0..count {
+        let element = match sampling::uniform_index(rng, charset.len()) {
+            Ok(index) => charset.element(index),
+            Err(err) => {
+                zero(buffer);
+                return Err(FixedBufferError::Rng(err));
+            }
+        };
+        let written = if i == 0 {
+            append(buffer, length, element)
+        } else {
+            append(buffer, length, separator).and_then(|length| append(buffer, length, element))
+        };
+        length = match written {
+            Some(length) => length,
+            None => {
+                zero(buffer);
+                return Err(FixedBufferError::BufferTooSmall);
+            }
+        };
+    }
+
+    // Only whole `&str`s were copied, so this can't fail.
+    Ok(str::from_utf8(&buffer[..length]).unwrap())
+}
+
+/// Copy `s` to `buffer` at `offset`, returning the new end, or `None` if it doesn't fit.
+fn append(buffer: &mut [u8], offset: usize, s: &str) -> Option<usize> {
+    let end = offset.checked_add(s.len())?;
+    buffer.get_mut(offset..end)?.copy_from_slice(s.as_bytes());
+    Some(end)
+}
+
+/// Zero `buffer` in a way the compiler won't optimize out, so a partial password isn't left behind.
+fn zero(buffer: &mut [u8]) {
+    for byte in buffer.iter_mut() {
+        // Safe because `byte` is a valid, aligned `&mut u8`.
+        unsafe { core::ptr::write_volatile(byte, 0) };
+    }
+}
+
+/// Checking for duplicates without allocating is quadratic, so sorted charsets are checked in
+/// linear time instead, and the built-in wordlists aren't checked at all.
+fn elements_are_distinct<'a, C: Charset<'a> + ?Sized>(charset: &C) -> bool {
+    if charset.is_known_distinct() || (1..charset.len()).all(|i| charset.element(i - 1) < charset.element(i)) {
+        return true;
+    }
+    (0..charset.len()).all(|i| (i + 1..charset.len()).all(|j| charset.element(i) != charset.element(j)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::charsets;
+    #[cfg(feature = "alloc")]
+    use super::super::random_password_with_rng;
+    use super::super::rand_chacha::ChaCha20Rng;
+    use super::super::rand_core::SeedableRng;
+
+    fn rng() -> ChaCha20Rng {
+        ChaCha20Rng::from_seed([7u8; 32])
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn matches_allocating_version() {
+        let cases: &[(&dyn Charset<'static>, usize, &str)] = &[
+            (&charsets::ASCII, 64, ""),
+            (charsets::WORDS, 8, " "),
+            (charsets::BIP39_ENGLISH, 24, "-"),
+            (&charsets::DECIMAL_DIGIT, 1, "."),
+            (&charsets::DECIMAL_DIGIT, 0, "."),
+        ];
+        for &(charset, count, separator) in cases {
+            let mut buffer = [0u8; 512];
+            let password = random_password(&mut rng(), charset, count, separator, &mut buffer).unwrap();
+            assert_eq!(password, random_password_with_rng(&mut rng(), charset, count, separator).unwrap());
+            assert!(password.len() <= max_password_length(charset, count, separator));
+        }
+    }
+
+    #[test]
+    fn exact_fit() {
+        let mut buffer = [0xffu8; 10];
+        let password = random_password(&mut rng(), charsets::UPPERCASE_HEX, 10, "", &mut buffer).unwrap();
+        assert_eq!(password.len(), 10);
+    }
+
+    #[test]
+    fn too_small_buffer_is_zeroed() {
+        let mut buffer = [0xffu8; 10];
+        match random_password(&mut rng(), charsets::UPPERCASE_HEX, 6, "-", &mut buffer) {
+            Err(FixedBufferError::BufferTooSmall) => (),
+            other => panic!("Expected BufferTooSmall, got {:?}", other),
+        }
+        assert_eq!(buffer, [0u8; 10]);
+    }
+
+    #[test]
+    fn maximum_lengths() {
+        assert_eq!(max_password_length(charsets::ASCII, 20, ""), 20);
+        assert_eq!(max_password_length(&["a", "bcd"], 3, "--"), 13);
+        assert_eq!(max_password_length(charsets::ASCII, 0, "-"), 0);
+    }
+
+    #[test]
+    fn built_in_wordlists_are_sorted() {
+        // Otherwise `is_unambiguous` would take quadratic time with an empty separator.
+        for wordlist in [charsets::WORDS, charsets::BIP39_ENGLISH].iter() {
+            assert!(wordlist.iter().zip(wordlist.iter().skip(1)).all(|(a, b)| a < b));
+        }
+    }
+
+    #[test]
+    fn unsorted_duplicates_are_detected() {
+        assert!(elements_are_distinct(&["b", "a", "c"]));
+        assert!(!elements_are_distinct(&["b", "a", "b"]));
+        assert!(!elements_are_distinct(&["a", "a"]));
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate elements")]
+    fn panics_on_duplicates() {
+        let _ = random_password(&mut rng(), &["a", "b", "a"], 1, "", &mut [0u8; 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "joined with the separator unambiguously")]
+    fn panics_on_ambiguous_separators() {
+        let _ = random_password(&mut rng(), &["a", "ab", "bc", "c"], 2, "", &mut [0u8; 8]);
+    }
+}
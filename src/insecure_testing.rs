@@ -0,0 +1,58 @@
+//! An escape hatch for deterministic tests, enabled by the `insecure_testing` feature.
+//!
+//! The `_with_rng` functions only accept a `CryptoRng`, so that a fast, predictable RNG can't be
+//! used to generate real passwords by accident. Tests that need to replay a fixed byte stream or
+//! use some other non-cryptographic RNG can wrap it in `InsecureRng` to opt out of that check:
+//!
+//!     # extern crate rand_core;
+//!     # extern crate passgenr;
+//!     use passgenr::insecure_testing::InsecureRng;
+//!     use rand_core::impls;
+//!
+//!     struct Counter(u32);
+//!
+//!     impl rand_core::RngCore for Counter {
+//!         fn next_u32(&mut self) -> u32 { self.0 += 1; self.0 }
+//!         fn next_u64(&mut self) -> u64 { impls::next_u64_via_u32(self) }
+//!         fn fill_bytes(&mut self, dest: &mut [u8]) { impls::fill_bytes_via_next(self, dest) }
+//!         fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+//!             Ok(self.fill_bytes(dest))
+//!         }
+//!     }
+//!
+//!     # fn main() {
+//!     let mut rng = InsecureRng(Counter(0));
+//!     let mut buffer = [0u8; 4];
+//!     let password = passgenr::fixed_buffer::random_password(
+//!         &mut rng, passgenr::charsets::DECIMAL_DIGIT, 4, "", &mut buffer
+//!     );
+//!     assert_eq!(password.unwrap().len(), 4);
+//!     # }
+//!
+//! Never enable this feature in a build that generates real passwords.
+
+use super::rand_core::{CryptoRng, Error, RngCore};
+
+/// Wraps any RNG and declares it to be a `CryptoRng`, whether or not it is.
+#[derive(Clone, Debug)]
+pub struct InsecureRng<R: RngCore>(pub R);
+
+impl<R: RngCore> RngCore for InsecureRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+impl<R: RngCore> CryptoRng for InsecureRng<R> {}
@@ -0,0 +1,128 @@
+//! Keyboard layouts, for passwords that have to be typed by hand. On many keyboards some of the
+//! printable ASCII characters are dead keys, which combine with the next key pressed instead of
+//! typing themselves (`^` and `` ` `` on German and French keyboards), or are missing from the
+//! keys shown on a phone's default keyboard (`` ` `` on iOS).
+//!
+//!     use passgenr::keyboard::Layout;
+//!
+//!     let french = Layout::from_code("fr").unwrap();
+//!     assert!(french.is_typeable('é'));
+//!     assert!(!french.is_typeable('^'));
+//!
+//! `Layout::filter` keeps just the elements of a charset that can be typed directly, e.g.
+//! `Layout::German.filter(charsets::ASCII)` is ASCII without `^` and `` ` ``.
+//!
+//! Directly typeable means with one key, optionally with Shift or AltGr, and no dead keys or
+//! long presses. The layouts are the standard Windows ones, and the mobile layout is the default
+//! English keyboard of iOS, whose symbol pages are a subset of Android's.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use charsets::Charset;
+
+/// A keyboard layout.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Layout {
+    /// The US QWERTY layout.
+    Us,
+    /// The UK QWERTY layout.
+    Uk,
+    /// The German QWERTZ layout (T1).
+    German,
+    /// The French AZERTY layout.
+    French,
+    /// The default English keyboard of mobile phones.
+    Mobile,
+}
+
+pub const LAYOUTS: &[Layout] = &[Layout::Us, Layout::Uk, Layout::German, Layout::French, Layout::Mobile];
+
+impl Layout {
+    /// The layout with the given code, `us`, `uk`, `de`, `fr` or `mobile`, ignoring case.
+    pub fn from_code(code: &str) -> Option<Layout> {
+        LAYOUTS.iter().cloned().find(|layout| layout.code().eq_ignore_ascii_case(code))
+    }
+
+    /// The code of the layout, as accepted by `from_code`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Layout::Us => "us",
+            Layout::Uk => "uk",
+            Layout::German => "de",
+            Layout::French => "fr",
+            Layout::Mobile => "mobile",
+        }
+    }
+
+    /// The printable ASCII characters that can't be typed directly, and the non-ASCII characters
+    /// that can.
+    fn exceptions(self) -> (&'static str, &'static str) {
+        match self {
+            Layout::Us => ("", ""),
+            Layout::Uk => ("", "£¬¦€"),
+            Layout::German => ("^`", "äöüÄÖÜß§°€µ²³"),
+            Layout::French => ("^`~", "éèçàù§°£¤µ²€"),
+            Layout::Mobile => ("`", "€£¥•"),
+        }
+    }
+
+    /// Whether `c` can be typed directly on this layout.
+    pub fn is_typeable(self, c: char) -> bool {
+        let (missing, extra) = self.exceptions();
+        if c == ' ' || c.is_ascii_graphic() {
+            !missing.contains(c)
+        } else {
+            extra.contains(c)
+        }
+    }
+
+    /// The elements of `charset` that consist only of characters that can be typed directly, in
+    /// their original order.
+    #[cfg(feature = "alloc")]
+    pub fn filter<'a, C: Charset<'a> + ?Sized>(self, charset: &C) -> Vec<&'a str> {
+        charset.to_vec().into_iter().filter(|element| element.chars().all(|c| self.is_typeable(c))).collect()
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use charsets;
+
+    #[test]
+    fn codes() {
+        for &layout in LAYOUTS {
+            assert_eq!(Layout::from_code(layout.code()), Some(layout));
+        }
+        assert_eq!(Layout::from_code("DE"), Some(Layout::German));
+        assert_eq!(Layout::from_code("dvorak"), None);
+    }
+
+    #[test]
+    fn ascii_subsets() {
+        assert_eq!(Layout::Us.filter(charsets::ASCII), charsets::ASCII.to_vec());
+        assert_eq!(Layout::Uk.filter(charsets::ASCII), charsets::ASCII.to_vec());
+        assert_eq!(Layout::German.filter(charsets::ASCII).len(), 92);
+        assert_eq!(Layout::French.filter(charsets::ASCII).len(), 91);
+        assert_eq!(Layout::Mobile.filter(charsets::ASCII).len(), 93);
+        assert!(!Layout::French.filter(charsets::ASCII).contains(&"~"));
+
+        // letters and digits are on every keyboard
+        for &layout in LAYOUTS {
+            assert_eq!(layout.filter(charsets::ALPHANUMERIC), charsets::ALPHANUMERIC.to_vec());
+            assert_eq!(layout.filter(charsets::WORDS).len(), charsets::WORDS.len());
+            assert!(layout.filter(charsets::EMOJI).is_empty());
+        }
+    }
+
+    #[test]
+    fn non_ascii() {
+        assert_eq!(Layout::German.filter(&["schön", "naïve", "straße"]), vec!["schön", "straße"]);
+        assert_eq!(Layout::Us.filter(&["schön", "schon"]), vec!["schon"]);
+        assert!(Layout::Uk.is_typeable('£'));
+        assert!(!Layout::Us.is_typeable('£'));
+        assert!(!Layout::German.is_typeable('\n'));
+    }
+}
@@ -0,0 +1,118 @@
+//! Known-answer tests that pin the exact passwords generated from a fixed-seed RNG.
+//!
+//! The expected passwords are in `resources/known-answers.txt`. A failure here means the mapping
+//! from random numbers to password elements has changed, which changes every password generated
+//! from a given seed. If the change is deliberate, regenerate the table with
+//!
+//!     cargo test print_known_answers -- --ignored --nocapture
+//!
+//! and commit the new table together with the change that required it. Changes to the sampling
+//! algorithm itself must also increment `sampling::ALGORITHM_VERSION`.
+
+use super::rand_chacha::ChaCha20Rng;
+use super::rand_core::SeedableRng;
+
+use super::{charsets, random_password_with_rng, sampling};
+
+static KNOWN_ANSWERS: &str = include_str!("../resources/known-answers.txt");
+
+/// The (charset, count, separator, seed) cases in the table.
+static CASES: &[(&str, usize, &str, u32)] = &[
+    ("UPPERCASE_HEX", 32, "", 0),
+    ("LOWERCASE_HEX", 32, "", 1),
+    ("ALPHANUMERIC", 20, "", 2),
+    ("ASCII", 20, "", 3),
+    ("ASCII", 64, "", 0xffff_ffff),
+    ("DECIMAL_DIGIT", 12, "", 4),
+    ("LOWERCASE_ALPHABETIC", 16, "", 5),
+    ("BASE32", 26, "", 6),
+    ("CROCKFORD_BASE32", 10, "-", 7),
+    ("BASE58", 22, "", 8),
+    ("BASE64URL", 43, "", 9),
+    ("WORDS", 8, " ", 10),
+    ("WORDS", 6, "-", 11),
+    ("BIP39_ENGLISH", 12, " ", 12),
+    ("DECIMAL_DIGIT", 1, "", 13),
+    ("ALPHANUMERIC", 0, "", 14),
+];
+
+struct KnownAnswer<'a> {
+    charset: &'a str,
+    count: usize,
+    separator: &'a str,
+    seed: u32,
+    password: &'a str,
+}
+
+/// The 32-byte ChaCha20 seed: `seed` in little-endian followed by zeros.
+fn chacha_seed(seed: u32) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes[..4].copy_from_slice(&seed.to_le_bytes());
+    bytes
+}
+
+fn generate(charset: &str, count: usize, separator: &str, seed: u32) -> String {
+    let mut rng = ChaCha20Rng::from_seed(chacha_seed(seed));
+    let charset = charsets::by_name(charset).expect("Unknown charset in the known-answer table");
+    random_password_with_rng(&mut rng, charset, count, separator).unwrap()
+}
+
+/// Parse the tab-separated lines of the table. The separator is quoted so that empty and
+/// whitespace separators are visible.
+fn known_answers() -> Vec<KnownAnswer<'static>> {
+    KNOWN_ANSWERS.lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            assert_eq!(fields.len(), 5, "Malformed known-answer line: {}", line);
+            KnownAnswer {
+                charset: fields[0],
+                count: fields[1].parse().unwrap(),
+                separator: fields[2].trim_start_matches('"').trim_end_matches('"'),
+                seed: fields[3].parse().unwrap(),
+                password: fields[4],
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn known_answers_match() {
+    let answers = known_answers();
+    assert_eq!(answers.len(), CASES.len());
+    for answer in answers {
+        assert_eq!(
+            generate(answer.charset, answer.count, answer.separator, answer.seed),
+            answer.password,
+            "{} x{} with separator {:?} and seed {}",
+            answer.charset, answer.count, answer.separator, answer.seed
+        );
+    }
+}
+
+#[test]
+fn table_covers_all_cases() {
+    let answers = known_answers();
+    for (answer, &(charset, count, separator, seed)) in answers.iter().zip(CASES.iter()) {
+        assert_eq!((answer.charset, answer.count, answer.separator, answer.seed), (charset, count, separator, seed));
+    }
+}
+
+#[test]
+fn same_seed_same_password() {
+    assert_eq!(generate("ASCII", 32, "", 42), generate("ASCII", 32, "", 42));
+    assert_ne!(generate("ASCII", 32, "", 42), generate("ASCII", 32, "", 43));
+}
+
+#[test]
+#[ignore]
+fn print_known_answers() {
+    println!("# Passwords generated by random_password_with_rng from ChaCha20Rng, seeded with");
+    println!("# seed in little-endian followed by 28 zero bytes, and version {} of the sampling", sampling::ALGORITHM_VERSION);
+    println!("# algorithm.");
+    println!("# Regenerate with: cargo test print_known_answers -- --ignored --nocapture");
+    println!("# charset\tcount\tseparator\tseed\tpassword");
+    for &(charset, count, separator, seed) in CASES {
+        println!("{}\t{}\t\"{}\"\t{}\t{}", charset, count, separator, seed, generate(charset, count, separator, seed));
+    }
+}
@@ -0,0 +1,242 @@
+//! Wordlists for passphrases in languages other than English. Each one is behind a feature:
+//! `lang-de` (German), `lang-fr` (French), `lang-es` (Spanish) and `lang-it` (Italian), or
+//! `languages` for all of them. English is `charsets::WORDS` and is always available.
+//!
+//!     use passgenr::languages::Language;
+//!
+//!     let words = Language::from_code("en").unwrap().wordlist().unwrap();
+//!     let passphrase = passgenr::random_password(words, 6, " ").unwrap();
+//!     assert_eq!(passphrase.split(' ').count(), 6);
+//!
+//! The lists are in Unicode NFC form. Accented letters are awkward to type on some keyboards, so
+//! `normalize_wordlist` can also fold them to ASCII. Folding can merge words (German "schon" and
+//! "schön"), which would make some passphrases more likely than others, so the folded list is
+//! checked for duplicates. The built-in lists have none.
+//!
+//! The German list is derived from the German vocabulary of the Snowball project
+//! (<https://snowballstem.org/>, BSD license), keeping one word of 4 to 8 letters per stem. The
+//! French, Spanish and Italian lists are the BIP39 wordlists (MIT license). Their license notices
+//! are `LICENSE-snowball` and `LICENSE-bip39` in `resources/wordlists/languages/`, and must be
+//! included with binaries built with these features.
+
+use std::error;
+use std::fmt;
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use super::charsets::{self, Wordlist};
+use super::slice_contains_duplicates;
+
+/// The wordlists in `resources/wordlists/languages/`, generated by the build script. Only the ones
+/// whose features are enabled are exported.
+#[allow(dead_code)]
+mod packed {
+    use charsets::Wordlist;
+
+    include!(concat!(env!("OUT_DIR"), "/languages.rs"));
+}
+
+#[cfg(feature = "lang-fr")]
+pub use self::packed::FRENCH;
+#[cfg(feature = "lang-de")]
+pub use self::packed::GERMAN;
+#[cfg(feature = "lang-it")]
+pub use self::packed::ITALIAN;
+#[cfg(feature = "lang-es")]
+pub use self::packed::SPANISH;
+
+/// A language with a built-in wordlist, which may or may not be compiled in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+    French,
+    Spanish,
+    Italian,
+}
+
+pub const LANGUAGES: &[Language] = &[
+    Language::English,
+    Language::German,
+    Language::French,
+    Language::Spanish,
+    Language::Italian,
+];
+
+impl Language {
+    /// The language with the given ISO 639-1 code, e.g. `"de"`, ignoring case.
+    pub fn from_code(code: &str) -> Option<Language> {
+        LANGUAGES.iter().cloned().find(|language| language.code().eq_ignore_ascii_case(code))
+    }
+
+    /// The ISO 639-1 code of the language.
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::German => "de",
+            Language::French => "fr",
+            Language::Spanish => "es",
+            Language::Italian => "it",
+        }
+    }
+
+    /// The wordlist, or `None` if its feature isn't enabled.
+    pub fn wordlist(self) -> Option<&'static Wordlist> {
+        match self {
+            Language::English => Some(charsets::WORDS),
+            #[cfg(feature = "lang-de")]
+            Language::German => Some(GERMAN),
+            #[cfg(feature = "lang-fr")]
+            Language::French => Some(FRENCH),
+            #[cfg(feature = "lang-es")]
+            Language::Spanish => Some(SPANISH),
+            #[cfg(feature = "lang-it")]
+            Language::Italian => Some(ITALIAN),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+}
+
+/// The reason `normalize_wordlist` rejected a wordlist.
+#[derive(Clone, PartialEq, Debug)]
+pub enum WordlistError {
+    /// Two words are the same after normalization. This is the normalized word.
+    Duplicate(String),
+}
+
+impl fmt::Display for WordlistError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            WordlistError::Duplicate(ref word) => {
+                write!(f, "the word \"{}\" appears more than once after normalization", word)
+            },
+        }
+    }
+}
+
+impl error::Error for WordlistError {}
+
+/// Strip the diacritics from `word`: letters are decomposed and their combining marks removed,
+/// and ß, æ, œ, ø, ł and đ are replaced by ss, ae, oe, o, l and d. Characters that have no ASCII
+/// equivalent are left as they are.
+///
+///     use passgenr::languages::fold_to_ascii;
+///
+///     assert_eq!(fold_to_ascii("Straße"), "Strasse");
+///     assert_eq!(fold_to_ascii("cœur"), "coeur");
+///     assert_eq!(fold_to_ascii("niño"), "nino");
+pub fn fold_to_ascii(word: &str) -> String {
+    let mut folded = String::with_capacity(word.len());
+    for c in word.nfd().filter(|&c| !is_combining_mark(c)) {
+        match c {
+            'ß' => folded.push_str("ss"),
+            'æ' => folded.push_str("ae"),
+            'Æ' => folded.push_str("AE"),
+            'œ' => folded.push_str("oe"),
+            'Œ' => folded.push_str("OE"),
+            'ø' => folded.push('o'),
+            'Ø' => folded.push('O'),
+            'ł' => folded.push('l'),
+            'Ł' => folded.push('L'),
+            'đ' => folded.push('d'),
+            'Đ' => folded.push('D'),
+            c => folded.push(c),
+        }
+    }
+    folded
+}
+
+/// Convert every word of `words` to NFC, and with `fold_ascii`, apply `fold_to_ascii` too.
+/// Returns an error if two words end up the same.
+pub fn normalize_wordlist(words: &[&str], fold_ascii: bool) -> Result<Vec<String>, WordlistError> {
+    let normalized: Vec<String> = words.iter()
+        .map(|word| if fold_ascii { fold_to_ascii(word) } else { word.nfc().collect() })
+        .collect();
+
+    if slice_contains_duplicates(&normalized) {
+        let mut sorted = normalized.clone();
+        sorted.sort();
+        let duplicate = sorted.windows(2).find(|pair| pair[0] == pair[1]).unwrap();
+        return Err(WordlistError::Duplicate(duplicate[0].clone()));
+    }
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::charsets::Charset;
+
+    /// Every built-in list must give at least 11 bits of entropy per word.
+    const MIN_WORDLIST_SIZE: usize = 2048;
+
+    #[test]
+    fn wordlists_are_valid() {
+        for &language in LANGUAGES {
+            let words = match language.wordlist() {
+                Some(words) => words,
+                None => continue,
+            };
+            assert!(words.len() >= MIN_WORDLIST_SIZE, "{:?} has only {} words", language, words.len());
+            let words = words.to_vec();
+            assert!(!slice_contains_duplicates(&words), "{:?} has duplicates", language);
+            for word in &words {
+                assert!(!word.is_empty() && word.chars().all(char::is_alphabetic), "{:?}: {:?}", language, word);
+                assert_eq!(word.nfc().collect::<String>(), *word, "{:?}: {:?} isn't NFC", language, word);
+            }
+
+            let folded = normalize_wordlist(&words, true).unwrap();
+            assert!(folded.iter().all(|word| word.is_ascii()), "{:?} doesn't fold to ASCII", language);
+        }
+    }
+
+    #[test]
+    fn third_party_lists_have_notices() {
+        let snowball = include_str!("../resources/wordlists/languages/LICENSE-snowball");
+        assert!(snowball.contains("german.txt") && snowball.contains("Redistributions in binary form"));
+        let bip39 = include_str!("../resources/wordlists/languages/LICENSE-bip39");
+        for file in ["french.txt", "spanish.txt", "italian.txt"].iter() {
+            assert!(bip39.contains(file));
+        }
+        assert!(bip39.contains("The above copyright notice"));
+    }
+
+    #[test]
+    fn language_codes() {
+        for &language in LANGUAGES {
+            assert_eq!(Language::from_code(language.code()), Some(language));
+        }
+        assert_eq!(Language::from_code("DE"), Some(Language::German));
+        assert_eq!(Language::from_code("xx"), None);
+        assert_eq!(Language::default().wordlist(), Some(charsets::WORDS));
+    }
+
+    #[test]
+    fn folding() {
+        assert_eq!(fold_to_ascii("schön"), "schon");
+        assert_eq!(fold_to_ascii("ábaco"), "abaco");
+        assert_eq!(fold_to_ascii("e\u{301}cole"), "ecole");
+        assert_eq!(fold_to_ascii("Øresund"), "Oresund");
+        assert_eq!(fold_to_ascii("ascii"), "ascii");
+        assert_eq!(fold_to_ascii("日本"), "日本");
+    }
+
+    #[test]
+    fn normalization() {
+        assert_eq!(normalize_wordlist(&["e\u{301}cole", "été"], false).unwrap(), vec!["école", "été"]);
+        assert_eq!(normalize_wordlist(&["école", "été"], true).unwrap(), vec!["ecole", "ete"]);
+
+        assert_eq!(normalize_wordlist(&["schon", "schön"], false).unwrap(), vec!["schon", "schön"]);
+        assert_eq!(
+            normalize_wordlist(&["schön", "zug", "schon"], true),
+            Err(WordlistError::Duplicate("schon".to_owned()))
+        );
+        assert_eq!(
+            normalize_wordlist(&["école", "e\u{301}cole"], false),
+            Err(WordlistError::Duplicate("école".to_owned()))
+        );
+    }
+}
@@ -1,8 +1,81 @@
-extern crate rand;
+//! A library for generating cryptographically-secure passwords.
+//!
+//! Without the default `std` feature, the crate is `no_std`, and the RNG must be supplied by the
+//! caller. The `_with_rng` functions, `join_grouped` and `crockford` then need the `alloc`
+//! feature, while `charsets`, `sampling` and `fixed_buffer` need no allocator at all. The `analysis`,
+//! `bip39`, `languages`, `recovery`, `rules`, `spec` and `token` modules require `std`.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate core;
+extern crate rand_core;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "std")]
+extern crate sha2;
+#[cfg(feature = "std")]
+extern crate unicode_normalization;
+#[cfg(feature = "std")]
+extern crate unicode_segmentation;
+#[cfg(feature = "wasm")]
+extern crate wasm_bindgen;
+
+#[cfg(test)]
+extern crate rand_chacha;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+#[cfg(all(test, target_arch = "wasm32"))]
+extern crate wasm_bindgen_test;
+
+#[cfg(feature = "std")]
+pub mod analysis;
+#[cfg(feature = "std")]
+mod bigint;
+#[cfg(feature = "std")]
+pub mod bip39;
 pub mod charsets;
+pub mod context;
+#[cfg(feature = "alloc")]
+pub mod crockford;
+pub mod fixed_buffer;
+#[cfg(any(test, feature = "insecure_testing"))]
+pub mod insecure_testing;
+pub mod keyboard;
+#[cfg(feature = "std")]
+pub mod languages;
+#[cfg(feature = "std")]
+pub mod recovery;
+#[cfg(feature = "std")]
+pub mod rules;
+pub mod sampling;
+#[cfg(feature = "std")]
+pub mod spec;
+#[cfg(feature = "std")]
+pub mod token;
+#[cfg(feature = "std")]
+pub mod unicode;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(all(test, feature = "alloc"))]
+mod uniformity_tests;
+
+#[cfg(all(test, feature = "alloc"))]
+mod known_answer_tests;
 
-use self::rand::{OsRng, Rng};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use self::rand_core::{CryptoRng, RngCore};
+use charsets::Charset;
+#[cfg(feature = "std")]
+use self::rand_core::OsRng;
 
 /// Randomly generate a password made of `count` elements selected uniformly from `charset`,
 /// separated by `separator`.
@@ -12,33 +85,206 @@ use self::rand::{OsRng, Rng};
 ///         passgenr::random_password(passgenr::charsets::ASCII, 20, "").unwrap().len()
 ///     );
 ///
-/// The call will panic if `charset` contains fewer than two elements. The caller is responsible
-/// for ensuring that the elements of `charset` are distinct (this is not verified internally).
+/// The call will panic if `charset` contains fewer than two elements, if it contains duplicate
+/// elements, or if different sequences of elements could join to the same password (see
+/// `is_unambiguous`).
+///
+/// Common character sets (e.g. ASCII, ALPHANUMERIC) are available in the `charsets` module. Any
+/// `charsets::Charset` can be used, including slices, arrays and vectors of `&str`.
+#[cfg(feature = "std")]
+pub fn random_password<'a, C: Charset<'a> + ?Sized>(charset: &C, count: usize, separator: &str) -> Result<String,std::io::Error> {
+    Ok(random_password_with_rng(&mut OsRng, charset, count, separator)?)
+}
+
+/// Randomly select `count` elements uniformly from `charset`, without joining them.
+///
+/// This is useful for formatting passwords in ways `random_password` doesn't support, e.g. with
+/// `join_grouped`, after checking the separators with `is_unambiguous`. The same panics as
+/// `random_password` apply, except that there are no separators to check.
+#[cfg(feature = "std")]
+pub fn random_password_elements<'a, C: Charset<'a> + ?Sized>(charset: &C, count: usize) -> Result<Vec<&'a str>,std::io::Error> {
+    Ok(random_password_elements_with_rng(&mut OsRng, charset, count)?)
+}
+
+/// Like `random_password`, but with the randomness taken from `rng` instead of the operating
+/// system. Passwords are only as unpredictable as `rng`, which is why it must be a `CryptoRng`. To
+/// use a seeded non-cryptographic RNG in tests, see the `insecure_testing` feature.
+#[cfg(feature = "alloc")]
+pub fn random_password_with_rng<'a, R: RngCore + CryptoRng, C: Charset<'a> + ?Sized>(rng: &mut R, charset: &C, count: usize, separator: &str) -> Result<String, rand_core::Error> {
+    check_charset(charset);
+
+    if !is_unambiguous(charset, separator, None) {
+        panic!("The elements of the character set can't be joined with the separator unambiguously!");
+    }
+
+    Ok(select_elements(rng, charset, count)?.join(separator))
+}
+
+/// Like `random_password_elements`, but with the randomness taken from `rng`.
 ///
-/// Common character sets (e.g. ASCII, ALPHANUMERIC) are available in the `charsets` module.
-pub fn random_password(charset: &[&str], count: usize, separator: &str) -> Result<String,std::io::Error> {
-    Ok(random_password_elements(charset, count)?.join(separator))
+/// The elements are selected with the algorithm in the `sampling` module, so the same `rng` output
+/// always gives the same password for a given `sampling::ALGORITHM_VERSION`.
+#[cfg(feature = "alloc")]
+pub fn random_password_elements_with_rng<'a, R: RngCore + CryptoRng, C: Charset<'a> + ?Sized>(rng: &mut R, charset: &C, count: usize) -> Result<Vec<&'a str>, rand_core::Error> {
+    check_charset(charset);
+    select_elements(rng, charset, count)
 }
 
-fn random_password_elements<'a>(charset: &[&'a str], count: usize) -> Result<Vec<&'a str>,std::io::Error> {
+/// Panic if `charset` is too small or contains duplicates. This happens before any randomness is
+/// drawn, so a bad charset never uses up the caller's RNG output.
+#[cfg(feature = "alloc")]
+fn check_charset<'a, C: Charset<'a> + ?Sized>(charset: &C) {
     if charset.len() < 2 {
         panic!("The character set is too small (only 0 or 1 elements) to generate distinct passwords!");
     }
 
-    if slice_contains_duplicates(charset) {
+    if !charset.is_known_distinct() && slice_contains_duplicates(&charset.to_vec()) {
         panic!("The character set contained duplicate elements!");
     }
+}
 
+#[cfg(feature = "alloc")]
+fn select_elements<'a, R: RngCore + CryptoRng, C: Charset<'a> + ?Sized>(rng: &mut R, charset: &C, count: usize) -> Result<Vec<&'a str>, rand_core::Error> {
     let mut password_elts = Vec::<&str>::with_capacity(count);
-    let mut rng = OsRng::new()?;
 
     for _ in 0..count {
-        password_elts.push(rng.choose(charset).unwrap());
+        password_elts.push(charset.element(sampling::uniform_index(rng, charset.len())?));
     }
 
     Ok(password_elts)
 }
 
+/// Join password elements with `separator`, except that every `group_size` elements are joined with
+/// `group_separator` instead. This makes long passwords easier to transcribe.
+///
+///     let elements = ["A", "B", "C", "D", "E", "F"];
+///     assert_eq!(passgenr::join_grouped(&elements, "", 4, "-"), "ABCD-EF");
+///
+///     let words = ["correct", "horse", "battery", "staple"];
+///     assert_eq!(passgenr::join_grouped(&words, ".", 2, " "), "correct.horse battery.staple");
+///
+/// Group separators are inserted at fixed positions, so grouping doesn't change the entropy of
+/// the password (see `entropy_bits`), as long as `is_unambiguous` holds for both separators.
+///
+/// The call will panic if `group_size` is zero.
+#[cfg(feature = "alloc")]
+pub fn join_grouped(elements: &[&str], separator: &str, group_size: usize, group_separator: &str) -> String {
+    if group_size == 0 {
+        panic!("The group size must be nonzero!");
+    }
+
+    elements.chunks(group_size)
+        .map(|group| group.join(separator))
+        .collect::<Vec<String>>()
+        .join(group_separator)
+}
+
+/// The entropy, in bits, of a password made of `count` elements selected uniformly from
+/// `charset`, i.e. `count * log2(charset.len())`.
+///
+///     assert_eq!(passgenr::entropy_bits(passgenr::charsets::UPPERCASE_HEX, 64), 256.0);
+///
+/// This assumes that distinct sequences of elements produce distinct passwords, which
+/// `is_unambiguous` checks for a given separator. `random_password` and the other functions that
+/// join elements refuse separators that fail the check, so their passwords always have this much
+/// entropy.
+#[cfg(feature = "std")]
+pub fn entropy_bits<'a, C: Charset<'a> + ?Sized>(charset: &C, count: usize) -> f64 {
+    count as f64 * (charset.len() as f64).log2()
+}
+
+/// Whether passwords made by joining elements of `charset` with `separator`, except with
+/// `group.1` after every `group.0` elements (as `join_grouped` does), can only be split back into
+/// elements in one way. If not, different sequences of elements can give the same password, which
+/// is weaker than `entropy_bits` says.
+///
+///     use passgenr::{charsets, is_unambiguous};
+///
+///     assert!(is_unambiguous(charsets::WORDS, ".", Some((3, " "))));
+///     assert!(!is_unambiguous(charsets::WORDS, "", None));
+///     // "ab" + "c" == "a" + "bc"
+///     assert!(!is_unambiguous(&["a", "ab", "bc", "c"], "", None));
+///     // Single-character elements are at fixed positions, whatever the separators.
+///     assert!(is_unambiguous(charsets::ASCII, "", Some((4, "-"))));
+///
+/// The elements are assumed to be distinct, which the generation functions check separately.
+/// Joining is unambiguous if the elements all have the same length, or if every separator that
+/// can appear is either nonempty and always ends the element before it (e.g. a separator that
+/// doesn't occur in any element), or empty with no element being a prefix of another. Otherwise,
+/// with the `std` feature, the elements followed by each separator are tested with
+/// `analysis::is_uniquely_decodable`. Without it, the remaining cases are reported as ambiguous.
+pub fn is_unambiguous<'a, C: Charset<'a> + ?Sized>(charset: &C, separator: &str, group: Option<(usize, &str)>) -> bool {
+    // `separator` is never used when every group has one element.
+    let uses_separator = group.is_none_or(|(size, _)| size != 1);
+    let separators = [
+        if uses_separator { Some(separator) } else { None },
+        group.map(|(_, group_separator)| group_separator).filter(|&group_separator| !uses_separator || group_separator != separator),
+    ];
+    let separators = separators.iter().flatten().cloned();
+
+    if (1..charset.len()).all(|i| charset.element(i).len() == charset.element(0).len()) {
+        return true;
+    }
+    if separators.clone().all(|separator| ends_elements(charset, separator)) {
+        return true;
+    }
+    joins_are_uniquely_decodable(charset, separators)
+}
+
+/// Whether the first occurrence of `separator` after the start of an element is always right after
+/// the element, so that splitting at it finds the element. An empty separator is instead checked
+/// for no element being a prefix of another.
+fn ends_elements<'a, C: Charset<'a> + ?Sized>(charset: &C, separator: &str) -> bool {
+    if separator.is_empty() {
+        return is_prefix_free(charset);
+    }
+
+    let separator = separator.as_bytes();
+    (0..charset.len()).all(|i| {
+        let element = charset.element(i).as_bytes();
+        (0..element.len()).all(|start| {
+            // Whether `separator` occurs at `start` in `element` followed by `separator`.
+            let tail = &element[start..];
+            let found = if tail.len() >= separator.len() {
+                tail.starts_with(separator)
+            } else {
+                separator.starts_with(tail) && separator[tail.len()..] == separator[..separator.len() - tail.len()]
+            };
+            !found
+        })
+    })
+}
+
+/// Checking without allocating is quadratic, so sorted charsets, which include the large built-in
+/// wordlists, are checked in linear time instead: in sorted order, a word and a longer word that
+/// starts with it are only separated by other words that start with it.
+fn is_prefix_free<'a, C: Charset<'a> + ?Sized>(charset: &C) -> bool {
+    if (1..charset.len()).all(|i| charset.element(i - 1) < charset.element(i)) {
+        return (1..charset.len()).all(|i| !charset.element(i).starts_with(charset.element(i - 1)));
+    }
+    (0..charset.len()).all(|i| {
+        (0..charset.len()).all(|j| i == j || !charset.element(j).starts_with(charset.element(i)))
+    })
+}
+
+/// The exact test for the cases `is_unambiguous` can't decide cheaply: whether the elements
+/// followed by each of `separators` form a uniquely decodable code.
+#[cfg(feature = "std")]
+fn joins_are_uniquely_decodable<'a, 'b, C: Charset<'a> + ?Sized, I: Iterator<Item = &'b str>>(charset: &C, separators: I) -> bool {
+    let mut codewords = Vec::new();
+    for separator in separators {
+        codewords.extend((0..charset.len()).map(|i| format!("{}{}", charset.element(i), separator)));
+    }
+    let codewords: Vec<&str> = codewords.iter().map(String::as_str).collect();
+    analysis::is_uniquely_decodable(&codewords)
+}
+
+#[cfg(not(feature = "std"))]
+fn joins_are_uniquely_decodable<'a, 'b, C: Charset<'a> + ?Sized, I: Iterator<Item = &'b str>>(_charset: &C, _separators: I) -> bool {
+    false
+}
+
+#[cfg(feature = "alloc")]
 fn slice_contains_duplicates<T: Clone + Ord>(slice: &[T]) -> bool {
     let unique_elts : Vec<T> = {
         let mut elts = slice.to_vec();
@@ -49,18 +295,26 @@ fn slice_contains_duplicates<T: Clone + Ord>(slice: &[T]) -> bool {
     slice.len() != unique_elts.len()
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
-    static ALL_INCLUDED_CHARSETS: [&[&str]; 7] = [
-        charsets::UPPERCASE_HEX,
-        charsets::LOWERCASE_HEX,
-        charsets::ALPHANUMERIC,
-        charsets::ASCII,
-        charsets::DECIMAL_DIGIT,
-        charsets::LOWERCASE_ALPHABETIC,
+    const ALL_INCLUDED_CHARSETS: [&dyn Charset<'static>; 15] = [
+        &charsets::UPPERCASE_HEX,
+        &charsets::LOWERCASE_HEX,
+        &charsets::ALPHANUMERIC,
+        &charsets::ASCII,
+        &charsets::DECIMAL_DIGIT,
+        &charsets::LOWERCASE_ALPHABETIC,
+        &charsets::BASE32,
+        &charsets::CROCKFORD_BASE32,
+        &charsets::BASE58,
+        &charsets::BASE64URL,
         charsets::WORDS,
+        charsets::BIP39_ENGLISH,
+        &charsets::EMOJI,
+        &charsets::GREEK_LOWERCASE,
+        &charsets::HIRAGANA,
     ];
 
     #[test]
@@ -71,12 +325,30 @@ mod tests {
         assert_eq!(charsets::ASCII.len(), 94);
         assert_eq!(charsets::DECIMAL_DIGIT.len(), 10);
         assert_eq!(charsets::LOWERCASE_ALPHABETIC.len(), 26);
+        assert_eq!(charsets::BASE32.len(), 32);
+        assert_eq!(charsets::CROCKFORD_BASE32.len(), 32);
+        assert_eq!(charsets::BASE58.len(), 58);
+        assert_eq!(charsets::BASE64URL.len(), 64);
+        assert_eq!(charsets::EMOJI.len(), 256);
+        assert_eq!(charsets::GREEK_LOWERCASE.len(), 24);
+        assert_eq!(charsets::HIRAGANA.len(), 46);
+    }
+
+    #[test]
+    fn all_charsets_are_named() {
+        assert_eq!(charsets::NAMED.len(), ALL_INCLUDED_CHARSETS.len());
+        for (&(name, charset), included) in charsets::NAMED.iter().zip(ALL_INCLUDED_CHARSETS.iter()) {
+            assert_eq!(charset.to_vec(), included.to_vec());
+            assert_eq!(charsets::by_name(name).unwrap().to_vec(), charset.to_vec());
+            assert_eq!(charsets::by_name(&name.to_uppercase()).unwrap().to_vec(), charset.to_vec());
+        }
+        assert!(charsets::by_name("hex").is_none());
     }
 
     #[test]
     fn no_duplicates_in_charsets() {
         for charset in ALL_INCLUDED_CHARSETS.iter() {
-            assert!(!slice_contains_duplicates(charset));
+            assert!(!slice_contains_duplicates(&charset.to_vec()));
         }
     }
 
@@ -102,9 +374,10 @@ mod tests {
     #[test]
     fn no_chars_outside_requested_charset() {
         for charset in ALL_INCLUDED_CHARSETS.iter() {
+            let elements = charset.to_vec();
             let password_elts = random_password_elements(charset, charset.len() * 5).unwrap();
             for elt in password_elts {
-                assert!(charset.contains(&elt));
+                assert!(elements.contains(&elt));
             }
         }
     }
@@ -113,7 +386,7 @@ mod tests {
     fn all_characters_seen_in_output_eventually() {
         for charset in ALL_INCLUDED_CHARSETS.iter() {
             let password_elts = random_password_elements(charset, charset.len() * 100).unwrap();
-            for ch in charset.iter() {
+            for ch in charset.to_vec() {
                 assert!(password_elts.contains(&ch));
             }
         }
@@ -134,13 +407,45 @@ mod tests {
     #[test]
     #[should_panic(expected = "character set is too small")]
     fn panics_on_empty_character_set() {
-        let _ = random_password(&vec![], 2, "");
+        let _ = random_password(&[], 2, "");
     }
 
     #[test]
     #[should_panic(expected = "character set is too small")]
     fn panics_on_size_one_character_set() {
-        let _ = random_password(&vec!["a"], 2, "");
+        let _ = random_password(&["a"], 2, "");
+    }
+
+    #[test]
+    fn grouping_works() {
+        let elements = ["a", "b", "c", "d", "e"];
+        assert_eq!(join_grouped(&elements, "", 1, "-"), "a-b-c-d-e");
+        assert_eq!(join_grouped(&elements, "", 2, "-"), "ab-cd-e");
+        assert_eq!(join_grouped(&elements, ".", 2, " "), "a.b c.d e");
+        assert_eq!(join_grouped(&elements, "", 5, "-"), "abcde");
+        assert_eq!(join_grouped(&elements, "", 6, "-"), "abcde");
+        assert_eq!(join_grouped(&[], "", 4, "-"), "");
+
+        for len in 1..50 {
+            let elements = random_password_elements(charsets::UPPERCASE_HEX, len).unwrap();
+            let grouped = join_grouped(&elements, "", 4, "-");
+            assert_eq!(grouped.len(), len + (len - 1) / 4);
+            assert_eq!(grouped.replace("-", ""), elements.concat());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "group size must be nonzero")]
+    fn panics_on_zero_group_size() {
+        let _ = join_grouped(&["a", "b"], "", 0, "-");
+    }
+
+    #[test]
+    fn entropy() {
+        assert_eq!(entropy_bits(charsets::UPPERCASE_HEX, 0), 0.0);
+        assert_eq!(entropy_bits(charsets::UPPERCASE_HEX, 64), 256.0);
+        assert_eq!(entropy_bits(charsets::BASE32, 10), 50.0);
+        assert!((entropy_bits(charsets::ALPHANUMERIC, 30) - 178.6).abs() < 0.1);
     }
 
     #[test]
@@ -173,4 +478,66 @@ mod tests {
             _ => panic!("Separator is broken for length-3 passwords")
         };
     }
+
+    #[test]
+    fn ambiguous_separators() {
+        // Elements of the same length are always at the same positions.
+        assert!(is_unambiguous(&["ab", "ba"], "", None));
+        assert!(is_unambiguous(&["ab", "ba"], "b", Some((2, "a"))));
+
+        // A separator inside an element, or one that an element's end can start.
+        assert!(!is_unambiguous(&["a", "a.b", "b"], ".", None));
+        // "ba" + "aa" + "b" == "b" + "aa" + "ab"
+        assert!(!is_unambiguous(&["b", "ab", "ba"], "aa", None));
+        assert!(is_unambiguous(&["b", "ab", "ba"], "-", None));
+        assert!(is_unambiguous(&["a", "ab", "b"], "aa", None));
+
+        // Prefix-free elements need no separator, sorted or not.
+        assert!(is_unambiguous(&["0", "10", "110", "111"], "", None));
+        assert!(is_unambiguous(&["111", "0", "110", "10"], "", None));
+        // Not prefix-free, but uniquely decodable.
+        assert!(is_unambiguous(&["0", "01", "11"], "", None));
+
+        // Every separator that can appear is checked, including the group separator.
+        assert!(!is_unambiguous(charsets::WORDS, ".", Some((1, ""))));
+        assert!(!is_unambiguous(charsets::WORDS, ".", Some((3, ""))));
+        assert!(!is_unambiguous(charsets::WORDS, "", Some((3, " "))));
+        assert!(is_unambiguous(charsets::WORDS, "", Some((1, " "))));
+        assert!(is_unambiguous(charsets::WORDS, " ", Some((3, " "))));
+
+        for &(_, charset) in charsets::NAMED.iter() {
+            assert!(is_unambiguous(charset, " ", Some((4, "\n"))));
+        }
+        for &(_, charset) in charsets::NAMED.iter().filter(|&&(name, _)| name != "words" && name != "bip39_english") {
+            assert!(is_unambiguous(charset, "", None));
+        }
+    }
+
+    #[test]
+    fn bad_setups_panic_before_using_the_rng() {
+        use std::panic::{self, AssertUnwindSafe};
+        use insecure_testing::InsecureRng;
+        use rand_chacha::ChaCha20Rng;
+        use rand_core::SeedableRng;
+
+        let bad_setups: [(&dyn Charset<'static>, &str); 3] = [
+            (charsets::WORDS, ""),
+            (&["a", "b", "a"], "-"),
+            (&["a"], "-"),
+        ];
+        for &(charset, separator) in bad_setups.iter() {
+            let mut rng = InsecureRng(ChaCha20Rng::from_seed([7; 32]));
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                random_password_with_rng(&mut rng, charset, 8, separator)
+            }));
+            assert!(result.is_err());
+            assert_eq!(rng.next_u64(), InsecureRng(ChaCha20Rng::from_seed([7; 32])).next_u64());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "joined with the separator unambiguously")]
+    fn panics_on_ambiguous_separator() {
+        let _ = random_password(charsets::WORDS, 6, "");
+    }
 }
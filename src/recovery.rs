@@ -0,0 +1,229 @@
+//! Sets of single-use recovery codes, e.g. backup codes for two-factor authentication.
+//!
+//!     use passgenr::recovery::{recovery_codes, RecoveryCodeFormat};
+//!
+//!     let codes = recovery_codes(10, &RecoveryCodeFormat::default()).unwrap();
+//!     assert_eq!(codes.len(), 10);
+//!     assert_eq!(codes[0].len(), "xxxxx-xxxxx".len());
+//!
+//! The plaintext codes are shown to the user once. Only salted hashes of them (see
+//! `hash_recovery_code`) should be stored.
+
+use std::collections::HashSet;
+
+use super::rand_core::{OsRng, RngCore};
+use super::sha2::{Digest, Sha256};
+
+use super::charsets;
+use super::crockford;
+use super::random_password;
+
+/// The shape of each code in a set: `group_count` groups of `group_length` elements of `charset`,
+/// joined by `separator`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RecoveryCodeFormat<'a> {
+    pub charset: &'a [&'a str],
+    pub group_count: usize,
+    pub group_length: usize,
+    pub separator: &'a str,
+}
+
+impl<'a> Default for RecoveryCodeFormat<'a> {
+    /// Codes like `3WQ7F-K9D2M`: two groups of five `charsets::CROCKFORD_BASE32` characters, about 50
+    /// bits of entropy per code.
+    fn default() -> RecoveryCodeFormat<'a> {
+        RecoveryCodeFormat {
+            charset: charsets::CROCKFORD_BASE32,
+            group_count: 2,
+            group_length: 5,
+            separator: "-",
+        }
+    }
+}
+
+impl<'a> RecoveryCodeFormat<'a> {
+    /// Convert a code entered by a user to the form that is hashed: separators are removed, and
+    /// `crockford::normalize` is applied if the charset is `charsets::CROCKFORD_BASE32`.
+    pub fn canonicalize(&self, code: &str) -> String {
+        let code = if self.separator.is_empty() { code.to_owned() } else { code.replace(self.separator, "") };
+        if self.charset == charsets::CROCKFORD_BASE32 {
+            crockford::normalize(&code).unwrap_or(code)
+        } else {
+            code
+        }
+    }
+}
+
+/// The hash function used by `hash_recovery_code`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum RecoveryCodeHash {
+    /// SHA-256 over a random 16-byte salt followed by the canonical code. Recovery codes have enough
+    /// entropy that a fast hash is acceptable.
+    Sha256,
+}
+
+const SALT_LENGTH: usize = 16;
+
+/// Randomly generate `count` distinct recovery codes in the given `format`.
+///
+/// The call will panic if `format` has no elements per code, or allows fewer than `count`
+/// distinct codes.
+pub fn recovery_codes(count: usize, format: &RecoveryCodeFormat) -> Result<Vec<String>, std::io::Error> {
+    let elements_per_code = format.group_count * format.group_length;
+    if elements_per_code == 0 {
+        panic!("The recovery code format has no elements!");
+    }
+
+    let possible_codes = (format.charset.len() as u64).checked_pow(elements_per_code as u32);
+    if matches!(possible_codes, Some(n) if n < count as u64) {
+        panic!("The recovery code format allows fewer distinct codes than were requested!");
+    }
+
+    let mut codes = Vec::with_capacity(count);
+    let mut seen = HashSet::with_capacity(count);
+    while codes.len() < count {
+        let mut groups = Vec::with_capacity(format.group_count);
+        for _ in 0..format.group_count {
+            groups.push(random_password(format.charset, format.group_length, "")?);
+        }
+        let code = groups.join(format.separator);
+        // Discarding duplicates keeps the set uniformly distributed over all sets of distinct codes.
+        if seen.insert(code.clone()) {
+            codes.push(code);
+        }
+    }
+    Ok(codes)
+}
+
+/// Hash a recovery code for storage, returning `sha256$<salt>$<hash>` with the salt and hash in
+/// lowercase hex.
+pub fn hash_recovery_code(format: &RecoveryCodeFormat, code: &str, algorithm: RecoveryCodeHash) -> Result<String, std::io::Error> {
+    let mut salt = [0u8; SALT_LENGTH];
+    OsRng.try_fill_bytes(&mut salt)?;
+    let hash = match algorithm {
+        RecoveryCodeHash::Sha256 => salted_sha256(&salt, &format.canonicalize(code)),
+    };
+    Ok(format!("sha256${}${}", to_hex(&salt), to_hex(&hash)))
+}
+
+/// Check a code entered by a user against a hash produced by `hash_recovery_code`.
+pub fn verify_recovery_code(format: &RecoveryCodeFormat, code: &str, stored_hash: &str) -> bool {
+    let parts: Vec<&str> = stored_hash.split('$').collect();
+    if parts.len() != 3 || parts[0] != "sha256" {
+        return false;
+    }
+
+    let (salt, expected) = match (from_hex(parts[1]), from_hex(parts[2])) {
+        (Some(salt), Some(expected)) => (salt, expected),
+        _ => return false,
+    };
+
+    let actual = salted_sha256(&salt, &format.canonicalize(code));
+    if actual.len() != expected.len() {
+        return false;
+    }
+    // Compare without an early exit so the running time doesn't depend on where they differ.
+    actual.iter().zip(expected.iter()).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+fn salted_sha256(salt: &[u8], code: &str) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(salt);
+    hasher.update(code.as_bytes());
+    hasher.finalize().to_vec()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() & 1 != 0 || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_format() {
+        let format = RecoveryCodeFormat::default();
+        for code in recovery_codes(20, &format).unwrap() {
+            let groups: Vec<&str> = code.split('-').collect();
+            assert_eq!(groups.len(), 2);
+            for group in groups {
+                assert_eq!(group.len(), 5);
+                assert!(group.chars().all(|c| charsets::CROCKFORD_BASE32.contains(&c.to_string().as_str())));
+            }
+        }
+    }
+
+    #[test]
+    fn codes_are_unique() {
+        // Only 16 possible codes, so duplicates are certain to be generated and discarded.
+        let format = RecoveryCodeFormat { charset: &["0", "1"], group_count: 2, group_length: 2, separator: " " };
+        let mut codes = recovery_codes(16, &format).unwrap();
+        codes.sort();
+        codes.dedup();
+        assert_eq!(codes.len(), 16);
+    }
+
+    #[test]
+    #[should_panic(expected = "fewer distinct codes")]
+    fn panics_on_too_many_codes() {
+        let format = RecoveryCodeFormat { charset: &["0", "1"], group_count: 2, group_length: 2, separator: " " };
+        let _ = recovery_codes(17, &format);
+    }
+
+    #[test]
+    #[should_panic(expected = "no elements")]
+    fn panics_on_empty_format() {
+        let format = RecoveryCodeFormat { group_count: 0, ..RecoveryCodeFormat::default() };
+        let _ = recovery_codes(1, &format);
+    }
+
+    #[test]
+    fn hashes_verify() {
+        let format = RecoveryCodeFormat::default();
+        let codes = recovery_codes(5, &format).unwrap();
+        for code in codes.iter() {
+            let stored = hash_recovery_code(&format, code, RecoveryCodeHash::Sha256).unwrap();
+            assert!(stored.starts_with("sha256$"));
+            assert!(verify_recovery_code(&format, code, &stored));
+            // Users may type codes without the separator, in lowercase, or with O for 0.
+            let typed = code.replace("-", "").to_lowercase().replace("0", "o");
+            assert!(verify_recovery_code(&format, &typed, &stored));
+        }
+        let stored = hash_recovery_code(&format, &codes[0], RecoveryCodeHash::Sha256).unwrap();
+        assert!(!verify_recovery_code(&format, &codes[1], &stored));
+    }
+
+    #[test]
+    fn hashes_are_salted() {
+        let format = RecoveryCodeFormat::default();
+        let first = hash_recovery_code(&format, "AAAAA-AAAAA", RecoveryCodeHash::Sha256).unwrap();
+        let second = hash_recovery_code(&format, "AAAAA-AAAAA", RecoveryCodeHash::Sha256).unwrap();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn known_hash() {
+        let stored = "sha256$0123456789abcdef0123456789abcdef$\
+                      ecfc9185fa2da46c39952ac24703a23f6e624b9cd83228e205891eb8c3d7c492";
+        assert!(verify_recovery_code(&RecoveryCodeFormat::default(), "aaaaa-aaaaa", stored));
+        assert!(!verify_recovery_code(&RecoveryCodeFormat::default(), "aaaaa-aaaab", stored));
+    }
+
+    #[test]
+    fn malformed_hashes_do_not_verify() {
+        let format = RecoveryCodeFormat::default();
+        assert!(!verify_recovery_code(&format, "AAAAA-AAAAA", ""));
+        assert!(!verify_recovery_code(&format, "AAAAA-AAAAA", "md5$00$00"));
+        assert!(!verify_recovery_code(&format, "AAAAA-AAAAA", "sha256$0g$00"));
+        assert!(!verify_recovery_code(&format, "AAAAA-AAAAA", "sha256$00$000"));
+        assert!(!verify_recovery_code(&format, "AAAAA-AAAAA", "sha256$00$00"));
+    }
+}
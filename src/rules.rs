@@ -0,0 +1,651 @@
+//! Password policies written in the `passwordrules` syntax that websites publish to describe
+//! which passwords they accept, e.g. `minlength: 12; required: upper; required: digit; allowed:
+//! [-_];`. See <https://developer.apple.com/password-rules/> for the format.
+//!
+//!     use passgenr::rules::PasswordPolicy;
+//!
+//!     let policy = PasswordPolicy::parse("minlength: 12; maxlength: 16; required: upper; required: digit; allowed: lower, [-_];").unwrap();
+//!     let length = policy.clamp_length(64);
+//!     assert_eq!(length, 16);
+//!
+//!     let password = policy.random_password(length).unwrap();
+//!     assert_eq!(password.len(), 16);
+//!     assert!(password.chars().any(|c| c.is_ascii_uppercase()));
+//!     assert!(password.chars().any(|c| c.is_ascii_digit()));
+//!     println!("{} ({:.1} bits)", password, policy.entropy_bits(length));
+//!
+//! The compliant passwords of a length are counted exactly, and passwords are built one character
+//! at a time, each chosen with probability proportional to the number of compliant passwords that
+//! start with the characters so far. Every compliant password is equally likely, `entropy_bits` is
+//! exact, and generation takes the same time however few passwords are compliant.
+
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+
+use super::bigint::BigUint;
+use super::charsets;
+use super::rand_core::{CryptoRng, OsRng, RngCore};
+
+/// The `special` character class: printable ASCII that isn't a letter or digit. Unlike Apple's
+/// definition, this leaves out the space character, which is too easily lost in transcription.
+const SPECIAL: &str = "-~!@#$%^&*_+=`|(){}[:;\"'<>,.?]/\\";
+
+/// The maximum number of `required` properties, to bound the cost of computing the entropy.
+const MAX_REQUIRED: usize = 16;
+
+/// The reason a `passwordrules` string was rejected by `PasswordPolicy::parse`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum RulesError {
+    /// A property other than `required`, `allowed`, `max-consecutive`, `minlength` or `maxlength`.
+    UnknownProperty(String),
+    /// A character class other than `upper`, `lower`, `digit`, `special`, `ascii-printable`,
+    /// `unicode` or a custom `[...]` class.
+    UnknownClass(String),
+    /// A property that should be a number isn't one.
+    InvalidNumber(String),
+    /// Anything else that doesn't follow the syntax.
+    Syntax(String),
+    /// The rules can't all be satisfied at once, e.g. `maxlength` is less than `minlength`.
+    Unsatisfiable,
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RulesError::UnknownProperty(ref name) => write!(f, "unknown property \"{}\"", name),
+            RulesError::UnknownClass(ref name) => write!(f, "unknown character class \"{}\"", name),
+            RulesError::InvalidNumber(ref value) => write!(f, "\"{}\" is not a valid number", value),
+            RulesError::Syntax(ref message) => write!(f, "{}", message),
+            RulesError::Unsatisfiable => write!(f, "no password can satisfy all of the rules"),
+        }
+    }
+}
+
+impl error::Error for RulesError {}
+
+/// A set of rules that generated passwords must satisfy.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PasswordPolicy {
+    /// Every character the password may contain, in ASCII order.
+    pub charset: Vec<String>,
+    /// The password must contain at least one character from each of these sets.
+    pub required: Vec<Vec<String>>,
+    /// The maximum number of times the same character may appear consecutively.
+    pub max_consecutive: Option<usize>,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+}
+
+impl PasswordPolicy {
+    /// Parse a `passwordrules` string. If it has no `required` or `allowed` properties, all of
+    /// `charsets::ASCII` is allowed.
+    pub fn parse(rules: &str) -> Result<PasswordPolicy, RulesError> {
+        let mut allowed = Vec::new();
+        let mut required = Vec::new();
+        let mut max_consecutive: Option<usize> = None;
+        let mut min_length: Option<usize> = None;
+        let mut max_length: Option<usize> = None;
+
+        for property in split_properties(rules)? {
+            let (name, value) = match property.find(':') {
+                Some(i) => (property[..i].trim().to_ascii_lowercase(), property[i + 1..].trim()),
+                None => return Err(RulesError::Syntax(format!("expected \"name: value\" in \"{}\"", property))),
+            };
+
+            match name.as_str() {
+                "required" => required.push(parse_classes(value)?),
+                "allowed" => allowed.extend(parse_classes(value)?),
+                // Repeated numeric properties are combined into the strictest one.
+                "max-consecutive" => {
+                    let n = parse_number(value)?;
+                    max_consecutive = Some(max_consecutive.map_or(n, |m| m.min(n)));
+                },
+                "minlength" => {
+                    let n = parse_number(value)?;
+                    min_length = Some(min_length.map_or(n, |m| m.max(n)));
+                },
+                "maxlength" => {
+                    let n = parse_number(value)?;
+                    max_length = Some(max_length.map_or(n, |m| m.min(n)));
+                },
+                _ => return Err(RulesError::UnknownProperty(name)),
+            }
+        }
+
+        if required.len() > MAX_REQUIRED {
+            return Err(RulesError::Syntax(format!("at most {} required properties are supported", MAX_REQUIRED)));
+        }
+
+        let mut charset: Vec<char> = allowed;
+        for set in required.iter() {
+            charset.extend(set.iter().cloned());
+        }
+        if charset.is_empty() {
+            charset = charsets::ASCII.iter().flat_map(|elt| elt.chars()).collect();
+        }
+        charset.sort();
+        charset.dedup();
+
+        let policy = PasswordPolicy {
+            charset: charset.iter().map(|c| c.to_string()).collect(),
+            required: required.into_iter().map(|set| set.iter().map(|c| c.to_string()).collect()).collect(),
+            max_consecutive,
+            min_length,
+            max_length,
+        };
+
+        if policy.charset.len() < 2
+            || policy.max_consecutive == Some(0)
+            || matches!((min_length, max_length), (Some(min), Some(max)) if min > max) {
+            return Err(RulesError::Unsatisfiable);
+        }
+
+        Ok(policy)
+    }
+
+    /// The length closest to `length` that `min_length` and `max_length` allow.
+    pub fn clamp_length(&self, length: usize) -> usize {
+        let length = length.max(self.min_length.unwrap_or(0));
+        length.min(self.max_length.unwrap_or(usize::MAX))
+    }
+
+    /// Whether `password` satisfies all of the rules.
+    pub fn is_compliant(&self, password: &[&str]) -> bool {
+        if password.len() != self.clamp_length(password.len()) {
+            return false;
+        }
+
+        if !password.iter().all(|elt| self.charset.iter().any(|c| c == elt)) {
+            return false;
+        }
+
+        if !self.required.iter().all(|set| password.iter().any(|elt| set.iter().any(|c| c == elt))) {
+            return false;
+        }
+
+        match self.max_consecutive {
+            Some(max) => longest_run(password) <= max,
+            None => true,
+        }
+    }
+
+    /// The entropy, in bits, of a password of `length` characters generated by
+    /// `random_password`. This is `log2` of the number of compliant passwords, and is negative
+    /// infinity if there are none.
+    pub fn entropy_bits(&self, length: usize) -> f64 {
+        if length != self.clamp_length(length) {
+            return f64::NEG_INFINITY;
+        }
+        Counter::new(self, length).compliant_count().log2()
+    }
+
+    /// Randomly generate a compliant password of `length` characters.
+    ///
+    /// The call will panic if no password of that length is compliant (see `entropy_bits`).
+    pub fn random_password(&self, length: usize) -> Result<String, std::io::Error> {
+        Ok(self.random_password_elements(length)?.concat())
+    }
+
+    /// Like `random_password`, but without joining the characters, e.g. for `join_grouped`.
+    pub fn random_password_elements(&self, length: usize) -> Result<Vec<&str>, std::io::Error> {
+        Ok(self.random_password_elements_with_rng(&mut OsRng, length)?)
+    }
+
+    /// Like `random_password_elements`, but with the randomness taken from `rng`.
+    pub fn random_password_elements_with_rng<R: RngCore + CryptoRng>(&self, rng: &mut R, length: usize) -> Result<Vec<&str>, rand_core::Error> {
+        let mut counter = Counter::new(self, length);
+        if length != self.clamp_length(length) || counter.compliant_count().is_zero() {
+            panic!("No password of the requested length satisfies the rules!");
+        }
+
+        let mut password = Vec::with_capacity(length);
+        let mut state = State { unsatisfied: (1 << self.required.len()) - 1, last: None, run: 0 };
+        for position in 0..length {
+            let remaining = length - position - 1;
+            // the count only depends on the unsatisfied sets and the run, which most characters share
+            let mut counts = HashMap::new();
+            let choices: Vec<(State, BigUint)> = (0..self.charset.len())
+                .map(|c| {
+                    let next = state.then(c, counter.membership[c]);
+                    let count = counts.entry((next.unsatisfied, next.run))
+                        .or_insert_with(|| counter.completions(&next, remaining))
+                        .clone();
+                    (next, count)
+                })
+                .collect();
+
+            let total = choices.iter().fold(BigUint::zero(), |total, (_, count)| total.add(count));
+            let mut choice = total.random_below(rng)?;
+            for (next, count) in choices {
+                if choice < count {
+                    password.push(self.charset[next.last.unwrap()].as_str());
+                    state = next;
+                    break;
+                }
+                choice = choice.sub(&count);
+            }
+        }
+        Ok(password)
+    }
+}
+
+/// What matters about a prefix of a password for counting its compliant completions.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    /// The `required` sets, as bits, that the prefix has no character from.
+    unsatisfied: u32,
+    /// The index in `charset` of the last character.
+    last: Option<usize>,
+    /// How many times the last character is repeated at the end of the prefix.
+    run: usize,
+}
+
+impl State {
+    /// The state after appending the character with index `c`, which is in the `required` sets
+    /// `membership`.
+    fn then(self, c: usize, membership: u32) -> State {
+        State {
+            unsatisfied: self.unsatisfied & !membership,
+            last: Some(c),
+            run: if self.last == Some(c) { self.run + 1 } else { 1 },
+        }
+    }
+}
+
+/// Counts compliant passwords of one length exactly.
+///
+/// By inclusion-exclusion over the `required` sets, the number of compliant ways to finish a
+/// prefix is the sum, over every subset S of the sets it has no character from, of (-1)^|S| times
+/// the number of ways that avoid every set in S and have no overlong runs. That only depends on how
+/// many characters are left once S is avoided, so the terms are collected by alphabet size, and
+/// the run-limited counts for each size are computed once.
+struct Counter<'p> {
+    policy: &'p PasswordPolicy,
+    length: usize,
+    /// The longest run allowed, which is at most `length`.
+    max_run: usize,
+    /// For each character in `charset`, the `required` sets it's in, as bits.
+    membership: Vec<u32>,
+    /// For each subset of the `required` sets, the inclusion-exclusion coefficient of each
+    /// alphabet size.
+    coefficients: HashMap<u32, Vec<(usize, i64)>>,
+    /// The sums for `runs_after` of each alphabet size.
+    sums: HashMap<usize, Vec<BigUint>>,
+}
+
+impl<'p> Counter<'p> {
+    fn new(policy: &'p PasswordPolicy, length: usize) -> Counter<'p> {
+        let membership = policy.charset.iter().map(|c| {
+            policy.required.iter().enumerate()
+                .filter(|&(_, set)| set.contains(c))
+                .fold(0, |bits, (i, _)| bits | 1 << i)
+        }).collect();
+        Counter {
+            policy,
+            length,
+            max_run: policy.max_consecutive.unwrap_or(length).min(length).max(1),
+            membership,
+            coefficients: HashMap::new(),
+            sums: HashMap::new(),
+        }
+    }
+
+    /// The number of compliant passwords.
+    fn compliant_count(&mut self) -> BigUint {
+        if self.length == 0 {
+            return BigUint::from_u32(if self.policy.required.is_empty() { 1 } else { 0 });
+        }
+        let all = (1 << self.policy.required.len()) - 1;
+        let length = self.length;
+        self.combine(all, |counter, size| counter.runs_after(size, length - 1, 1).mul_u32(size as u32))
+    }
+
+    /// The number of compliant ways to add `remaining` characters after a prefix in `state`.
+    fn completions(&mut self, state: &State, remaining: usize) -> BigUint {
+        if state.run > self.max_run {
+            return BigUint::zero();
+        }
+        let run = state.run;
+        self.combine(state.unsatisfied, |counter, size| counter.runs_after(size, remaining, run))
+    }
+
+    /// The sum, over every subset S of `unsatisfied`, of (-1)^|S| times `count` of the number of
+    /// characters outside the sets in S.
+    fn combine<F: FnMut(&mut Self, usize) -> BigUint>(&mut self, unsatisfied: u32, mut count: F) -> BigUint {
+        let coefficients = self.coefficients(unsatisfied);
+        let mut positive = BigUint::zero();
+        let mut negative = BigUint::zero();
+        for (size, coefficient) in coefficients {
+            let term = count(self, size).mul_u32(coefficient.unsigned_abs() as u32);
+            if coefficient > 0 {
+                positive = positive.add(&term);
+            } else {
+                negative = negative.add(&term);
+            }
+        }
+        positive.sub(&negative)
+    }
+
+    fn coefficients(&mut self, unsatisfied: u32) -> Vec<(usize, i64)> {
+        if let Some(coefficients) = self.coefficients.get(&unsatisfied) {
+            return coefficients.clone();
+        }
+
+        let mut by_size = vec![0i64; self.policy.charset.len() + 1];
+        let mut subset = unsatisfied;
+        loop {
+            let size = self.membership.iter().filter(|&&sets| sets & subset == 0).count();
+            by_size[size] += if subset.count_ones().is_multiple_of(2) { 1 } else { -1 };
+            if subset == 0 {
+                break;
+            }
+            subset = (subset - 1) & unsatisfied;
+        }
+
+        // Without characters, nothing can follow a prefix, and there's no first character.
+        let coefficients: Vec<(usize, i64)> = by_size.into_iter().enumerate()
+            .filter(|&(size, coefficient)| size > 0 && coefficient != 0)
+            .collect();
+        self.coefficients.insert(unsatisfied, coefficients.clone());
+        coefficients
+    }
+
+    /// The number of ways to add `remaining` characters from an alphabet of `size`, after a run of
+    /// `run` copies of one of them, with no run longer than `max_run`.
+    fn runs_after(&mut self, size: usize, remaining: usize, run: usize) -> BigUint {
+        let max_run = self.max_run;
+        runs_after(self.sums(size), size, max_run, remaining, run)
+    }
+
+    /// For an alphabet of `size`, the sums, for each i, of `runs_after(size, m, 1)` for `m < i`.
+    fn sums(&mut self, size: usize) -> &[BigUint] {
+        let (length, max_run) = (self.length, self.max_run);
+        self.sums.entry(size).or_insert_with(|| {
+            // `runs_after` for `m` characters only needs the sums up to `m`, so they're built in order
+            let mut sums = vec![BigUint::zero()];
+            for m in 0..length {
+                let total = sums[m].add(&runs_after(&sums, size, max_run, m, 1));
+                sums.push(total);
+            }
+            sums
+        })
+    }
+}
+
+/// `Counter::runs_after`, given at least the first `remaining + 1` of its `sums`. Each way
+/// repeats the last character j more times and then either ends or switches to one of the
+/// `size - 1` others.
+fn runs_after(sums: &[BigUint], size: usize, max_run: usize, remaining: usize, run: usize) -> BigUint {
+    let ending = BigUint::from_u32(if remaining <= max_run - run { 1 } else { 0 });
+    if remaining == 0 {
+        return ending;
+    }
+    let repeats = (max_run - run).min(remaining - 1);
+    let switching = sums[remaining].sub(&sums[remaining - 1 - repeats]);
+    ending.add(&switching.mul_u32(size as u32 - 1))
+}
+
+fn longest_run(password: &[&str]) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+    for (i, elt) in password.iter().enumerate() {
+        current = if i > 0 && password[i - 1] == *elt { current + 1 } else { 1 };
+        longest = longest.max(current);
+    }
+    longest
+}
+
+/// Split on the semicolons that end properties, leaving any inside custom classes alone.
+fn split_properties(rules: &str) -> Result<Vec<&str>, RulesError> {
+    let mut properties = Vec::new();
+    let mut start = 0;
+    let mut rest = rules;
+    while let Some(i) = rest.find([';', '[']) {
+        if rest.as_bytes()[i] == b'[' {
+            let length = custom_class_length(&rest[i..])?;
+            rest = &rest[i + length..];
+            continue;
+        }
+        let end = rules.len() - rest.len() + i;
+        properties.push(&rules[start..end]);
+        start = end + 1;
+        rest = &rules[start..];
+    }
+    properties.push(&rules[start..]);
+    Ok(properties.into_iter().filter(|p| !p.trim().is_empty()).collect())
+}
+
+/// The length of the custom class at the start of `s`, including the brackets. The class ends at
+/// the first `]` followed by a comma, semicolon or the end of the string, so `]` may appear
+/// inside it.
+fn custom_class_length(s: &str) -> Result<usize, RulesError> {
+    for (i, c) in s.char_indices().skip(1) {
+        if c == ']' {
+            match s[i + 1..].trim_start().chars().next() {
+                None | Some(',') | Some(';') => return Ok(i + 1),
+                _ => {},
+            }
+        }
+    }
+    Err(RulesError::Syntax(format!("unterminated custom character class \"{}\"", s)))
+}
+
+fn parse_classes(value: &str) -> Result<Vec<char>, RulesError> {
+    let mut chars = Vec::new();
+    let mut rest = value.trim();
+    while !rest.is_empty() {
+        let class_length = if rest.starts_with('[') {
+            let length = custom_class_length(rest)?;
+            for c in rest[1..length - 1].chars().filter(|c| !c.is_whitespace()) {
+                if !c.is_ascii_graphic() {
+                    return Err(RulesError::Syntax(format!("\"{}\" is not printable ASCII", c)));
+                }
+                chars.push(c);
+            }
+            length
+        } else {
+            let length = rest.find(',').unwrap_or(rest.len());
+            chars.extend(named_class(rest[..length].trim())?);
+            length
+        };
+        rest = rest[class_length..].trim_start();
+        if rest.starts_with(',') {
+            rest = rest[1..].trim_start();
+        } else if !rest.is_empty() {
+            return Err(RulesError::Syntax(format!("expected a comma before \"{}\"", rest)));
+        }
+    }
+    Ok(chars)
+}
+
+fn named_class(name: &str) -> Result<Vec<char>, RulesError> {
+    let elements = match name.to_ascii_lowercase().as_str() {
+        "upper" => &charsets::ALPHANUMERIC[26..52],
+        "lower" => charsets::LOWERCASE_ALPHABETIC,
+        "digit" => charsets::DECIMAL_DIGIT,
+        "special" => return Ok(SPECIAL.chars().collect()),
+        // Generated passwords stick to ASCII even when the site accepts any Unicode.
+        "ascii-printable" | "unicode" => charsets::ASCII,
+        _ => return Err(RulesError::UnknownClass(name.to_owned())),
+    };
+    Ok(elements.iter().flat_map(|elt| elt.chars()).collect())
+}
+
+fn parse_number(value: &str) -> Result<usize, RulesError> {
+    value.parse().map_err(|_| RulesError::InvalidNumber(value.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(s: &str) -> Vec<String> {
+        s.chars().map(|c| c.to_string()).collect()
+    }
+
+    /// Count the compliant passwords by brute force.
+    fn count_compliant(policy: &PasswordPolicy, length: usize) -> usize {
+        let n = policy.charset.len();
+        (0..n.pow(length as u32)).filter(|&password_index| {
+            let mut index = password_index;
+            let password: Vec<&str> = (0..length).map(|_| {
+                let elt = policy.charset[index % n].as_str();
+                index /= n;
+                elt
+            }).collect();
+            policy.is_compliant(&password)
+        }).count()
+    }
+
+    #[test]
+    fn special_is_all_punctuation() {
+        let mut special = chars(SPECIAL);
+        special.sort();
+        let expected: Vec<String> = charsets::ASCII.iter()
+            .filter(|elt| !elt.chars().next().unwrap().is_ascii_alphanumeric())
+            .map(|elt| elt.to_string())
+            .collect();
+        assert_eq!(special, expected);
+    }
+
+    #[test]
+    fn parse_example() {
+        let policy = PasswordPolicy::parse("minlength: 12; required: upper; required: digit; allowed: [-_];").unwrap();
+        assert_eq!(policy.charset, chars("-0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ_"));
+        assert_eq!(policy.required, vec![chars("ABCDEFGHIJKLMNOPQRSTUVWXYZ"), chars("0123456789")]);
+        assert_eq!(policy.min_length, Some(12));
+        assert_eq!(policy.max_length, None);
+        assert_eq!(policy.max_consecutive, None);
+    }
+
+    #[test]
+    fn parse_defaults_to_ascii() {
+        let policy = PasswordPolicy::parse("minlength: 8").unwrap();
+        assert_eq!(policy.charset.len(), charsets::ASCII.len());
+        assert!(policy.required.is_empty());
+        assert_eq!(PasswordPolicy::parse("").unwrap().charset.len(), charsets::ASCII.len());
+    }
+
+    #[test]
+    fn parse_syntax_variations() {
+        let policy = PasswordPolicy::parse(
+            "  Required : lower , upper;required:digit;ALLOWED: [-;,]];max-consecutive: 3; max-consecutive: 2;;"
+        ).unwrap();
+        assert_eq!(policy.required.len(), 2);
+        assert_eq!(policy.required[0].len(), 52);
+        assert!(policy.charset.contains(&"]".to_owned()));
+        assert!(policy.charset.contains(&";".to_owned()));
+        assert!(policy.charset.contains(&",".to_owned()));
+        assert!(policy.charset.contains(&"-".to_owned()));
+        assert_eq!(policy.max_consecutive, Some(2));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(PasswordPolicy::parse("minlen: 8"), Err(RulesError::UnknownProperty("minlen".to_owned())));
+        assert_eq!(PasswordPolicy::parse("required: letters"), Err(RulesError::UnknownClass("letters".to_owned())));
+        assert_eq!(PasswordPolicy::parse("minlength: -1"), Err(RulesError::InvalidNumber("-1".to_owned())));
+        assert_eq!(PasswordPolicy::parse("minlength: 20; maxlength: 10"), Err(RulesError::Unsatisfiable));
+        assert_eq!(PasswordPolicy::parse("allowed: [a]"), Err(RulesError::Unsatisfiable));
+        assert_eq!(PasswordPolicy::parse("max-consecutive: 0"), Err(RulesError::Unsatisfiable));
+        match PasswordPolicy::parse("allowed: [abc") {
+            Err(RulesError::Syntax(message)) => assert!(message.contains("unterminated")),
+            result => panic!("Unterminated custom class was accepted: {:?}", result),
+        }
+        match PasswordPolicy::parse("allowed: upper lower") {
+            Err(RulesError::UnknownClass(_)) => {},
+            result => panic!("Missing comma was accepted: {:?}", result),
+        }
+        match PasswordPolicy::parse("minlength 8") {
+            Err(RulesError::Syntax(_)) => {},
+            result => panic!("Missing colon was accepted: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn length_clamping() {
+        let policy = PasswordPolicy::parse("minlength: 12; maxlength: 16").unwrap();
+        assert_eq!(policy.clamp_length(0), 12);
+        assert_eq!(policy.clamp_length(14), 14);
+        assert_eq!(policy.clamp_length(64), 16);
+        assert_eq!(policy.entropy_bits(17), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn generated_passwords_are_compliant() {
+        let policy = PasswordPolicy::parse("required: upper; required: digit; required: [-_]; allowed: lower; max-consecutive: 1").unwrap();
+        for length in 3..20 {
+            for _ in 0..20 {
+                let password = policy.random_password_elements(length).unwrap();
+                assert_eq!(password.len(), length);
+                assert!(policy.is_compliant(&password));
+            }
+        }
+    }
+
+    #[test]
+    fn entropy_without_constraints() {
+        let policy = PasswordPolicy::parse("allowed: digit").unwrap();
+        assert!((policy.entropy_bits(10) - 10.0 * 10f64.log2()).abs() < 1e-9);
+        assert_eq!(policy.entropy_bits(0), 0.0);
+    }
+
+    #[test]
+    fn entropy_matches_brute_force() {
+        let rules = [
+            "required: [ab]; required: [cd]; allowed: [e]",
+            "required: [abc]; required: [bcd]",
+            "allowed: [abc]; max-consecutive: 1",
+            "required: [a]; allowed: [bc]; max-consecutive: 2",
+            "required: [a]; required: [b]; required: [c]; max-consecutive: 1",
+        ];
+        for rule in rules.iter() {
+            let policy = PasswordPolicy::parse(rule).unwrap();
+            for length in 0..7 {
+                let expected = (count_compliant(&policy, length) as f64).log2();
+                let actual = policy.entropy_bits(length);
+                assert!(
+                    (expected - actual).abs() < 1e-9 || (expected == actual),
+                    "{} at length {}: expected {} bits, got {}", rule, length, expected, actual
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn entropy_of_long_passwords() {
+        let policy = PasswordPolicy::parse("").unwrap();
+        assert!((policy.entropy_bits(64) - 64.0 * 94f64.log2()).abs() < 1e-9);
+
+        // 10^64 - 2 * 9^64 + 8^64 passwords have a 0 and a 1, and 9^64 / 10^64 is about 0.1%
+        let policy = PasswordPolicy::parse("allowed: digit; required: [0]; required: [1]").unwrap();
+        let expected = 64.0 * 10f64.log2() + (1.0 - 2.0 * 0.9f64.powi(64) + 0.8f64.powi(64)).log2();
+        assert!((policy.entropy_bits(64) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rules_with_few_compliant_passwords() {
+        // only "abab..." and "baba..." are compliant, out of 2^length passwords
+        let policy = PasswordPolicy::parse("allowed: [ab]; max-consecutive: 1").unwrap();
+        for &length in [36, 64, 500].iter() {
+            assert_eq!(policy.entropy_bits(length), 1.0);
+            let password = policy.random_password(length).unwrap();
+            assert!(password == "ab".repeat(length / 2) || password == "ba".repeat(length / 2), "{}", password);
+        }
+
+        // exactly one character of every class, in some order
+        let policy = PasswordPolicy::parse("maxlength: 4; required: [a]; required: [b]; required: [c]; required: [d]").unwrap();
+        assert!((policy.entropy_bits(4) - 24f64.log2()).abs() < 1e-9);
+        let mut password: Vec<char> = policy.random_password(4).unwrap().chars().collect();
+        password.sort();
+        assert_eq!(password, vec!['a', 'b', 'c', 'd']);
+    }
+
+    #[test]
+    #[should_panic(expected = "No password of the requested length")]
+    fn panics_on_unsatisfiable_length() {
+        let policy = PasswordPolicy::parse("required: upper; required: digit").unwrap();
+        let _ = policy.random_password(1);
+    }
+}
@@ -0,0 +1,132 @@
+//! The algorithm that maps random bytes to password elements.
+//!
+//! Passwords generated from the same RNG output must not change between releases, e.g. when
+//! `rand` is upgraded, so passgenr does its own sampling instead of using `Rng::choose`. Any
+//! change to the mapping must increment `ALGORITHM_VERSION`.
+//!
+//! Version 1 selects an index below `n` by rejection sampling:
+//!
+//! 1. Let `bits` be the number of bits needed to write `n - 1`, and `bytes` be `bits / 8` rounded
+//!    up.
+//! 2. Fill a buffer of `bytes` bytes with a single `try_fill_bytes` call on the RNG.
+//! 3. Read the buffer as a big-endian integer and keep only its low `bits` bits.
+//! 4. If the result is less than `n`, it is the index. Otherwise, go back to step 2.
+//!
+//! Every attempt succeeds with probability greater than 1/2, and every index is equally likely,
+//! unlike reducing a random number modulo `n`.
+
+use super::rand_core::{CryptoRng, Error, RngCore};
+
+/// The version of the sampling algorithm described in the module documentation.
+pub const ALGORITHM_VERSION: u32 = 1;
+
+/// Select an index in `0..n` uniformly at random, using version `ALGORITHM_VERSION` of the
+/// sampling algorithm.
+///
+/// The call will panic if `n` is zero.
+pub fn uniform_index<R: RngCore + CryptoRng>(rng: &mut R, n: usize) -> Result<usize, Error> {
+    if n == 0 {
+        panic!("Can't select an index from an empty range!");
+    }
+
+    let max = (n - 1) as u64;
+    let bits = 64 - max.leading_zeros() as usize;
+    let mask = if bits == 64 { u64::MAX } else { (1 << bits) - 1 };
+    let mut buffer = [0u8; 8];
+    let bytes = &mut buffer[..bits.div_ceil(8)];
+    loop {
+        rng.try_fill_bytes(bytes)?;
+        let value = bytes.iter().fold(0u64, |acc, &b| (acc << 8) | u64::from(b)) & mask;
+        if value <= max {
+            return Ok(value as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::insecure_testing::InsecureRng;
+    use super::super::rand_core::impls;
+
+    /// Replays a fixed byte stream, so that the algorithm can be checked independently of any RNG.
+    struct ByteStream<'a> {
+        bytes: &'a [u8],
+    }
+
+    impl<'a> RngCore for ByteStream<'a> {
+        fn next_u32(&mut self) -> u32 {
+            impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            let (head, tail) = self.bytes.split_at(dest.len());
+            dest.copy_from_slice(head);
+            self.bytes = tail;
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn byte_stream_reads_words_little_endian() {
+        let mut stream = ByteStream { bytes: &[1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0] };
+        assert_eq!(stream.next_u32(), 1);
+        assert_eq!(stream.next_u64(), 2);
+        assert!(stream.bytes.is_empty());
+    }
+
+    fn indices(bytes: &[u8], n: usize, count: usize) -> Vec<usize> {
+        let mut stream = InsecureRng(ByteStream { bytes });
+        let indices = (0..count).map(|_| uniform_index(&mut stream, n).unwrap()).collect();
+        assert!(stream.0.bytes.is_empty(), "Not all of the bytes were used");
+        indices
+    }
+
+    #[test]
+    fn version() {
+        // Changing the algorithm requires changing this test, the version, and the known answers.
+        assert_eq!(ALGORITHM_VERSION, 1);
+    }
+
+    #[test]
+    fn power_of_two_never_rejects() {
+        assert_eq!(indices(&[0x00, 0xff, 0x3c], 16, 3), vec![0x0, 0xf, 0xc]);
+        assert_eq!(indices(&[0x00, 0xff, 0x3c], 256, 3), vec![0x00, 0xff, 0x3c]);
+        assert_eq!(indices(&[0xff], 2, 1), vec![1]);
+    }
+
+    #[test]
+    fn out_of_range_values_are_rejected() {
+        // 94 needs 7 bits: 0xde & 0x7f = 94 and 0xff & 0x7f = 127 are rejected, 0x5d = 93 is not.
+        assert_eq!(indices(&[0xde, 0xff, 0x5d, 0x00], 94, 2), vec![93, 0]);
+        // 10 needs 4 bits.
+        assert_eq!(indices(&[0x0a, 0x09], 10, 1), vec![9]);
+    }
+
+    #[test]
+    fn multiple_bytes_are_big_endian() {
+        // 7236 needs 13 bits and 2 bytes: 0x1c44 = 7236 is rejected, 0x3c43 & 0x1fff = 7235 is not.
+        assert_eq!(indices(&[0x1c, 0x44, 0x3c, 0x43], 7236, 1), vec![7235]);
+        assert_eq!(indices(&[0x01, 0x00], 257, 1), vec![256]);
+        assert_eq!(indices(&[0x01, 0x00, 0x00], 65537, 1), vec![65536]);
+    }
+
+    #[test]
+    fn single_index_reads_nothing() {
+        assert_eq!(indices(&[], 1, 3), vec![0, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty range")]
+    fn panics_on_empty_range() {
+        let _ = indices(&[], 0, 1);
+    }
+}
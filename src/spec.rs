@@ -0,0 +1,413 @@
+//! Complete descriptions of how to generate a password, which can be stored in configuration.
+//! With the `serde` feature, `PasswordSpec` implements `Serialize` and `Deserialize`, so a spec
+//! can be read from JSON such as
+//!
+//! ```json
+//! { "charset": "words", "count": 6, "separator": "." }
+//! { "charset": ["red", "green", "blue"], "count": 12, "separator": " ", "group": { "size": 4, "separator": "\n" } }
+//! { "rules": "minlength: 12; required: upper; required: digit; allowed: lower;", "count": 16 }
+//! { "charset": "crockford_base32", "count": 16, "group": { "size": 4, "separator": "-" }, "check_symbol": true }
+//! ```
+//!
+//! or the equivalent TOML, and turned into a password with `PasswordSpec::generate`:
+//!
+//!     use passgenr::spec::{CharsetSpec, PasswordSpec, Source};
+//!
+//!     let spec = PasswordSpec::new(Source::Charset(CharsetSpec::Named("base58".to_owned())), 22);
+//!     assert_eq!(spec.generate().unwrap().len(), 22);
+//!
+//! Charset names are those of `charsets::NAMED`. Since specs usually come from outside the
+//! program, everything that would make the generation functions panic is reported as a
+//! `SpecError` instead. That includes separators with which different sequences of elements can
+//! join to the same password, such as an empty separator or group separator with the `words`
+//! charset (see `is_unambiguous`), so the password would be weaker than `entropy_bits` says.
+
+use std::error;
+use std::fmt;
+use std::io;
+
+#[cfg(feature = "serde")]
+use super::serde::{Deserialize, Serialize};
+
+use super::charsets;
+use super::crockford;
+use super::rules::{PasswordPolicy, RulesError};
+use super::{is_unambiguous, join_grouped, random_password_elements, slice_contains_duplicates};
+
+/// A character set, either one of the built-in sets or an explicit list of elements. In
+/// serialized form, this is a string or an array of strings.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(untagged))]
+pub enum CharsetSpec {
+    /// A name from `charsets::NAMED`, matched case-insensitively.
+    Named(String),
+    Elements(Vec<String>),
+}
+
+/// Where the password's elements come from. In serialized form, this is a `charset` or a `rules`
+/// field of the `PasswordSpec`.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(rename_all = "snake_case"))]
+pub enum Source {
+    /// `count` elements selected uniformly from a charset.
+    Charset(CharsetSpec),
+    /// A password of `count` characters satisfying a `passwordrules` policy (see `rules`).
+    Rules(String),
+}
+
+/// Joins every `size` elements with `separator` instead of the spec's separator, as in
+/// `join_grouped`.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Grouping {
+    pub size: usize,
+    pub separator: String,
+}
+
+/// How to generate a password: its source, length, and output style. Only `count` and the
+/// source are required when deserializing.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PasswordSpec {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub source: Source,
+    /// The number of elements, or of characters for `Source::Rules`.
+    pub count: usize,
+    /// Placed between elements.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub separator: String,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub group: Option<Grouping>,
+    /// Append a `crockford::check_symbol` as an extra element. The password must be Crockford
+    /// base32, so the source must be the `crockford_base32` charset or some of its elements.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub check_symbol: bool,
+}
+
+/// The reason a `PasswordSpec` can't be used.
+#[derive(Debug)]
+pub enum SpecError {
+    /// The name isn't in `charsets::NAMED`.
+    UnknownCharset(String),
+    /// The charset has fewer than two elements.
+    CharsetTooSmall,
+    /// The charset contains the same element more than once.
+    DuplicateElements,
+    /// Different sequences of elements can join to the same password with the separator and
+    /// group separator.
+    Ambiguous,
+    /// The rules can't be parsed, or no password of `count` characters satisfies them.
+    Rules(RulesError),
+    /// The group size is zero.
+    ZeroGroupSize,
+    /// `check_symbol` is set but the password isn't Crockford base32.
+    CheckSymbol,
+    /// The random number generator failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for SpecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SpecError::UnknownCharset(ref name) => write!(f, "unknown charset \"{}\"", name),
+            SpecError::CharsetTooSmall => write!(f, "the charset must have at least two elements"),
+            SpecError::DuplicateElements => write!(f, "the charset contains duplicate elements"),
+            SpecError::Ambiguous => {
+                write!(f, "the charset's elements can't be joined with the separators unambiguously")
+            },
+            SpecError::Rules(ref err) => write!(f, "invalid rules: {}", err),
+            SpecError::ZeroGroupSize => write!(f, "the group size must be nonzero"),
+            SpecError::CheckSymbol => write!(f, "check symbols require a Crockford base32 password"),
+            SpecError::Io(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl error::Error for SpecError {}
+
+impl From<RulesError> for SpecError {
+    fn from(err: RulesError) -> SpecError {
+        SpecError::Rules(err)
+    }
+}
+
+impl From<io::Error> for SpecError {
+    fn from(err: io::Error) -> SpecError {
+        SpecError::Io(err)
+    }
+}
+
+impl CharsetSpec {
+    /// The elements of the charset, checked to be usable by `random_password`.
+    pub fn elements(&self) -> Result<Vec<&str>, SpecError> {
+        let elements: Vec<&str> = match *self {
+            CharsetSpec::Named(ref name) => match charsets::by_name(name) {
+                Some(charset) => charset.to_vec(),
+                None => return Err(SpecError::UnknownCharset(name.clone())),
+            },
+            CharsetSpec::Elements(ref elements) => elements.iter().map(String::as_str).collect(),
+        };
+
+        if elements.len() < 2 {
+            return Err(SpecError::CharsetTooSmall);
+        }
+        if slice_contains_duplicates(&elements) {
+            return Err(SpecError::DuplicateElements);
+        }
+        Ok(elements)
+    }
+}
+
+impl PasswordSpec {
+    /// A spec for `count` elements of `source`, without separators, grouping or check symbol.
+    pub fn new(source: Source, count: usize) -> PasswordSpec {
+        PasswordSpec {
+            source,
+            count,
+            separator: String::new(),
+            group: None,
+            check_symbol: false,
+        }
+    }
+
+    /// Randomly generate a password as described by the spec.
+    pub fn generate(&self) -> Result<String, SpecError> {
+        if let Some(ref group) = self.group {
+            if group.size == 0 {
+                return Err(SpecError::ZeroGroupSize);
+            }
+        }
+
+        self.check_symbol_source()?;
+
+        match self.source {
+            Source::Charset(ref charset) => {
+                let elements = random_password_elements(&self.elements(charset)?, self.count)?;
+                self.join(elements)
+            },
+            Source::Rules(ref rules) => {
+                let policy = self.policy(rules)?;
+                let elements = policy.random_password_elements(self.count)?;
+                self.join(elements)
+            },
+        }
+    }
+
+    /// The entropy, in bits, of the passwords `generate` returns. Grouping and check symbols
+    /// don't change it.
+    pub fn entropy_bits(&self) -> Result<f64, SpecError> {
+        match self.source {
+            Source::Charset(ref charset) => Ok(super::entropy_bits(&self.elements(charset)?, self.count)),
+            Source::Rules(ref rules) => Ok(self.policy(rules)?.entropy_bits(self.count)),
+        }
+    }
+
+    /// The elements of `charset`, checked to be unambiguous when joined with the separator and the
+    /// group separator.
+    fn elements<'a>(&self, charset: &'a CharsetSpec) -> Result<Vec<&'a str>, SpecError> {
+        let elements = charset.elements()?;
+        let group = self.group.as_ref().map(|group| (group.size, group.separator.as_str()));
+        if !is_unambiguous(&elements, &self.separator, group) {
+            return Err(SpecError::Ambiguous);
+        }
+        Ok(elements)
+    }
+
+    /// With `check_symbol` set, check that the password will be Crockford base32: the source must
+    /// be the `crockford_base32` charset, or elements that are all in it. Other charsets and rules
+    /// can produce passwords `crockford::check_symbol` accepts, e.g. hex, but only because it
+    /// ignores case and reads I, L and O as digits.
+    fn check_symbol_source(&self) -> Result<(), SpecError> {
+        if !self.check_symbol {
+            return Ok(());
+        }
+        let is_crockford = match self.source {
+            Source::Charset(CharsetSpec::Named(ref name)) => name.eq_ignore_ascii_case("crockford_base32"),
+            Source::Charset(CharsetSpec::Elements(ref elements)) => {
+                elements.iter().all(|element| charsets::CROCKFORD_BASE32.contains(&element.as_str()))
+            },
+            Source::Rules(_) => false,
+        };
+        if is_crockford {
+            Ok(())
+        } else {
+            Err(SpecError::CheckSymbol)
+        }
+    }
+
+    /// Parse `rules`, checking that some password of `count` characters satisfies them.
+    fn policy(&self, rules: &str) -> Result<PasswordPolicy, SpecError> {
+        let policy = PasswordPolicy::parse(rules)?;
+        if policy.entropy_bits(self.count) == f64::NEG_INFINITY {
+            return Err(SpecError::Rules(RulesError::Unsatisfiable));
+        }
+        Ok(policy)
+    }
+
+    fn join(&self, elements: Vec<&str>) -> Result<String, SpecError> {
+        let check_symbol = if self.check_symbol {
+            match crockford::check_symbol(&elements.concat()) {
+                Some(check) => Some(check.to_string()),
+                None => return Err(SpecError::CheckSymbol),
+            }
+        } else {
+            None
+        };
+
+        let mut elements = elements;
+        if let Some(ref check) = check_symbol {
+            elements.push(check);
+        }
+
+        Ok(match self.group {
+            Some(ref group) => join_grouped(&elements, &self.separator, group.size, &group.separator),
+            None => elements.join(&self.separator),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "serde")]
+    use serde_json;
+
+    fn named(name: &str, count: usize) -> PasswordSpec {
+        PasswordSpec::new(Source::Charset(CharsetSpec::Named(name.to_owned())), count)
+    }
+
+    #[test]
+    fn generates_from_named_charsets() {
+        let mut spec = named("WORDS", 6);
+        spec.separator = ".".to_owned();
+        let password = spec.generate().unwrap();
+        assert_eq!(password.split('.').count(), 6);
+        assert!(password.split('.').all(|word| charsets::WORDS.contains(word)));
+        assert_eq!(spec.entropy_bits().unwrap(), ::entropy_bits(charsets::WORDS, 6));
+    }
+
+    #[test]
+    fn generates_from_elements() {
+        let elements = vec!["red".to_owned(), "green".to_owned(), "blue".to_owned()];
+        let mut spec = PasswordSpec::new(Source::Charset(CharsetSpec::Elements(elements)), 8);
+        spec.separator = " ".to_owned();
+        spec.group = Some(Grouping { size: 4, separator: "\n".to_owned() });
+        let password = spec.generate().unwrap();
+        assert_eq!(password.lines().count(), 2);
+        assert!(password.split_whitespace().all(|word| ["red", "green", "blue"].contains(&word)));
+    }
+
+    #[test]
+    fn generates_from_rules() {
+        let spec = PasswordSpec::new(Source::Rules("minlength: 12; required: upper; required: digit;".to_owned()), 16);
+        let password = spec.generate().unwrap();
+        assert_eq!(password.len(), 16);
+        assert!(password.chars().any(|c| c.is_ascii_uppercase()));
+        assert!(password.chars().any(|c| c.is_ascii_digit()));
+        assert!(spec.entropy_bits().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn appends_check_symbols() {
+        let mut spec = named("crockford_base32", 16);
+        spec.group = Some(Grouping { size: 4, separator: "-".to_owned() });
+        spec.check_symbol = true;
+        let password = spec.generate().unwrap();
+        assert_eq!(password.len(), 17 + 4);
+        assert!(crockford::verify_check_symbol(&password));
+    }
+
+    #[test]
+    fn rejects_invalid_specs() {
+        match named("klingon", 8).generate() {
+            Err(SpecError::UnknownCharset(ref name)) => assert_eq!(name, "klingon"),
+            other => panic!("{:?}", other),
+        }
+
+        let one = PasswordSpec::new(Source::Charset(CharsetSpec::Elements(vec!["a".to_owned()])), 8);
+        assert!(matches!(one.generate(), Err(SpecError::CharsetTooSmall)));
+
+        let duplicates = vec!["a".to_owned(), "b".to_owned(), "a".to_owned()];
+        let duplicates = PasswordSpec::new(Source::Charset(CharsetSpec::Elements(duplicates)), 8);
+        assert!(matches!(duplicates.entropy_bits(), Err(SpecError::DuplicateElements)));
+
+        let rules = PasswordSpec::new(Source::Rules("maxlength: 8;".to_owned()), 9);
+        assert!(matches!(rules.generate(), Err(SpecError::Rules(RulesError::Unsatisfiable))));
+        let rules = PasswordSpec::new(Source::Rules("bogus: 1;".to_owned()), 9);
+        assert!(matches!(rules.generate(), Err(SpecError::Rules(RulesError::UnknownProperty(_)))));
+
+        assert!(matches!(named("words", 6).generate(), Err(SpecError::Ambiguous)));
+        assert!(matches!(named("words", 6).entropy_bits(), Err(SpecError::Ambiguous)));
+        let prefix_free = vec!["0".to_owned(), "10".to_owned(), "110".to_owned(), "111".to_owned()];
+        let prefix_free = PasswordSpec::new(Source::Charset(CharsetSpec::Elements(prefix_free)), 8);
+        assert!(prefix_free.generate().is_ok());
+
+        let mut glued = named("words", 6);
+        glued.separator = ".".to_owned();
+        assert!(glued.generate().is_ok());
+        glued.group = Some(Grouping { size: 2, separator: "".to_owned() });
+        assert!(matches!(glued.generate(), Err(SpecError::Ambiguous)));
+        assert!(matches!(glued.entropy_bits(), Err(SpecError::Ambiguous)));
+        let mut inside = PasswordSpec::new(Source::Charset(CharsetSpec::Elements(vec!["a".to_owned(), "a.b".to_owned(), "b".to_owned()])), 4);
+        inside.separator = ".".to_owned();
+        assert!(matches!(inside.generate(), Err(SpecError::Ambiguous)));
+
+        let mut grouped = named("ascii", 8);
+        grouped.group = Some(Grouping { size: 0, separator: "-".to_owned() });
+        assert!(matches!(grouped.generate(), Err(SpecError::ZeroGroupSize)));
+
+        let mut checked = PasswordSpec::new(Source::Charset(CharsetSpec::Elements(vec!["#".to_owned(), "%".to_owned()])), 8);
+        checked.check_symbol = true;
+        assert!(matches!(checked.generate(), Err(SpecError::CheckSymbol)));
+        for &name in ["uppercase_hex", "lowercase_alphabetic", "alphanumeric"].iter() {
+            let mut checked = named(name, 16);
+            checked.check_symbol = true;
+            assert!(matches!(checked.generate(), Err(SpecError::CheckSymbol)));
+        }
+        let elements = vec!["0".to_owned(), "Z".to_owned()];
+        let mut checked = PasswordSpec::new(Source::Charset(CharsetSpec::Elements(elements)), 8);
+        checked.check_symbol = true;
+        assert!(crockford::verify_check_symbol(&checked.generate().unwrap()));
+        let mut checked = PasswordSpec::new(Source::Rules("allowed: upper, digit;".to_owned()), 16);
+        checked.check_symbol = true;
+        assert!(matches!(checked.generate(), Err(SpecError::CheckSymbol)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserializes_json() {
+        let spec: PasswordSpec = serde_json::from_str(r#"{ "charset": "base58", "count": 22 }"#).unwrap();
+        assert_eq!(spec, named("base58", 22));
+
+        let spec: PasswordSpec = serde_json::from_str(
+            r#"{ "charset": ["a", "b"], "count": 4, "separator": "-", "group": { "size": 2, "separator": " " } }"#
+        ).unwrap();
+        assert_eq!(spec.source, Source::Charset(CharsetSpec::Elements(vec!["a".to_owned(), "b".to_owned()])));
+        assert_eq!(spec.separator, "-");
+        assert_eq!(spec.group, Some(Grouping { size: 2, separator: " ".to_owned() }));
+        assert_eq!(spec.generate().unwrap().len(), "a-b a-b".len());
+
+        let spec: PasswordSpec = serde_json::from_str(r#"{ "rules": "required: digit;", "count": 10, "check_symbol": true }"#).unwrap();
+        assert_eq!(spec.source, Source::Rules("required: digit;".to_owned()));
+        assert!(spec.check_symbol);
+        assert!(matches!(spec.generate(), Err(SpecError::CheckSymbol)));
+
+        assert!(serde_json::from_str::<PasswordSpec>(r#"{ "charset": "base58" }"#).is_err());
+        assert!(serde_json::from_str::<PasswordSpec>(r#"{ "count": 22 }"#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialization_roundtrips() {
+        let mut spec = named("words", 6);
+        spec.separator = " ".to_owned();
+        spec.group = Some(Grouping { size: 3, separator: "\n".to_owned() });
+        let json = serde_json::to_string(&spec).unwrap();
+        assert_eq!(json, r#"{"charset":"words","count":6,"separator":" ","group":{"size":3,"separator":"\n"},"check_symbol":false}"#);
+        assert_eq!(serde_json::from_str::<PasswordSpec>(&json).unwrap(), spec);
+
+        let spec = PasswordSpec::new(Source::Rules("maxlength: 20;".to_owned()), 20);
+        let json = serde_json::to_string(&spec).unwrap();
+        assert_eq!(serde_json::from_str::<PasswordSpec>(&json).unwrap(), spec);
+    }
+}
@@ -0,0 +1,201 @@
+//! API tokens in the style of GitHub's `ghp_...` tokens.
+//!
+//! A token is a fixed prefix, followed by a random body of `charsets::ALPHANUMERIC` characters,
+//! followed by a checksum of the body. The checksum lets secret scanners tell real tokens apart
+//! from random-looking strings without having to call the issuing service.
+//!
+//!     use passgenr::token::{generate_token, verify_token, TokenFormat};
+//!
+//!     let format = TokenFormat::new("myco_");
+//!     let token = generate_token(&format).unwrap();
+//!     assert!(token.starts_with("myco_"));
+//!     assert!(verify_token(&format, &token));
+
+use super::charsets;
+use super::random_password;
+
+/// The checksum appended to the body of a token.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ChecksumAlgorithm {
+    /// CRC-32 (ISO-HDLC, as used by zlib and GitHub's tokens).
+    Crc32,
+    /// CRC-32C (Castagnoli).
+    Crc32c,
+    /// No checksum.
+    None,
+}
+
+const CRC32_POLYNOMIAL: u32 = 0xEDB8_8320;
+const CRC32C_POLYNOMIAL: u32 = 0x82F6_3B78;
+
+/// Number of `charsets::ALPHANUMERIC` characters needed to encode any `u32` (62^6 > 2^32).
+const ENCODED_CHECKSUM_LENGTH: usize = 6;
+
+impl ChecksumAlgorithm {
+    fn checksum(self, data: &[u8]) -> Option<u32> {
+        match self {
+            ChecksumAlgorithm::Crc32 => Some(crc32(CRC32_POLYNOMIAL, data)),
+            ChecksumAlgorithm::Crc32c => Some(crc32(CRC32C_POLYNOMIAL, data)),
+            ChecksumAlgorithm::None => None,
+        }
+    }
+
+    fn encoded_length(self) -> usize {
+        match self {
+            ChecksumAlgorithm::None => 0,
+            _ => ENCODED_CHECKSUM_LENGTH,
+        }
+    }
+}
+
+/// The shape of a token: `prefix`, then `body_length` random characters, then the checksum.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TokenFormat<'a> {
+    pub prefix: &'a str,
+    pub body_length: usize,
+    pub checksum: ChecksumAlgorithm,
+}
+
+impl<'a> TokenFormat<'a> {
+    /// A format with the given prefix, a 30-character body (about 178 bits of entropy) and a CRC-32
+    /// checksum, matching the layout of GitHub's tokens.
+    pub fn new(prefix: &'a str) -> TokenFormat<'a> {
+        TokenFormat {
+            prefix,
+            body_length: 30,
+            checksum: ChecksumAlgorithm::Crc32,
+        }
+    }
+}
+
+/// Randomly generate a token in the given `format`.
+///
+/// The call will panic if `format.body_length` is zero.
+pub fn generate_token(format: &TokenFormat) -> Result<String, std::io::Error> {
+    if format.body_length == 0 {
+        panic!("The token body length must be nonzero!");
+    }
+
+    let body = random_password(charsets::ALPHANUMERIC, format.body_length, "")?;
+
+    let mut token = String::with_capacity(
+        format.prefix.len() + format.body_length + format.checksum.encoded_length()
+    );
+    token.push_str(format.prefix);
+    token.push_str(&body);
+    if let Some(checksum) = format.checksum.checksum(body.as_bytes()) {
+        token.push_str(&encode_checksum(checksum));
+    }
+    Ok(token)
+}
+
+/// Check whether `token` has the given `format` and a correct checksum.
+///
+/// This only detects typos and random strings; it says nothing about whether the token was ever
+/// issued.
+pub fn verify_token(format: &TokenFormat, token: &str) -> bool {
+    if !token.starts_with(format.prefix) {
+        return false;
+    }
+
+    let rest = &token[format.prefix.len()..];
+
+    if rest.len() != format.body_length + format.checksum.encoded_length() {
+        return false;
+    }
+
+    if !rest.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return false;
+    }
+
+    let (body, checksum) = rest.split_at(format.body_length);
+    match format.checksum.checksum(body.as_bytes()) {
+        Some(expected) => encode_checksum(expected) == checksum,
+        None => true,
+    }
+}
+
+fn encode_checksum(mut checksum: u32) -> String {
+    let mut encoded = [""; ENCODED_CHECKSUM_LENGTH];
+    for slot in encoded.iter_mut().rev() {
+        *slot = charsets::ALPHANUMERIC[(checksum % 62) as usize];
+        checksum /= 62;
+    }
+    encoded.concat()
+}
+
+fn crc32(polynomial: u32, data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ polynomial } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc_check_values() {
+        assert_eq!(crc32(CRC32_POLYNOMIAL, b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(CRC32C_POLYNOMIAL, b"123456789"), 0xE306_9283);
+        assert_eq!(crc32(CRC32_POLYNOMIAL, b""), 0);
+    }
+
+    #[test]
+    fn checksum_encoding() {
+        assert_eq!(encode_checksum(0), "aaaaaa");
+        assert_eq!(encode_checksum(61), "aaaaa9");
+        assert_eq!(encode_checksum(62), "aaaaba");
+        assert_eq!(encode_checksum(u32::MAX).len(), ENCODED_CHECKSUM_LENGTH);
+    }
+
+    #[test]
+    fn generated_tokens_verify() {
+        for checksum in [ChecksumAlgorithm::Crc32, ChecksumAlgorithm::Crc32c, ChecksumAlgorithm::None].iter() {
+            let format = TokenFormat { prefix: "myco_", body_length: 30, checksum: *checksum };
+            for _ in 0..100 {
+                let token = generate_token(&format).unwrap();
+                assert_eq!(token.len(), 5 + 30 + checksum.encoded_length());
+                assert!(token.starts_with("myco_"));
+                assert!(verify_token(&format, &token));
+            }
+        }
+    }
+
+    #[test]
+    fn corrupted_tokens_do_not_verify() {
+        let format = TokenFormat::new("ghp_");
+        let token = generate_token(&format).unwrap();
+
+        assert!(!verify_token(&format, &token[1..]));
+        assert!(!verify_token(&format, &token[..token.len() - 1]));
+        assert!(!verify_token(&format, &format!("{}a", token)));
+        assert!(!verify_token(&TokenFormat::new("gho_"), &token));
+
+        // Changing any single character of the body or checksum is always detected by a CRC.
+        for i in format.prefix.len()..token.len() {
+            let mut corrupted = token.clone().into_bytes();
+            corrupted[i] = if corrupted[i] == b'x' { b'y' } else { b'x' };
+            assert!(!verify_token(&format, &String::from_utf8(corrupted).unwrap()));
+        }
+    }
+
+    #[test]
+    fn non_alphanumeric_body_does_not_verify() {
+        let format = TokenFormat { prefix: "", body_length: 4, checksum: ChecksumAlgorithm::None };
+        assert!(verify_token(&format, "abcd"));
+        assert!(!verify_token(&format, "ab-d"));
+    }
+
+    #[test]
+    #[should_panic(expected = "body length must be nonzero")]
+    fn panics_on_empty_body() {
+        let format = TokenFormat { prefix: "x_", body_length: 0, checksum: ChecksumAlgorithm::Crc32 };
+        let _ = generate_token(&format);
+    }
+}
@@ -0,0 +1,152 @@
+//! Lengths and checks for passwords made of non-ASCII characters, such as `charsets::EMOJI`.
+//!
+//! `str::len` counts bytes, so an emoji password is three or four times longer than it looks. What
+//! a person sees, and counts when a site limits the length, is the number of graphemes: the
+//! user-perceived characters of Unicode Standard Annex #29.
+//!
+//!     use passgenr::charsets;
+//!     use passgenr::unicode::grapheme_length;
+//!
+//!     assert_eq!("🐶⚽".len(), 7);
+//!     assert_eq!(grapheme_length("🐶⚽"), 2);
+//!
+//!     let code = passgenr::random_password(charsets::EMOJI, 8, "").unwrap();
+//!     assert_eq!(grapheme_length(&code), 8);
+//!
+//! A charset is only as strong as it looks if every element is one grapheme and no two elements
+//! merge into one when they're joined. Many emoji are sequences of code points, and some single
+//! code points combine with their neighbours: two regional indicators make a flag, and a skin tone
+//! modifier changes the emoji before it. `check_unicode_safe` rejects such charsets.
+//!
+//!     use passgenr::unicode::{check_unicode_safe, UnicodeSafetyError};
+//!
+//!     assert!(check_unicode_safe(passgenr::charsets::EMOJI).is_ok());
+//!     assert_eq!(
+//!         check_unicode_safe(&["🇩", "🇪", "🐶"]),
+//!         Err(UnicodeSafetyError::Combines("🇩".to_owned(), "🇩".to_owned()))
+//!     );
+
+use std::error;
+use std::fmt;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use charsets::Charset;
+
+/// The number of extended grapheme clusters in `s`.
+///
+///     assert_eq!(passgenr::unicode::grapheme_length("cafe\u{301}"), 4);
+///     assert_eq!(passgenr::unicode::grapheme_length("👍🏽"), 1);
+pub fn grapheme_length(s: &str) -> usize {
+    s.graphemes(true).count()
+}
+
+/// Whether `s` is exactly one extended grapheme cluster.
+pub fn is_single_grapheme(s: &str) -> bool {
+    let mut graphemes = s.graphemes(true);
+    graphemes.next().is_some() && graphemes.next().is_none()
+}
+
+/// The reason `check_unicode_safe` rejected a charset.
+#[derive(Clone, PartialEq, Debug)]
+pub enum UnicodeSafetyError {
+    /// The element is empty, or more than one grapheme.
+    NotOneGrapheme(String),
+    /// The two elements, in this order, form a single grapheme when joined.
+    Combines(String, String),
+}
+
+impl fmt::Display for UnicodeSafetyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            UnicodeSafetyError::NotOneGrapheme(ref element) => {
+                write!(f, "the element \"{}\" isn't a single grapheme", element)
+            },
+            UnicodeSafetyError::Combines(ref first, ref second) => {
+                write!(f, "the elements \"{}\" and \"{}\" combine into one grapheme", first, second)
+            },
+        }
+    }
+}
+
+impl error::Error for UnicodeSafetyError {}
+
+/// Check that every element of `charset` is a single grapheme, and that every password made of
+/// them has as many graphemes as elements. Every ordered pair of elements is tried, so this takes
+/// time quadratic in the size of the charset.
+pub fn check_unicode_safe<'a, C: Charset<'a> + ?Sized>(charset: &C) -> Result<(), UnicodeSafetyError> {
+    let elements = charset.to_vec();
+    if let Some(element) = elements.iter().find(|element| !is_single_grapheme(element)) {
+        return Err(UnicodeSafetyError::NotOneGrapheme((*element).to_owned()));
+    }
+
+    let mut pair = String::new();
+    for first in &elements {
+        for second in &elements {
+            pair.clear();
+            pair.push_str(first);
+            pair.push_str(second);
+            if is_single_grapheme(&pair) {
+                return Err(UnicodeSafetyError::Combines((*first).to_owned(), (*second).to_owned()));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use charsets;
+
+    #[test]
+    fn lengths() {
+        assert_eq!(grapheme_length(""), 0);
+        assert_eq!(grapheme_length("abc"), 3);
+        // a family: four people joined by zero-width joiners
+        assert_eq!(grapheme_length("👨\u{200D}👩\u{200D}👧\u{200D}👦"), 1);
+        assert_eq!(grapheme_length("🇩🇪🇫🇷"), 2);
+        assert_eq!(grapheme_length("\r\n"), 1);
+
+        assert!(is_single_grapheme("é"));
+        assert!(is_single_grapheme("e\u{301}"));
+        assert!(!is_single_grapheme(""));
+        assert!(!is_single_grapheme("ab"));
+    }
+
+    #[test]
+    fn builtin_charsets() {
+        for &(name, charset) in charsets::NAMED {
+            let safe = check_unicode_safe(charset).is_ok();
+            assert_eq!(safe, name != "words" && name != "bip39_english", "{}", name);
+        }
+
+        let elements = charsets::EMOJI.iter().flat_map(|element| element.chars()).collect::<Vec<char>>();
+        assert_eq!(elements.len(), charsets::EMOJI.len());
+    }
+
+    #[test]
+    fn unsafe_charsets() {
+        assert_eq!(
+            check_unicode_safe(&["a", "bc"]),
+            Err(UnicodeSafetyError::NotOneGrapheme("bc".to_owned()))
+        );
+        assert_eq!(
+            check_unicode_safe(&["a", ""]),
+            Err(UnicodeSafetyError::NotOneGrapheme("".to_owned()))
+        );
+        // a skin tone modifier on its own is one grapheme, but it attaches to the emoji before it
+        assert_eq!(
+            check_unicode_safe(&["👍", "\u{1F3FD}"]),
+            Err(UnicodeSafetyError::Combines("👍".to_owned(), "\u{1F3FD}".to_owned()))
+        );
+        assert_eq!(
+            check_unicode_safe(&["e", "\u{301}"]),
+            Err(UnicodeSafetyError::Combines("e".to_owned(), "\u{301}".to_owned()))
+        );
+        assert_eq!(
+            UnicodeSafetyError::NotOneGrapheme("bc".to_owned()).to_string(),
+            "the element \"bc\" isn't a single grapheme"
+        );
+    }
+}
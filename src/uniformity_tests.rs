@@ -0,0 +1,263 @@
+//! Statistical tests that password elements are selected uniformly and independently.
+//!
+//! The tests run against a fixed-seed RNG so that they are deterministic. The `os_rng_*` variants
+//! repeat them against `OsRng`; run them with `cargo test -- --ignored`. Each check fails with
+//! probability about 1 in 10,000 for a correct implementation, so an occasional failure of an
+//! ignored test is expected, but a repeatable one is not.
+//!
+//! The `harness_detects_*` tests make sure the checks are strong enough to notice the kinds of
+//! mistakes they are meant to catch.
+
+use std::collections::HashMap;
+
+use super::rand_chacha::ChaCha20Rng;
+use super::rand_core::{CryptoRng, RngCore, SeedableRng};
+#[cfg(feature = "std")]
+use super::rand_core::OsRng;
+#[cfg(feature = "std")]
+use super::rules::PasswordPolicy;
+
+use super::charsets::Charset;
+use super::{charsets, join_grouped, random_password_elements_with_rng, random_password_with_rng, sampling};
+
+/// The standard normal quantile for a one-sided significance level of 10^-4.
+const Z_CRITICAL: f64 = 3.719;
+
+/// Every charset is checked per position with about this many samples expected per element.
+const SAMPLES_PER_ELEMENT: usize = 200;
+
+fn seeded_rng() -> ChaCha20Rng {
+    ChaCha20Rng::from_seed(*b"passgenr uniformity tests seed!!")
+}
+
+/// The upper critical value of the chi-square distribution with `df` degrees of freedom, using the
+/// Wilson-Hilferty approximation.
+fn chi_square_critical_value(df: usize) -> f64 {
+    let df = df as f64;
+    let a = 2.0 / (9.0 * df);
+    df * (1.0 - a + Z_CRITICAL * a.sqrt()).powi(3)
+}
+
+/// Pearson's chi-square statistic for `counts` against a uniform distribution.
+fn chi_square(counts: &[usize]) -> f64 {
+    let total: usize = counts.iter().sum();
+    let expected = total as f64 / counts.len() as f64;
+    counts.iter().map(|&c| (c as f64 - expected).powi(2) / expected).sum()
+}
+
+fn assert_uniform(counts: &[usize], what: &str) {
+    let statistic = chi_square(counts);
+    let critical = chi_square_critical_value(counts.len() - 1);
+    assert!(
+        statistic < critical,
+        "{} is not uniform: chi-square = {:.1} with {} degrees of freedom (critical value {:.1})",
+        what, statistic, counts.len() - 1, critical
+    );
+}
+
+fn indices(charset: &[&str], elements: &[&str]) -> Vec<usize> {
+    let index: HashMap<&str, usize> = charset.iter().enumerate().map(|(i, elt)| (*elt, i)).collect();
+    elements.iter().map(|elt| index[elt]).collect()
+}
+
+/// A chi-square goodness-of-fit test of each position of `length`-element passwords.
+fn check_positions<R: RngCore + CryptoRng>(rng: &mut R, charset: &[&str], length: usize) {
+    let mut counts = vec![vec![0; charset.len()]; length];
+    for _ in 0..SAMPLES_PER_ELEMENT * charset.len() {
+        let password = random_password_elements_with_rng(rng, charset, length).unwrap();
+        for (position, index) in indices(charset, &password).into_iter().enumerate() {
+            counts[position][index] += 1;
+        }
+    }
+    for (position, position_counts) in counts.iter().enumerate() {
+        assert_uniform(position_counts, &format!("Position {} of a {}-element charset", position, charset.len()));
+    }
+}
+
+/// A chi-square goodness-of-fit test of a single long password, for charsets too large to test
+/// position by position.
+fn check_overall<R: RngCore + CryptoRng>(rng: &mut R, charset: &[&str], samples_per_element: usize) {
+    let mut counts = vec![0; charset.len()];
+    for index in indices(charset, &random_password_elements_with_rng(rng, charset, samples_per_element * charset.len()).unwrap()) {
+        counts[index] += 1;
+    }
+    assert_uniform(&counts, &format!("A {}-element charset", charset.len()));
+}
+
+/// A chi-square test of independence of adjacent elements, over all `charset.len()^2` pairs.
+fn check_adjacent_pairs<R: RngCore + CryptoRng>(rng: &mut R, charset: &[&str]) {
+    let k = charset.len();
+    let elements = random_password_elements_with_rng(rng, charset, SAMPLES_PER_ELEMENT * k * k + 1).unwrap();
+    let mut counts = vec![0; k * k];
+    for pair in indices(charset, &elements).windows(2) {
+        counts[pair[0] * k + pair[1]] += 1;
+    }
+    assert_uniform(&counts, &format!("Adjacent pairs from a {}-element charset", k));
+}
+
+/// The lag-1 serial correlation coefficient of element indices, which is approximately normal with
+/// standard deviation 1/sqrt(n) when elements are independent.
+fn assert_uncorrelated(indices: &[usize], what: &str) {
+    let n = indices.len() as f64;
+    let values: Vec<f64> = indices.iter().map(|&i| i as f64).collect();
+    let mean = values.iter().sum::<f64>() / n;
+    let variance: f64 = values.iter().map(|v| (v - mean).powi(2)).sum();
+    let covariance: f64 = values.windows(2).map(|w| (w[0] - mean) * (w[1] - mean)).sum();
+    let correlation = covariance / variance;
+    assert!(
+        correlation.abs() * n.sqrt() < Z_CRITICAL,
+        "{} are correlated: r = {}", what, correlation
+    );
+}
+
+fn check_serial_correlation<R: RngCore + CryptoRng>(rng: &mut R, charset: &[&str]) {
+    let elements = random_password_elements_with_rng(rng, charset, 100_000).unwrap();
+    assert_uncorrelated(
+        &indices(charset, &elements),
+        &format!("Adjacent elements from a {}-element charset", charset.len())
+    );
+}
+
+/// Separators are deterministic, so they must appear exactly where expected and leave the
+/// distribution of the elements between them intact.
+fn check_separators<R: RngCore + CryptoRng>(rng: &mut R, charset: &[&str]) {
+    let length = 6;
+    let mut counts = vec![0; charset.len()];
+    for _ in 0..SAMPLES_PER_ELEMENT * charset.len() / length {
+        let password = random_password_with_rng(rng, charset, length, " ").unwrap();
+        let elements: Vec<&str> = password.split(' ').collect();
+        assert_eq!(elements.len(), length);
+        for index in indices(charset, &elements) {
+            counts[index] += 1;
+        }
+
+        let grouped = join_grouped(&elements, "", 4, "\n");
+        assert_eq!(grouped.matches('\n').count(), 1);
+        assert_eq!(grouped.find('\n'), Some(elements[..4].concat().len()));
+    }
+    assert_uniform(&counts, &format!("Separated elements of a {}-element charset", charset.len()));
+}
+
+fn small_charsets() -> Vec<&'static [&'static str]> {
+    vec![
+        &["a", "b", "c"],
+        charsets::DECIMAL_DIGIT,
+        charsets::UPPERCASE_HEX,
+        charsets::BASE58,
+        charsets::ALPHANUMERIC,
+        charsets::ASCII,
+    ]
+}
+
+#[cfg(feature = "std")]
+fn check_all<R: RngCore + CryptoRng>(rng: &mut R) {
+    for charset in small_charsets() {
+        check_positions(rng, charset, 8);
+        check_serial_correlation(rng, charset);
+        check_separators(rng, charset);
+    }
+    for charset in [charsets::WORDS, charsets::BIP39_ENGLISH].iter() {
+        check_overall(rng, &charset.to_vec(), 20);
+    }
+    for charset in [&["a", "b", "c"][..], charsets::DECIMAL_DIGIT, charsets::UPPERCASE_HEX].iter() {
+        check_adjacent_pairs(rng, charset);
+    }
+}
+
+#[test]
+fn seeded_rng_is_uniform_per_position() {
+    let mut rng = seeded_rng();
+    for charset in small_charsets() {
+        check_positions(&mut rng, charset, 8);
+    }
+}
+
+#[test]
+fn seeded_rng_is_uniform_over_wordlists() {
+    let mut rng = seeded_rng();
+    check_overall(&mut rng, &charsets::WORDS.to_vec(), 20);
+    check_overall(&mut rng, &charsets::BIP39_ENGLISH.to_vec(), 20);
+}
+
+#[test]
+fn seeded_rng_adjacent_elements_are_independent() {
+    let mut rng = seeded_rng();
+    for charset in [&["a", "b", "c"][..], charsets::DECIMAL_DIGIT, charsets::UPPERCASE_HEX].iter() {
+        check_adjacent_pairs(&mut rng, charset);
+    }
+    for charset in small_charsets() {
+        check_serial_correlation(&mut rng, charset);
+    }
+}
+
+#[test]
+fn seeded_rng_separators_are_placed_correctly() {
+    let mut rng = seeded_rng();
+    for charset in small_charsets() {
+        check_separators(&mut rng, charset);
+    }
+}
+
+/// Every compliant password of a tight policy, with about 50 samples of each, is equally likely.
+#[cfg(feature = "std")]
+#[test]
+fn seeded_rng_is_uniform_over_compliant_passwords() {
+    let mut rng = seeded_rng();
+    for rules in ["required: [a]; allowed: [bc]; max-consecutive: 2", "required: [a]; required: [b]; allowed: [cd]"].iter() {
+        let policy = PasswordPolicy::parse(rules).unwrap();
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        let compliant = policy.entropy_bits(4).exp2().round() as usize;
+        for _ in 0..50 * compliant {
+            let password = policy.random_password_elements_with_rng(&mut rng, 4).unwrap();
+            assert!(policy.is_compliant(&password));
+            *counts.entry(password.concat()).or_insert(0) += 1;
+        }
+        assert_eq!(counts.len(), compliant);
+        assert_uniform(&counts.values().cloned().collect::<Vec<usize>>(), rules);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[ignore]
+fn os_rng_is_uniform() {
+    check_all(&mut OsRng);
+}
+
+#[test]
+#[should_panic(expected = "is not uniform")]
+fn harness_detects_modulo_bias() {
+    // Reducing a random byte mod 94 makes the first 68 characters 50% more likely than the rest.
+    let mut rng = seeded_rng();
+    let mut counts = vec![0; charsets::ASCII.len()];
+    for _ in 0..SAMPLES_PER_ELEMENT * charsets::ASCII.len() {
+        counts[rng.next_u32() as u8 as usize % charsets::ASCII.len()] += 1;
+    }
+    assert_uniform(&counts, "Modulo-biased selection");
+}
+
+#[test]
+#[should_panic(expected = "is not uniform")]
+fn harness_detects_off_by_one() {
+    // Never selecting the last element.
+    let mut rng = seeded_rng();
+    let mut counts = vec![0; charsets::ALPHANUMERIC.len()];
+    for _ in 0..SAMPLES_PER_ELEMENT * charsets::ALPHANUMERIC.len() {
+        counts[sampling::uniform_index(&mut rng, charsets::ALPHANUMERIC.len() - 1).unwrap()] += 1;
+    }
+    assert_uniform(&counts, "Off-by-one selection");
+}
+
+#[test]
+#[should_panic(expected = "are correlated")]
+fn harness_detects_serial_correlation() {
+    // Repeating the previous element a tenth of the time.
+    let mut rng = seeded_rng();
+    let mut indices = vec![0];
+    for _ in 1..100_000 {
+        let previous = indices[indices.len() - 1];
+        let sticky = sampling::uniform_index(&mut rng, 10).unwrap() == 0;
+        indices.push(if sticky { previous } else { sampling::uniform_index(&mut rng, 10).unwrap() });
+    }
+    assert_uncorrelated(&indices, "Sticky elements");
+}
@@ -0,0 +1,138 @@
+//! JavaScript bindings, enabled by the `wasm` feature. Randomness comes from
+//! `crypto.getRandomValues`, through `getrandom`'s `js` backend.
+//!
+//! Build them with e.g. `wasm-pack build --target web -- --features wasm`, then:
+//!
+//! ```js
+//! import init, { randomPassword, entropyBits } from "./pkg/passgenr.js";
+//!
+//! await init();
+//! const password = randomPassword("words", 6, " ");
+//! const bits = entropyBits("words", 6);
+//! ```
+//!
+//! Charsets are named as in `charsets::NAMED`. Functions throw an `Error` for unknown names.
+//!
+//! The tests also run in node: `wasm-pack test --node -- --features wasm`.
+
+use wasm_bindgen::prelude::*;
+
+use super::charsets::{self, Charset};
+
+fn lookup(name: &str) -> Result<&'static dyn Charset<'static>, String> {
+    charsets::by_name(name).ok_or_else(|| format!("Unknown charset \"{}\"", name))
+}
+
+/// The named charset, checked to be unambiguous when joined with `separator`.
+fn checked(name: &str, separator: &str) -> Result<&'static dyn Charset<'static>, String> {
+    let charset = lookup(name)?;
+    if !super::is_unambiguous(charset, separator, None) {
+        return Err(format!("The elements of charset \"{}\" can't be joined with \"{}\" unambiguously", name, separator));
+    }
+    Ok(charset)
+}
+
+/// Randomly generate a password of `count` elements of the named charset, separated by
+/// `separator`. Separators with which different sequences of elements can give the same password,
+/// such as `""` with `"words"`, throw an `Error`.
+#[wasm_bindgen(js_name = randomPassword)]
+pub fn random_password(charset: &str, count: usize, separator: &str) -> Result<String, JsError> {
+    let charset = checked(charset, separator).map_err(|err| JsError::new(&err))?;
+    super::random_password(charset, count, separator).map_err(|err| JsError::new(&err.to_string()))
+}
+
+/// The names of the built-in charsets.
+#[wasm_bindgen(js_name = charsetNames)]
+pub fn charset_names() -> Vec<String> {
+    charsets::NAMED.iter().map(|&(name, _)| name.to_owned()).collect()
+}
+
+/// The elements of the named charset.
+#[wasm_bindgen]
+pub fn charset(name: &str) -> Result<Vec<String>, JsError> {
+    let charset = lookup(name).map_err(|err| JsError::new(&err))?;
+    Ok(charset.to_vec().iter().map(|elt| elt.to_string()).collect())
+}
+
+/// The entropy, in bits, of a password of `count` elements of the named charset.
+#[wasm_bindgen(js_name = entropyBits)]
+pub fn entropy_bits(charset: &str, count: usize) -> Result<f64, JsError> {
+    let charset = lookup(charset).map_err(|err| JsError::new(&err))?;
+    Ok(super::entropy_bits(charset, count))
+}
+
+/// The number of graphemes (user-perceived characters) in `password`. JavaScript's `length`
+/// counts UTF-16 code units, so it's 2 for most of the `emoji` charset's elements.
+#[wasm_bindgen(js_name = graphemeLength)]
+pub fn grapheme_length(password: &str) -> usize {
+    super::unicode::grapheme_length(password)
+}
+
+// `JsError` can only be created on wasm32, so the error cases are only tested there.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn generates_passwords() {
+        let password = random_password("base58", 22, "").unwrap();
+        assert_eq!(password.len(), 22);
+        assert!(password.chars().all(|c| charsets::BASE58.contains(&c.to_string().as_str())));
+
+        let password = random_password("WORDS", 6, " ").unwrap();
+        assert_eq!(password.split(' ').count(), 6);
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn lists_charsets() {
+        let names = charset_names();
+        assert_eq!(names.len(), charsets::NAMED.len());
+        for name in names {
+            assert_eq!(charset(&name).unwrap().len(), charsets::by_name(&name).unwrap().len());
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn computes_entropy() {
+        assert_eq!(entropy_bits("uppercase_hex", 64).unwrap(), 256.0);
+        assert_eq!(entropy_bits("decimal_digit", 0).unwrap(), 0.0);
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn counts_graphemes() {
+        assert_eq!("🐶⚽".encode_utf16().count(), 3);
+        assert_eq!(grapheme_length("🐶⚽"), 2);
+        assert_eq!(grapheme_length(&random_password("emoji", 8, "").unwrap()), 8);
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn unknown_charsets() {
+        assert_eq!(lookup("klingon").err(), Some("Unknown charset \"klingon\"".to_owned()));
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn ambiguous_separators() {
+        assert!(checked("words", " ").is_ok());
+        assert_eq!(
+            checked("words", "").err(),
+            Some("The elements of charset \"words\" can't be joined with \"\" unambiguously".to_owned())
+        );
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    #[wasm_bindgen_test]
+    fn unknown_charsets_throw() {
+        assert!(random_password("klingon", 10, "").is_err());
+        assert!(random_password("words", 6, "").is_err());
+        assert!(charset("klingon").is_err());
+        assert!(entropy_bits("klingon", 10).is_err());
+    }
+}